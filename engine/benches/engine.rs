@@ -0,0 +1,348 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use seedwing_policy_engine::runtime::config::ConfigContext;
+use seedwing_policy_engine::runtime::{EvalContext, EvalOptions};
+use seedwing_policy_engine::{lang::builder::Builder, runtime::sources::Ephemeral};
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+fn eval_speed(bencher: &mut Criterion, data: TestData) {
+    eval_speed_with(bencher, data, "eval", EvalOptions::default());
+}
+
+fn eval_speed_with(bencher: &mut Criterion, data: TestData, name: &str, options: EvalOptions) {
+    let mut builder = Builder::new();
+
+    let _ = builder.build(data.src.iter());
+
+    let executor = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let runtime = executor.block_on(builder.finish()).unwrap();
+
+    bencher.bench_function(&format!("{} {name}", data.id), |b| {
+        b.iter(|| {
+            let ctx = EvalContext::new_with_config(ConfigContext::default(), options.clone());
+            executor
+                .block_on(runtime.evaluate(&data.path, &data.value, ctx))
+                .unwrap();
+        })
+    });
+}
+
+fn build_speed(bencher: &mut Criterion, data: TestData) {
+    bencher.bench_function(&format!("{} build", data.id), |b| {
+        b.iter(|| {
+            let mut builder = Builder::new();
+            let _ = builder.build(data.src.clone().iter());
+        })
+    });
+}
+
+fn end_to_end_speed(bencher: &mut Criterion, data: TestData) {
+    let executor = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    bencher.bench_function(&format!("{} end 2 end", data.id), |b| {
+        b.iter(|| {
+            let data = data.clone();
+            executor.block_on(async move {
+                let mut builder = Builder::new();
+
+                let _ = builder.build(data.src.iter());
+                let runtime = builder.finish().await.unwrap();
+                runtime
+                    .evaluate(&data.path, &data.value, EvalContext::default())
+                    .await
+                    .unwrap()
+            });
+        })
+    });
+}
+
+#[derive(Clone)]
+struct TestData {
+    id: &'static str,
+    src: Ephemeral,
+    path: String,
+    value: JsonValue,
+}
+
+/*
+ * Smoke test group
+ *
+ * Basic tests with small in-memory examples.
+ */
+fn smoke_eval(bencher: &mut Criterion) {
+    eval_speed(bencher, testdata_smoke());
+}
+
+fn smoke_build(bencher: &mut Criterion) {
+    build_speed(bencher, testdata_smoke());
+}
+
+fn smoke_end_to_end(bencher: &mut Criterion) {
+    end_to_end_speed(bencher, testdata_smoke());
+}
+
+fn testdata_smoke() -> TestData {
+    let src = Ephemeral::new(
+        "smoke",
+        r#"
+        pattern named<name> = {
+            name: name
+        }
+
+        pattern jim = named<"Jim">
+        pattern bob = named<"Bob">
+
+        pattern folks = jim || bob
+        "#,
+    );
+
+    TestData {
+        id: "smoke",
+        src,
+        path: "smoke::bob".to_string(),
+        value: json!(
+            {
+                "name": "Bob"
+            }
+        ),
+    }
+}
+
+/*
+ * Sigstore test group
+ *
+ * More complex tests using sigstore verification.
+ */
+
+//fn sigstore_eval(bencher: &mut Criterion) {
+//    eval_speed(bencher, testdata_sigstore());
+//}
+//
+//fn sigstore_end_to_end(bencher: &mut Criterion) {
+//    end_to_end_speed(bencher, testdata_sigstore());
+//}
+
+fn sigstore_build(bencher: &mut Criterion) {
+    build_speed(bencher, testdata_sigstore());
+}
+
+fn testdata_sigstore() -> TestData {
+    let src = Ephemeral::new(
+        "foo::bar",
+        r#"
+            // Single-line comment, yay
+            pattern signed-thing = {
+                digest: sigstore::sha256(
+                    n<1>::{
+                        apiVersion: "0.0.1",
+                        spec: {
+                            signature: {
+                                publicKey: {
+                                    content: base64::base64(
+                                        x509::pem( n<1>::{
+                                            version: 2,
+                                            extensions: n<1>::{
+                                                subjectAlternativeName: n<1>::{
+                                                    rfc822: "bob@mcwhirter.org",
+                                                }
+                                            }
+                                        } )
+                                    )
+                                }
+                            }
+                        }
+                    }
+                )
+            }
+        "#,
+    );
+
+    let value = json!(
+        {
+            "digest": "5dd1e2b50b89874fd086da4b61176167ae9e4b434945325326690c8f604d0408"
+        }
+    );
+
+    TestData {
+        id: "sigstore",
+        src,
+        path: "foo::bar::signed-thing".to_string(),
+        value,
+    }
+}
+
+/*
+ * Object test group
+ *
+ * Evaluating an object pattern with many fields.
+ */
+fn object_eval(bencher: &mut Criterion) {
+    eval_speed(bencher, testdata_object());
+}
+
+fn testdata_object() -> TestData {
+    let fields: Vec<_> = (0..100).map(|i| format!("field-{i}: string,")).collect();
+    let src = Ephemeral::new(
+        "object",
+        format!(
+            r#"
+            pattern wide = {{
+                {}
+                count?: integer,
+            }}
+            "#,
+            fields.join("\n")
+        ),
+    );
+
+    let value: serde_json::Map<_, _> = (0..100)
+        .map(|i| (format!("field-{i}"), json!(format!("value {i}"))))
+        .collect();
+
+    TestData {
+        id: "object",
+        src,
+        path: "object::wide".to_string(),
+        value: JsonValue::Object(value),
+    }
+}
+
+/*
+ * List test group
+ *
+ * Evaluating `list::all` and `list::any` over a long list, `or` and `and` with and without
+ * short-circuiting.
+ */
+fn list_all_eval(bencher: &mut Criterion) {
+    eval_speed(bencher, testdata_list("all"));
+}
+
+fn list_any_eval(bencher: &mut Criterion) {
+    eval_speed(bencher, testdata_list("any"));
+}
+
+fn list_or_eval(bencher: &mut Criterion) {
+    let data = testdata_list("or");
+    eval_speed(bencher, data.clone());
+    eval_speed_with(
+        bencher,
+        data,
+        "eval short-circuit",
+        EvalOptions {
+            short_circuit: true,
+            ..Default::default()
+        },
+    );
+}
+
+fn testdata_list(pattern: &'static str) -> TestData {
+    let src = Ephemeral::new(
+        "list",
+        r#"
+        pattern component = {
+            name: string,
+            version: string,
+            licenses: list::all<string>,
+        }
+
+        pattern all = list::all<component>
+        pattern any = list::any<{ name: "component-999" }>
+        pattern or = list::all<{ name: string } || component>
+        "#,
+    );
+
+    let value = (0..1000)
+        .map(|i| {
+            json!({
+                "name": format!("component-{i}"),
+                "version": "1.0.0",
+                "licenses": ["Apache-2.0", "MIT"],
+            })
+        })
+        .collect();
+
+    TestData {
+        id: pattern,
+        src,
+        path: format!("list::{pattern}"),
+        value: JsonValue::Array(value),
+    }
+}
+
+/*
+ * Recursive test group
+ *
+ * Evaluating a recursive pattern against a deep tree.
+ */
+fn recursive_eval(bencher: &mut Criterion) {
+    eval_speed(bencher, testdata_recursive());
+}
+
+fn testdata_recursive() -> TestData {
+    fn tree(depth: usize) -> JsonValue {
+        match depth {
+            0 => json!({ "name": "leaf" }),
+            _ => json!({
+                "name": format!("node-{depth}"),
+                "children": [tree(depth - 1), tree(depth - 1)],
+            }),
+        }
+    }
+
+    let src = Ephemeral::new(
+        "recursive",
+        r#"
+        pattern tree = {
+            name: string,
+            children?: list::all<tree>,
+        }
+        "#,
+    );
+
+    TestData {
+        id: "recursive",
+        src,
+        path: "recursive::tree".to_string(),
+        value: tree(10),
+    }
+}
+
+/*
+ * Core test group
+ *
+ * Compiling the world of all core packages, without any policies.
+ */
+fn core_build(bencher: &mut Criterion) {
+    let executor = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    bencher.bench_function("core build", |b| {
+        b.iter(|| executor.block_on(Builder::new().finish()).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    smoke_build,
+    smoke_eval,
+    smoke_end_to_end,
+    sigstore_build,
+    // sigstore_eval,
+    // sigstore_end_to_end
+    object_eval,
+    list_all_eval,
+    list_any_eval,
+    list_or_eval,
+    recursive_eval,
+    core_build,
+);
+criterion_main!(benches);
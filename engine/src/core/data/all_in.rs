@@ -0,0 +1,112 @@
+use crate::core::data::{resolve, Values};
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::data::DataSource;
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("all-in.adoc");
+const SOURCE: &str = "source";
+
+#[derive(Debug)]
+pub struct AllIn {
+    data_sources: Arc<Vec<Arc<dyn DataSource>>>,
+}
+
+impl AllIn {
+    pub fn new(data_sources: Vec<Arc<dyn DataSource>>) -> Self {
+        Self {
+            data_sources: Arc::new(data_sources),
+        }
+    }
+}
+
+impl Function for AllIn {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![SOURCE.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(source)) = bindings
+                .get(SOURCE)
+                .and_then(|source| source.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("source must be a string literal"));
+            };
+
+            let Some(items) = input.try_get_list() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match resolve(&self.data_sources, &source)? {
+                None => Ok(invalid_arg(format!("no such data source: {source}"))),
+                Some(RuntimeValue::List(list)) => {
+                    let known = Values::new(&list);
+                    match items.iter().find(|item| !known.contains(item)) {
+                        None => Ok(Output::Identity.into()),
+                        Some(missing) => Ok(unsatisfied(format!(
+                            "{missing} is not contained in {source}"
+                        ))),
+                    }
+                }
+                Some(other) => Ok(invalid_arg(format!(
+                    "data source {source} is not a list, but {}",
+                    other.type_name()
+                ))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"data::all-in<"data/allowed-licenses.json">"#;
+
+    #[tokio::test]
+    async fn all_present() {
+        assert_satisfied!(test_pattern(PATTERN, json!(["MIT", "BSD-3-Clause"])).await);
+        assert_satisfied!(test_pattern(PATTERN, json!([])).await);
+    }
+
+    #[tokio::test]
+    async fn one_missing() {
+        let result = test_pattern(
+            PATTERN,
+            json!(["MIT", "GPL-3.0-only", "Apache-2.0", "LGPL-2.1-only"]),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "GPL-3.0-only is not contained in data/allowed-licenses.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_list() {
+        assert_not_satisfied!(test_pattern(PATTERN, json!("MIT")).await);
+    }
+}
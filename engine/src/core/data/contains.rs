@@ -0,0 +1,114 @@
+use crate::core::data::{resolve, Values};
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::data::DataSource;
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("contains.adoc");
+const SOURCE: &str = "source";
+
+#[derive(Debug)]
+pub struct Contains {
+    data_sources: Arc<Vec<Arc<dyn DataSource>>>,
+}
+
+impl Contains {
+    pub fn new(data_sources: Vec<Arc<dyn DataSource>>) -> Self {
+        Self {
+            data_sources: Arc::new(data_sources),
+        }
+    }
+}
+
+impl Function for Contains {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![SOURCE.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(source)) = bindings
+                .get(SOURCE)
+                .and_then(|source| source.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("source must be a string literal"));
+            };
+
+            match resolve(&self.data_sources, &source)? {
+                None => Ok(invalid_arg(format!("no such data source: {source}"))),
+                Some(RuntimeValue::List(list)) => {
+                    if Values::new(&list).contains(&input) {
+                        Ok(Output::Identity.into())
+                    } else {
+                        Ok(unsatisfied(format!("{input} is not contained in {source}")))
+                    }
+                }
+                Some(other) => Ok(invalid_arg(format!(
+                    "data source {source} is not a list, but {}",
+                    other.type_name()
+                ))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"data::contains<"data/allowed-licenses.json">"#;
+
+    #[tokio::test]
+    async fn present() {
+        assert_satisfied!(test_pattern(PATTERN, json!("MIT")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!("BSD-3-Clause")).await);
+    }
+
+    #[tokio::test]
+    async fn absent() {
+        let result = test_pattern(PATTERN, json!("GPL-3.0-only")).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "GPL-3.0-only is not contained in data/allowed-licenses.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_source() {
+        let result = test_pattern(r#"data::contains<"data/missing.json">"#, json!("MIT")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: no such data source: data/missing.json"
+        );
+
+        let result = test_pattern(r#"data::contains<"data/not-a-list.json">"#, json!("MIT")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: data source data/not-a-list.json is not a list, but object"
+        );
+    }
+}
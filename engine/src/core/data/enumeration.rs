@@ -0,0 +1,151 @@
+use crate::core::data::{resolve, Values};
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::data::DataSource;
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("enum-from-data.adoc");
+const SOURCE: &str = "source";
+const FIELD: &str = "field";
+
+#[derive(Debug)]
+pub struct Enumeration {
+    data_sources: Arc<Vec<Arc<dyn DataSource>>>,
+}
+
+impl Enumeration {
+    pub fn new(data_sources: Vec<Arc<dyn DataSource>>) -> Self {
+        Self {
+            data_sources: Arc::new(data_sources),
+        }
+    }
+}
+
+impl Function for Enumeration {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![SOURCE.into(), FIELD.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let (Some(ValuePattern::String(source)), Some(ValuePattern::String(field))) = (
+                bindings
+                    .get(SOURCE)
+                    .and_then(|source| source.try_get_resolved_value()),
+                bindings
+                    .get(FIELD)
+                    .and_then(|field| field.try_get_resolved_value()),
+            ) else {
+                return Ok(invalid_arg("source and field must be string literals"));
+            };
+
+            let msg = match resolve(&self.data_sources, &source)? {
+                None => format!("no such data source: {source}"),
+                Some(RuntimeValue::Object(object)) => match object.get(&*field).as_deref() {
+                    Some(RuntimeValue::List(list)) => {
+                        if Values::new(list).contains(&input) {
+                            return Ok(Output::Identity.into());
+                        }
+                        return Ok(unsatisfied(format!(
+                            "{input} is not one of the values of {field} in {source}"
+                        )));
+                    }
+                    Some(other) => format!(
+                        "field {field} of data source {source} is not a list, but {}",
+                        other.type_name()
+                    ),
+                    None => format!("data source {source} has no field {field}"),
+                },
+                Some(other) => format!(
+                    "data source {source} is not an object, but {}",
+                    other.type_name()
+                ),
+            };
+
+            Ok(invalid_arg(msg))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::enum-from-data<"data/allow-lists.json", "registries">"#;
+
+    #[tokio::test]
+    async fn allowed() {
+        assert_satisfied!(test_pattern(PATTERN, json!("quay.io")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!("registry.access.redhat.com")).await);
+    }
+
+    #[tokio::test]
+    async fn rejected() {
+        let result = test_pattern(PATTERN, json!("docker.io")).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "docker.io is not one of the values of registries in data/allow-lists.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_field() {
+        let result = test_pattern(
+            r#"lang::enum-from-data<"data/allow-lists.json", "licenses">"#,
+            json!("MIT"),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: data source data/allow-lists.json has no field licenses"
+        );
+
+        let result = test_pattern(
+            r#"lang::enum-from-data<"data/allow-lists.json", "owner">"#,
+            json!("ops"),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: field owner of data source data/allow-lists.json is not a list, but string"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_source() {
+        let result = test_pattern(
+            r#"lang::enum-from-data<"data/allowed-licenses.json", "licenses">"#,
+            json!("MIT"),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: data source data/allowed-licenses.json is not an object, but list"
+        );
+    }
+}
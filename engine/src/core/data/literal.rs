@@ -0,0 +1,47 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("literal.adoc");
+const VALUE: &str = "value";
+
+#[derive(Debug)]
+pub struct Literal;
+
+impl Function for Literal {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUE.into()]
+    }
+
+    fn internal(&self) -> bool {
+        // only produced by folding data lookups
+        true
+    }
+
+    fn call<'v>(
+        &'v self,
+        _input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            match bindings.get(VALUE).and_then(|v| v.try_get_resolved_value()) {
+                Some(value) => Ok(Output::Transform(Arc::new(RuntimeValue::from(&value))).into()),
+                None => Ok(Severity::Error.into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use crate::core::data::resolve;
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::data::DataSource;
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("lookup-by-key.adoc");
+const SOURCE: &str = "source";
+const KEY_FIELD: &str = "key-field";
+
+#[derive(Debug)]
+pub struct LookupByKey {
+    data_sources: Arc<Vec<Arc<dyn DataSource>>>,
+}
+
+impl LookupByKey {
+    pub fn new(data_sources: Vec<Arc<dyn DataSource>>) -> Self {
+        Self {
+            data_sources: Arc::new(data_sources),
+        }
+    }
+}
+
+impl Function for LookupByKey {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![SOURCE.into(), KEY_FIELD.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(source)) = bindings
+                .get(SOURCE)
+                .and_then(|source| source.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("source must be a string literal"));
+            };
+            let Some(ValuePattern::String(field)) = bindings
+                .get(KEY_FIELD)
+                .and_then(|field| field.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("key-field must be a string literal"));
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+            let Some(key) = object.get(&*field) else {
+                return Ok((Severity::Error, Rationale::MissingField(field.into())).into());
+            };
+            let Some(key) = key.try_get_str() else {
+                return Ok(unsatisfied(format!("key field {field} is not a string")));
+            };
+
+            match resolve(&self.data_sources, &source)? {
+                None => Ok(invalid_arg(format!("no such data source: {source}"))),
+                Some(RuntimeValue::Object(entries)) => match entries.get(key) {
+                    Some(value) => Ok(Output::Transform(value).into()),
+                    None => Ok(unsatisfied(format!("no entry for {key} in {source}"))),
+                },
+                Some(other) => Ok(invalid_arg(format!(
+                    "data source {source} is not an object, but {}",
+                    other.type_name()
+                ))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"data::lookup-by-key<"data/inventory.json", "name">"#;
+
+    #[tokio::test]
+    async fn present() {
+        let result = test_pattern(PATTERN, json!({"name": "acme-lib"})).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"version": "1.2.3", "license": "Apache-2.0"})
+        );
+    }
+
+    #[tokio::test]
+    async fn further_matching() {
+        let pattern = format!(r#"{PATTERN}({{ license: "MIT" }})"#);
+
+        assert_satisfied!(test_pattern(&pattern, json!({"name": "widgets"})).await);
+        assert_not_satisfied!(test_pattern(&pattern, json!({"name": "acme-lib"})).await);
+    }
+
+    #[tokio::test]
+    async fn absent() {
+        let result = test_pattern(PATTERN, json!({"name": "gadgets"})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "no entry for gadgets in data/inventory.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_key_field() {
+        let result = test_pattern(PATTERN, json!({"version": "1.0.0"})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(result.rationale().reason(), "missing field: name");
+    }
+
+    #[tokio::test]
+    async fn invalid_source() {
+        let result = test_pattern(
+            r#"data::lookup-by-key<"data/allowed-licenses.json", "name">"#,
+            json!({"name": "MIT"}),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: data source data/allowed-licenses.json is not an object, but list"
+        );
+    }
+}
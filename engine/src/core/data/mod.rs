@@ -1,17 +1,92 @@
 use crate::data::DataSource;
 use crate::package::Package;
-use crate::runtime::PackagePath;
+use crate::runtime::{PackagePath, RuntimeError};
+use crate::value::RuntimeValue;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+mod all_in;
+mod contains;
+mod enumeration;
 mod from;
+mod literal;
 mod lookup;
+mod lookup_by_key;
 
+use crate::core::data::all_in::AllIn;
+use crate::core::data::contains::Contains;
+use crate::core::data::enumeration::Enumeration;
 use crate::core::data::from::From;
+use crate::core::data::literal::Literal;
 use crate::core::data::lookup::Lookup;
+use crate::core::data::lookup_by_key::LookupByKey;
 
 pub fn package(data_sources: Vec<Arc<dyn DataSource>>) -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["data"]));
     pkg.register_function("from".into(), From::new(data_sources.clone()));
     pkg.register_function("lookup".into(), Lookup::new(data_sources.clone()));
+    pkg.register_function(
+        "lookup-by-key".into(),
+        LookupByKey::new(data_sources.clone()),
+    );
+    pkg.register_function("contains".into(), Contains::new(data_sources.clone()));
+    pkg.register_function("all-in".into(), AllIn::new(data_sources.clone()));
+    pkg.register_function("literal".into(), Literal);
     pkg
 }
+
+/// Register the functions of the `lang` package reading the data sources, as those are only
+/// known once the world is lowered or restored.
+pub fn register_lang_functions(lang: &mut Package, data_sources: Vec<Arc<dyn DataSource>>) {
+    lang.register_function("enum-from-data".into(), Enumeration::new(data_sources));
+}
+
+/// Get the data at `path`, from the first of the data sources providing it.
+fn resolve(
+    data_sources: &[Arc<dyn DataSource>],
+    path: &str,
+) -> Result<Option<RuntimeValue>, RuntimeError> {
+    for ds in data_sources {
+        if let Some(value) = ds.get(path)? {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// The values of a list from a data source, indexed for checking membership.
+struct Values<'a> {
+    list: &'a [Arc<RuntimeValue>],
+    strings: HashSet<&'a str>,
+    others: Vec<&'a RuntimeValue>,
+}
+
+impl<'a> Values<'a> {
+    fn new(list: &'a [Arc<RuntimeValue>]) -> Self {
+        let mut strings = HashSet::new();
+        let mut others = Vec::new();
+        for value in list {
+            match value.try_get_str() {
+                Some(value) => {
+                    strings.insert(value);
+                }
+                None => others.push(&**value),
+            }
+        }
+        Self {
+            list,
+            strings,
+            others,
+        }
+    }
+
+    fn contains(&self, value: &RuntimeValue) -> bool {
+        // strings, the common case, are looked up directly, but values of other types may still
+        // equal them, like octets do
+        match value.try_get_str() {
+            Some(string) if self.strings.contains(string) => true,
+            Some(_) => self.others.iter().any(|known| **known == *value),
+            None => self.list.iter().any(|known| **known == *value),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::package::Package;
+use crate::runtime::PackagePath;
+use crate::value::RuntimeValue;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+use base64::Engine;
+use serde::{Serialize, Serializer};
+
+mod payload;
+#[cfg(feature = "sigstore")]
+mod verify;
+
+pub fn package() -> Package {
+    let mut pkg = Package::new(PackagePath::from_parts(vec!["dsse"])).with_documentation(
+        "Functions for processing DSSE (Dead Simple Signing Envelope) envelopes",
+    );
+    pkg.register_function("payload".into(), payload::Payload);
+    #[cfg(feature = "sigstore")]
+    pkg.register_function("verify".into(), verify::Verify);
+    pkg
+}
+
+/// A DSSE envelope, with its payload decoded.
+///
+/// It serializes to the JSON representation of the specification, with a base64 encoded payload.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Envelope {
+    pub payload_type: String,
+    #[serde(serialize_with = "serialize_base64")]
+    pub payload: Vec<u8>,
+    pub signatures: Vec<Signature>,
+}
+
+/// A signature of an envelope.
+#[derive(Clone, Debug, Serialize)]
+pub struct Signature {
+    /// Identifies the key which created the signature, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyid: Option<String>,
+    /// The base64 encoded signature of the [`pae`] of the envelope.
+    pub sig: String,
+}
+
+impl Envelope {
+    /// Decode an envelope, checking its structure.
+    fn decode(input: &RuntimeValue) -> Result<Self, String> {
+        let Some(envelope) = input.try_get_object() else {
+            return Err(format!("envelope must be an object, but is {}", input.type_name()));
+        };
+        let field = |name: &str| {
+            envelope
+                .get(name)
+                .ok_or_else(|| format!("envelope is missing the {name} field"))
+        };
+
+        let payload_type = field("payloadType")?;
+        let Some(payload_type) = payload_type.try_get_str() else {
+            return Err("payloadType must be a string".into());
+        };
+
+        let payload = field("payload")?;
+        let Some(payload) = payload.try_get_str() else {
+            return Err("payload must be a string".into());
+        };
+        // the specification allows both alphabets
+        let payload = STANDARD
+            .decode(payload)
+            .or_else(|_| URL_SAFE.decode(payload))
+            .map_err(|err| format!("payload is not base64 encoded: {err}"))?;
+
+        let signatures = field("signatures")?;
+        let Some(signatures) = signatures.try_get_list() else {
+            return Err("signatures must be a list".into());
+        };
+        let signatures = signatures
+            .iter()
+            .map(|signature| {
+                let signature = signature.try_get_object();
+                let sig = signature
+                    .and_then(|signature| signature.get("sig"))
+                    .and_then(|sig| sig.try_get_str().map(ToString::to_string))
+                    .ok_or_else(|| "signatures must have a sig string".to_string())?;
+                let keyid = signature
+                    .and_then(|signature| signature.get("keyid"))
+                    .and_then(|keyid| keyid.try_get_str().map(ToString::to_string));
+                Ok(Signature { keyid, sig })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            payload_type: payload_type.into(),
+            payload,
+            signatures,
+        })
+    }
+
+    /// The payload, parsed according to its type.
+    ///
+    /// JSON payloads (e.g. `application/vnd.in-toto+json`) are parsed, text payloads become a
+    /// string, and all others are provided as octets.
+    fn parsed_payload(&self) -> Result<RuntimeValue, String> {
+        let media_type = self
+            .payload_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if media_type == "application/json" || media_type.ends_with("+json") {
+            serde_json::from_slice::<serde_json::Value>(&self.payload)
+                .map(Into::into)
+                .map_err(|err| format!("payload is not valid JSON: {err}"))
+        } else if media_type.starts_with("text/") {
+            String::from_utf8(self.payload.clone())
+                .map(Into::into)
+                .map_err(|err| format!("payload is not valid UTF-8: {err}"))
+        } else {
+            Ok(self.payload.clone().into())
+        }
+    }
+
+    /// The Pre-Authentication Encoding (PAE) of the envelope, which is what actually gets signed.
+    pub fn pae(&self) -> Vec<u8> {
+        pae(&self.payload_type, &self.payload)
+    }
+}
+
+/// The Pre-Authentication Encoding (PAE) of a payload, which is what actually gets signed.
+pub fn pae(payload_type: &str, payload: &[u8]) -> Vec<u8> {
+    let mut pae = format!(
+        "DSSEv1 {} {} {} ",
+        payload_type.len(),
+        payload_type,
+        payload.len()
+    )
+    .into_bytes();
+    pae.extend_from_slice(payload);
+    pae
+}
+
+fn serialize_base64<S: Serializer>(payload: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(payload))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_data_dir;
+    use std::fs;
+
+    /// An envelope, created by Tekton Chains, wrapping an in-toto statement.
+    pub(crate) fn envelope() -> serde_json::Value {
+        let envelope = fs::read_to_string(
+            test_data_dir()
+                .join("intoto")
+                .join("tekton-chains-envelope.json"),
+        )
+        .unwrap();
+        serde_json::from_str(&envelope).unwrap()
+    }
+}
@@ -0,0 +1,126 @@
+use super::Envelope;
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("payload.adoc");
+
+#[derive(Debug)]
+pub struct Payload;
+
+impl Function for Payload {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            match Envelope::decode(&input).and_then(|envelope| envelope.parsed_payload()) {
+                Ok(payload) => Ok(Output::Transform(Arc::new(payload)).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::dsse::test::envelope;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn in_toto_statement() {
+        let result = test_pattern("dsse::payload", envelope()).await;
+        assert_satisfied!(&result);
+
+        let statement = result.output().as_json();
+        assert_eq!(statement["_type"], "https://in-toto.io/Statement/v0.1");
+        assert_eq!(
+            statement["predicateType"],
+            "https://slsa.dev/provenance/v0.2"
+        );
+        assert_eq!(statement["subject"][0]["name"], "gcr.io/foo/bar");
+
+        assert_satisfied!(
+            test_pattern(
+                r#"dsse::payload({ predicateType: "https://slsa.dev/provenance/v0.2" })"#,
+                envelope()
+            )
+            .await
+        );
+        assert_not_satisfied!(
+            test_pattern(
+                r#"dsse::payload({ predicateType: "https://spdx.dev/Document" })"#,
+                envelope()
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn other_payload_types() {
+        let result = test_pattern(
+            "dsse::payload",
+            json!({"payloadType": "text/plain", "payload": "aGVsbG8=", "signatures": []}),
+        )
+        .await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!("hello"));
+
+        let result = test_pattern(
+            "dsse::payload",
+            json!({"payloadType": "application/octet-stream", "payload": "AAE=", "signatures": []}),
+        )
+        .await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_octets(), Some(&vec![0u8, 1]));
+    }
+
+    #[tokio::test]
+    async fn invalid_envelope() {
+        let result = test_pattern(
+            "dsse::payload",
+            json!({"payloadType": "application/json", "signatures": []}),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "envelope is missing the payload field"
+        );
+
+        let result = test_pattern(
+            "dsse::payload",
+            json!({"payloadType": "application/json", "payload": "not base64!", "signatures": []}),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+
+        let result = test_pattern(
+            "dsse::payload",
+            json!({"payloadType": "application/json", "payload": "aGVsbG8=", "signatures": []}),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("payload is not valid JSON"));
+    }
+}
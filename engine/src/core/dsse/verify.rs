@@ -0,0 +1,101 @@
+use super::Envelope;
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use sigstore::cosign::client::Client as Cosign;
+use sigstore::cosign::CosignCapabilities;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("verify.adoc");
+const KEY: &str = "key";
+
+#[derive(Debug)]
+pub struct Verify;
+
+impl Function for Verify {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![KEY.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(key)) = bindings
+                .get(KEY)
+                .and_then(|key| key.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("key must be a PEM encoded public key"));
+            };
+
+            let envelope = match Envelope::decode(&input) {
+                Ok(envelope) => envelope,
+                Err(msg) => return Ok(unsatisfied(msg)),
+            };
+
+            let pae = envelope.pae();
+            let verified = envelope.signatures.iter().any(|signature| {
+                Cosign::verify_blob_with_public_key(key.trim(), &signature.sig, &pae).is_ok()
+            });
+            if !verified {
+                return Ok(unsatisfied("no signature could be verified with the key"));
+            }
+
+            match envelope.parsed_payload() {
+                Ok(payload) => Ok(Output::Transform(Arc::new(payload)).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::dsse::test::envelope;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const VERIFY: &str = r#"dsse::verify<"-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEqiLuArRcZCY1s650rgKUDpj7f+b8
+9HMu3K/PDaUcR9kcyyXY8q6U+TFTkc9u84wJTsZe21wBPd/STPEzo0JrzQ==
+-----END PUBLIC KEY-----">"#;
+
+    #[tokio::test]
+    async fn verified() {
+        let result = test_pattern(VERIFY, envelope()).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json()["predicateType"],
+            "https://slsa.dev/provenance/v0.2"
+        );
+    }
+
+    #[tokio::test]
+    async fn tampered() {
+        let mut envelope = envelope();
+        envelope["payloadType"] = json!("application/json");
+
+        let result = test_pattern(VERIFY, envelope).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "no signature could be verified with the key"
+        );
+    }
+}
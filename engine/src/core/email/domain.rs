@@ -0,0 +1,103 @@
+use crate::core::email::parse::{invalid_address, Address};
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult, FunctionInput};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, Pattern, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("domain.adoc");
+const VALUE: &str = "value";
+
+#[derive(Debug)]
+pub struct Domain;
+
+impl Function for Domain {
+    fn input(&self, _bindings: &[Arc<Pattern>]) -> FunctionInput {
+        FunctionInput::String
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(expected)) = bindings
+                .get(VALUE)
+                .and_then(|value| value.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("value must be a string literal"));
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            let address = match Address::parse(value) {
+                Ok(address) => address,
+                Err(msg) => return Ok(invalid_address(value, msg)),
+            };
+
+            if address.domain.eq_ignore_ascii_case(&expected) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = format!("expected domain '{expected}', found '{}'", address.domain);
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"email::domain<"example.com">"#;
+
+    #[tokio::test]
+    async fn matching_domain() {
+        assert_satisfied!(test_pattern(PATTERN, json!("jane@example.com")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!("jane@EXAMPLE.com")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!("jane+ci@example.com")).await);
+    }
+
+    #[tokio::test]
+    async fn other_domain() {
+        let result = test_pattern(PATTERN, json!("jane@mail.example.com")).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "expected domain 'example.com', found 'mail.example.com'"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_address() {
+        let result = test_pattern(PATTERN, json!("example.com")).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "'example.com' is not a valid email address: missing '@'"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::package::Package;
+use crate::runtime::PackagePath;
+
+mod domain;
+mod parse;
+
+pub fn package() -> Package {
+    let mut pkg = Package::new(PackagePath::from_parts(vec!["email"]))
+        .with_documentation("Utilities for working with email addresses");
+    pkg.register_source("".into(), include_str!("email.dog"));
+    pkg.register_function("parse".into(), parse::Parse);
+    pkg.register_function("domain".into(), domain::Domain);
+    pkg
+}
@@ -0,0 +1,223 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult, FunctionInput};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, Pattern, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("parse.adoc");
+
+/// Characters allowed in the local part, besides ASCII letters and digits (RFC 5322 `atext`).
+const ATEXT: &str = "!#$%&'*+-/=?^_`{|}~";
+
+#[derive(Debug)]
+pub struct Parse;
+
+impl Function for Parse {
+    fn input(&self, _bindings: &[Arc<Pattern>]) -> FunctionInput {
+        FunctionInput::String
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match Address::parse(value) {
+                Ok(address) => {
+                    let mut result = Object::new();
+                    result.set("local-part", address.local_part);
+                    result.set("user", address.user);
+                    if let Some(tag) = address.tag {
+                        result.set("tag", tag);
+                    }
+                    result.set("domain", address.domain);
+                    Ok(Output::Transform(Arc::new(result.into())).into())
+                }
+                Err(msg) => Ok(invalid_address(value, msg)),
+            }
+        })
+    }
+}
+
+/// An email address, split into its parts.
+pub(crate) struct Address<'a> {
+    pub local_part: &'a str,
+    /// The local part, without the subaddress.
+    pub user: &'a str,
+    /// The subaddress, following the first `+` of the local part.
+    pub tag: Option<&'a str>,
+    /// The domain, in lower case.
+    pub domain: String,
+}
+
+impl<'a> Address<'a> {
+    /// Parse an address in the `dot-atom` form of RFC 5322.
+    ///
+    /// Quoted local parts, comments, and domain literals (like `[10.0.0.1]`) are not supported.
+    pub fn parse(value: &'a str) -> Result<Self, &'static str> {
+        let Some((local_part, domain)) = value.split_once('@') else {
+            return Err("missing '@'");
+        };
+
+        if value.len() > 254 {
+            return Err("longer than 254 characters");
+        }
+        if local_part.is_empty() {
+            return Err("empty local part");
+        }
+        if local_part.len() > 64 {
+            return Err("local part longer than 64 characters");
+        }
+        if !local_part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || ATEXT.contains(c))
+        {
+            return Err("invalid character in local part");
+        }
+        if local_part.split('.').any(str::is_empty) {
+            return Err("misplaced '.' in local part");
+        }
+
+        if domain.is_empty() {
+            return Err("empty domain");
+        }
+        if domain.contains('@') {
+            return Err("more than one '@'");
+        }
+        for label in domain.split('.') {
+            if label.is_empty() {
+                return Err("empty label in domain");
+            }
+            if label.len() > 63 {
+                return Err("domain label longer than 63 characters");
+            }
+            if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err("invalid character in domain");
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err("domain label starting or ending with '-'");
+            }
+        }
+
+        let (user, tag) = match local_part.split_once('+') {
+            Some((user, tag)) => (user, Some(tag)),
+            None => (local_part, None),
+        };
+
+        Ok(Self {
+            local_part,
+            user,
+            tag,
+            domain: domain.to_ascii_lowercase(),
+        })
+    }
+}
+
+pub(crate) fn invalid_address(value: &str, msg: &str) -> FunctionEvaluationResult {
+    unsatisfied(format!("'{value}' is not a valid email address: {msg}"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn valid() {
+        let result = test_pattern("email::parse", json!("Jane.Doe@Example.COM")).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output(),
+            Arc::new(
+                json!({
+                    "local-part": "Jane.Doe",
+                    "user": "Jane.Doe",
+                    "domain": "example.com",
+                })
+                .into()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn subaddressed() {
+        let result = test_pattern("email::parse", json!("user+tag@example.com")).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output(),
+            Arc::new(
+                json!({
+                    "local-part": "user+tag",
+                    "user": "user",
+                    "tag": "tag",
+                    "domain": "example.com",
+                })
+                .into()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn structure() {
+        let result = test_pattern(
+            "email::parse | email::address",
+            json!("user+tag@example.com"),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        for (value, reason) in [
+            ("jane.example.com", "missing '@'"),
+            ("@example.com", "empty local part"),
+            ("jane@", "empty domain"),
+            ("jane@doe@example.com", "more than one '@'"),
+            ("jane..doe@example.com", "misplaced '.' in local part"),
+            (".jane@example.com", "misplaced '.' in local part"),
+            ("jane doe@example.com", "invalid character in local part"),
+            ("jane@example..com", "empty label in domain"),
+            ("jane@example_corp.com", "invalid character in domain"),
+            (
+                "jane@-example.com",
+                "domain label starting or ending with '-'",
+            ),
+        ] {
+            let result = test_pattern("email::parse", json!(value)).await;
+
+            assert_not_satisfied!(&result);
+            assert_eq!(
+                result.rationale().reason(),
+                format!("'{value}' is not a valid email address: {reason}")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn not_a_string() {
+        assert_not_satisfied!(test_pattern("email::parse", json!(42)).await);
+    }
+}
@@ -73,6 +73,10 @@ impl Function for Remote {
         192
     }
 
+    fn deterministic(&self) -> bool {
+        false
+    }
+
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
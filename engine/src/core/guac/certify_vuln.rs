@@ -85,6 +85,9 @@ impl Function for CertifyVuln {
         // Reaching out to the network
         200
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
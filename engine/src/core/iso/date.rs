@@ -0,0 +1,162 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use chrono::NaiveDate;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("date.adoc");
+
+#[derive(Debug)]
+pub struct Date;
+
+impl Function for Date {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            if input.is_timestamp() {
+                let msg = format!("{input} is a date and time, not a date only");
+                return Ok(unsatisfied(msg));
+            }
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match parse(value) {
+                Ok(date) => Ok(Output::Transform(Arc::new(date.into())).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+/// Parse an ISO 8601 calendar date in the extended format, possibly with a reduced precision.
+fn parse(value: &str) -> Result<Object, String> {
+    if value.contains('T') {
+        return Err(format!("{value} is a date and time, not a date only"));
+    }
+
+    let invalid = || format!("{value} is not an ISO 8601 calendar date");
+    let parts: Vec<_> = value.split('-').collect();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+    let year = digits(parts[0], 4).ok_or_else(invalid)?;
+
+    let mut date = Object::new();
+    date.set("year", year);
+
+    let Some(month) = parts.get(1) else {
+        date.set("precision", "year");
+        return Ok(date);
+    };
+    let month = digits(month, 2).ok_or_else(invalid)?;
+    if !(1..=12).contains(&month) {
+        return Err(format!("{value} has an invalid month: {month}"));
+    }
+    date.set("month", month);
+
+    let Some(day) = parts.get(2) else {
+        date.set("precision", "month");
+        return Ok(date);
+    };
+    let day = digits(day, 2).ok_or_else(invalid)?;
+    if NaiveDate::from_ymd_opt(year as i32, month, day).is_none() {
+        return Err(format!("{value} has an invalid day: {day}"));
+    }
+    date.set("day", day);
+    date.set("precision", "day");
+
+    Ok(date)
+}
+
+/// Parse a number consisting of exactly `len` digits.
+pub(crate) fn digits(value: &str, len: usize) -> Option<u32> {
+    if value.len() == len && value.bytes().all(|b| b.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn full_date() {
+        let result = test_pattern("iso::date", json!("2023-01-15")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"year": 2023, "month": 1, "day": 15, "precision": "day"})
+        );
+
+        assert_satisfied!(test_pattern("iso::date({ month: 1 })", json!("2023-01-15")).await);
+    }
+
+    #[tokio::test]
+    async fn reduced_precision() {
+        let result = test_pattern("iso::date", json!("2023-01")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"year": 2023, "month": 1, "precision": "month"})
+        );
+
+        let result = test_pattern("iso::date", json!("2023")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"year": 2023, "precision": "year"})
+        );
+    }
+
+    #[tokio::test]
+    async fn full_datetime() {
+        let result = test_pattern("iso::date", json!("2023-01-15T10:30:00Z")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2023-01-15T10:30:00Z is a date and time, not a date only"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        let result = test_pattern("iso::date", json!("2023-02-30")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2023-02-30 has an invalid day: 30"
+        );
+
+        let result = test_pattern("iso::date", json!("2023-13")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2023-13 has an invalid month: 13"
+        );
+
+        assert_not_satisfied!(test_pattern("iso::date", json!("202301")).await);
+        assert_not_satisfied!(test_pattern("iso::date", json!("2023-1-5")).await);
+        assert_not_satisfied!(test_pattern("iso::date", json!(2023)).await);
+    }
+}
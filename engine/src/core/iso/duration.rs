@@ -0,0 +1,190 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("duration.adoc");
+
+/// The designators of the date components, in order.
+const DATE: &[(char, &str)] = &[
+    ('Y', "years"),
+    ('M', "months"),
+    ('W', "weeks"),
+    ('D', "days"),
+];
+/// The designators of the time components, in order.
+const TIME: &[(char, &str)] = &[('H', "hours"), ('M', "minutes"), ('S', "seconds")];
+
+#[derive(Debug)]
+pub struct Duration;
+
+impl Function for Duration {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match parse(value) {
+                Ok(duration) => Ok(Output::Transform(Arc::new(duration.into())).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+/// Parse an ISO 8601 duration, in the format using designators (`PnYnMnDTnHnMnS` or `PnW`).
+fn parse(value: &str) -> Result<Object, String> {
+    let invalid = |reason: &str| format!("{value} is not an ISO 8601 duration: {reason}");
+
+    let Some(duration) = value.strip_prefix('P') else {
+        return Err(invalid("it must start with P"));
+    };
+    let (date, time) = match duration.split_once('T') {
+        Some((_, "")) => return Err(invalid("a T must be followed by time components")),
+        Some((date, time)) => (date, Some(time)),
+        None => (duration, None),
+    };
+
+    let mut components = Vec::new();
+    components_into(date, DATE, &mut components).map_err(|reason| invalid(&reason))?;
+    if let Some(time) = time {
+        components_into(time, TIME, &mut components).map_err(|reason| invalid(&reason))?;
+    }
+
+    if components.is_empty() {
+        return Err(invalid("it has no components"));
+    }
+    // only the smallest component may have a fraction
+    let last = components.len() - 1;
+    if components[..last]
+        .iter()
+        .any(|(_, n)| n.contains(['.', ',']))
+    {
+        return Err(invalid("only the last component may have a fraction"));
+    }
+
+    let mut result = Object::new();
+    for (name, number) in components {
+        let number = number.replace(',', ".");
+        if number.contains('.') {
+            let number: f64 = number.parse().map_err(|_| invalid("invalid number"))?;
+            result.set(name, number);
+        } else {
+            let number: i64 = number.parse().map_err(|_| invalid("invalid number"))?;
+            result.set(name, number);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Split a section of a duration into its numbers, with the names of their designators.
+fn components_into<'a>(
+    mut section: &'a str,
+    designators: &[(char, &'static str)],
+    components: &mut Vec<(&'static str, &'a str)>,
+) -> Result<(), String> {
+    let mut designators = designators.iter();
+
+    while !section.is_empty() {
+        let Some(idx) = section.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')) else {
+            return Err(format!("{section} is missing a designator"));
+        };
+        let (number, rest) = section.split_at(idx);
+        let mut chars = rest.chars();
+        let designator = chars.next().unwrap_or_default();
+        if number.is_empty() {
+            return Err(format!("{designator} is missing a number"));
+        }
+
+        // the designators must be provided in order, which also prevents duplicates
+        let Some((_, name)) = designators.find(|(d, _)| *d == designator) else {
+            return Err(format!("unexpected designator {designator}"));
+        };
+        components.push((*name, number));
+        section = chars.as_str();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn full_duration() {
+        let result = test_pattern("iso::duration", json!("P1Y2M10DT2H30M")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"years": 1, "months": 2, "days": 10, "hours": 2, "minutes": 30})
+        );
+
+        assert_satisfied!(
+            test_pattern("iso::duration({ hours: 2 })", json!("P1Y2M10DT2H30M")).await
+        );
+    }
+
+    #[tokio::test]
+    async fn partial_duration() {
+        let result = test_pattern("iso::duration", json!("PT1M")).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"minutes": 1}));
+
+        let result = test_pattern("iso::duration", json!("P2W")).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"weeks": 2}));
+
+        let result = test_pattern("iso::duration", json!("PT1H0,5S")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"hours": 1, "seconds": 0.5})
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        let result = test_pattern("iso::duration", json!("P1M1Y")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "P1M1Y is not an ISO 8601 duration: unexpected designator Y"
+        );
+
+        let result = test_pattern("iso::duration", json!("P1DT")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "P1DT is not an ISO 8601 duration: a T must be followed by time components"
+        );
+
+        assert_not_satisfied!(test_pattern("iso::duration", json!("P")).await);
+        assert_not_satisfied!(test_pattern("iso::duration", json!("P1.5YT1H")).await);
+        assert_not_satisfied!(test_pattern("iso::duration", json!("P1H")).await);
+        assert_not_satisfied!(test_pattern("iso::duration", json!("1Y")).await);
+        assert_not_satisfied!(test_pattern("iso::duration", json!("PY")).await);
+        assert_not_satisfied!(test_pattern("iso::duration", json!(10)).await);
+    }
+}
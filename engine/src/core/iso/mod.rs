@@ -1,8 +1,15 @@
 use crate::package::Package;
 use crate::runtime::PackagePath;
 
+mod date;
+mod duration;
+mod time;
+
 pub fn package() -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["iso"]));
     pkg.register_source("swid".into(), include_str!("swid.dog"));
+    pkg.register_function("date".into(), date::Date);
+    pkg.register_function("time".into(), time::Time);
+    pkg.register_function("duration".into(), duration::Duration);
     pkg
 }
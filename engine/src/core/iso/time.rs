@@ -0,0 +1,219 @@
+use super::date::digits;
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("time.adoc");
+
+#[derive(Debug)]
+pub struct Time;
+
+impl Function for Time {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            if input.is_timestamp() {
+                let msg = format!("{input} is a date and time, not a time only");
+                return Ok(unsatisfied(msg));
+            }
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match parse(value) {
+                Ok(time) => Ok(Output::Transform(Arc::new(time.into())).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+/// Parse an ISO 8601 time of day in the extended format, possibly with a reduced precision.
+fn parse(value: &str) -> Result<Object, String> {
+    let time = value.strip_prefix('T').unwrap_or(value);
+    if time.contains('T') || time.get(4..5) == Some("-") {
+        return Err(format!("{value} is a date and time, not a time only"));
+    }
+
+    let invalid = || format!("{value} is not an ISO 8601 time");
+
+    let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+        (time, Some(0))
+    } else if let Some(idx) = time.find(['+', '-']) {
+        let (time, offset) = time.split_at(idx);
+        (time, Some(parse_offset(offset).ok_or_else(invalid)?))
+    } else {
+        (time, None)
+    };
+
+    let parts: Vec<_> = time.split(':').collect();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+
+    let mut result = Object::new();
+
+    let hour = digits(parts[0], 2).ok_or_else(invalid)?;
+    if hour > 23 {
+        return Err(format!("{value} has an invalid hour: {hour}"));
+    }
+    result.set("hour", hour);
+
+    let precision = match (parts.get(1), parts.get(2)) {
+        (None, _) => "hour",
+        (Some(minute), second) => {
+            let minute = digits(minute, 2).ok_or_else(invalid)?;
+            if minute > 59 {
+                return Err(format!("{value} has an invalid minute: {minute}"));
+            }
+            result.set("minute", minute);
+
+            match second {
+                None => "minute",
+                Some(second) => {
+                    let (second, fraction) = match second.split_once(['.', ',']) {
+                        Some((second, fraction)) => (second, Some(fraction)),
+                        None => (second, None),
+                    };
+                    let second = digits(second, 2).ok_or_else(invalid)?;
+                    // allow for leap seconds
+                    if second > 60 {
+                        return Err(format!("{value} has an invalid second: {second}"));
+                    }
+                    result.set("second", second);
+
+                    if let Some(fraction) = fraction {
+                        let nanosecond = parse_fraction(fraction).ok_or_else(invalid)?;
+                        result.set("nanosecond", nanosecond);
+                    }
+                    "second"
+                }
+            }
+        }
+    };
+
+    if let Some(offset) = offset {
+        result.set("offset", offset);
+    }
+    result.set("precision", precision);
+
+    Ok(result)
+}
+
+/// Parse a UTC offset (`±hh:mm`, `±hhmm` or `±hh`) into minutes.
+fn parse_offset(offset: &str) -> Option<i64> {
+    if !offset.is_ascii() {
+        return None;
+    }
+    let (sign, offset) = match offset.split_at(1) {
+        ("+", offset) => (1, offset),
+        ("-", offset) => (-1, offset),
+        _ => return None,
+    };
+    let (hours, minutes) = match offset.len() {
+        2 => (offset, "00"),
+        4 => offset.split_at(2),
+        5 if &offset[2..3] == ":" => (&offset[..2], &offset[3..]),
+        _ => return None,
+    };
+    let hours = digits(hours, 2).filter(|h| *h < 24)?;
+    let minutes = digits(minutes, 2).filter(|m| *m < 60)?;
+
+    Some(sign * (hours * 60 + minutes) as i64)
+}
+
+/// Parse the decimal fraction of a second into nanoseconds, ignoring any digits beyond those.
+fn parse_fraction(fraction: &str) -> Option<u32> {
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let fraction = format!("{:0<9}", &fraction[..fraction.len().min(9)]);
+    fraction.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn full_time() {
+        let result = test_pattern("iso::time", json!("10:30:15.25+02:00")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({
+                "hour": 10,
+                "minute": 30,
+                "second": 15,
+                "nanosecond": 250_000_000,
+                "offset": 120,
+                "precision": "second",
+            })
+        );
+
+        let result = test_pattern("iso::time", json!("T23:59:60Z")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"hour": 23, "minute": 59, "second": 60, "offset": 0, "precision": "second"})
+        );
+    }
+
+    #[tokio::test]
+    async fn reduced_precision() {
+        let result = test_pattern("iso::time", json!("10:30")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"hour": 10, "minute": 30, "precision": "minute"})
+        );
+
+        let result = test_pattern("iso::time", json!("10-0330")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"hour": 10, "offset": -210, "precision": "hour"})
+        );
+    }
+
+    #[tokio::test]
+    async fn full_datetime() {
+        let result = test_pattern("iso::time", json!("2023-01-15T10:30:00Z")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2023-01-15T10:30:00Z is a date and time, not a time only"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        let result = test_pattern("iso::time", json!("24:00")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(result.rationale().reason(), "24:00 has an invalid hour: 24");
+
+        assert_not_satisfied!(test_pattern("iso::time", json!("10:3")).await);
+        assert_not_satisfied!(test_pattern("iso::time", json!("10:30:00+2")).await);
+        assert_not_satisfied!(test_pattern("iso::time", json!("10:30:00.")).await);
+        assert_not_satisfied!(test_pattern("iso::time", json!(10)).await);
+    }
+}
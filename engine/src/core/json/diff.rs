@@ -0,0 +1,221 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("diff.adoc");
+
+const BASELINE: &str = "baseline";
+
+#[derive(Debug)]
+pub struct Diff;
+
+impl Function for Diff {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![BASELINE.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(baseline) = bindings.get(BASELINE) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let (baseline, supporting) = match baseline.try_get_resolved_value() {
+                Some(value) => (RuntimeValue::from(&value).as_json(), vec![]),
+                None => {
+                    let result = baseline
+                        .evaluate(input.clone(), ctx.push()?, &Default::default(), world)
+                        .await?;
+                    if result.severity() >= Severity::Error {
+                        return Ok((Severity::Error, vec![result]).into());
+                    }
+                    (result.output().as_json(), vec![result])
+                }
+            };
+
+            let mut patch = Vec::new();
+            diff(&mut patch, String::new(), &baseline, &input.as_json());
+
+            Ok(FunctionEvaluationResult {
+                severity: Severity::None,
+                output: Output::Transform(Arc::new(Value::Array(patch).into())),
+                rationale: None,
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+/// Append the operations required to turn `from` into `to`.
+fn diff(patch: &mut Vec<Value>, path: String, from: &Value, to: &Value) {
+    match (from, to) {
+        (Value::Object(from), Value::Object(to)) => {
+            for (key, value) in from {
+                let path = format!("{path}/{}", escape(key));
+                match to.get(key) {
+                    Some(to) => diff(patch, path, value, to),
+                    None => patch.push(json!({"op": "remove", "path": path})),
+                }
+            }
+            for (key, value) in to {
+                if !from.contains_key(key) {
+                    let path = format!("{path}/{}", escape(key));
+                    patch.push(json!({"op": "add", "path": path, "value": value}));
+                }
+            }
+        }
+        (Value::Array(from), Value::Array(to)) => {
+            for (idx, (from, to)) in from.iter().zip(to).enumerate() {
+                diff(patch, format!("{path}/{idx}"), from, to);
+            }
+            for (idx, value) in to.iter().enumerate().skip(from.len()) {
+                patch.push(json!({"op": "add", "path": format!("{path}/{idx}"), "value": value}));
+            }
+            // remove from the end, so that the indexes stay valid while applying the patch
+            for idx in (to.len()..from.len()).rev() {
+                patch.push(json!({"op": "remove", "path": format!("{path}/{idx}")}));
+            }
+        }
+        (from, to) => {
+            if from != to {
+                patch.push(json!({"op": "replace", "path": path, "value": to}));
+            }
+        }
+    }
+}
+
+/// Escape a key for use in a JSON pointer (RFC 6901).
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::{MemDataSource, MemDataSourceType};
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::{EvalContext, EvaluationResult};
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use std::collections::HashMap;
+
+    async fn eval(pattern: &str, value: Value) -> Result<EvaluationResult, RuntimeError> {
+        let baseline = json!({
+            "metadata": {
+                "name": "app",
+                "labels": {
+                    "tier": "backend",
+                },
+            },
+            "spec": {
+                "replicas": 2,
+                "ports": [80, 443],
+            },
+        });
+
+        let src = Ephemeral::new("test", format!("pattern test = {pattern}"));
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+        builder.data(MemDataSource::new(HashMap::from([(
+            "baseline.json".to_string(),
+            MemDataSourceType::String(baseline.to_string()),
+        )])));
+        let runtime = builder.finish().await.unwrap();
+
+        runtime
+            .evaluate("test::test", value, EvalContext::default())
+            .await
+    }
+
+    #[tokio::test]
+    async fn diff_objects() {
+        let result = eval(
+            r#"json::diff<data::from<"baseline.json">(json::json)>"#,
+            json!({
+                "metadata": {
+                    "labels": {
+                        "tier": "frontend",
+                        "team": "a/b",
+                    },
+                },
+                "spec": {
+                    "replicas": 2,
+                    "ports": [8080],
+                },
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_satisfied!(&result);
+        let patch = result.output().as_json();
+        let patch = patch.as_array().unwrap();
+        let expected = [
+            json!({"op": "remove", "path": "/metadata/name"}),
+            json!({"op": "replace", "path": "/metadata/labels/tier", "value": "frontend"}),
+            json!({"op": "add", "path": "/metadata/labels/team", "value": "a/b"}),
+            json!({"op": "replace", "path": "/spec/ports/0", "value": 8080}),
+            json!({"op": "remove", "path": "/spec/ports/1"}),
+        ];
+        assert_eq!(patch.len(), expected.len(), "{patch:?}");
+        for op in &expected {
+            assert!(patch.contains(op), "missing {op} in {patch:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn diff_only_labels_changed() {
+        let pattern = r#"json::diff<data::from<"baseline.json">(json::json)>(
+            list::all<{ path: string::regexp<"^/metadata/labels(/.*)?$"> }>
+        )"#;
+
+        let mut input = json!({
+            "metadata": {
+                "name": "app",
+                "labels": {
+                    "tier": "frontend",
+                },
+            },
+            "spec": {
+                "replicas": 2,
+                "ports": [80, 443],
+            },
+        });
+        assert_satisfied!(eval(pattern, input.clone()).await.unwrap());
+
+        input["spec"]["replicas"] = json!(3);
+        assert_not_satisfied!(eval(pattern, input).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn diff_identical() {
+        let result = eval(r#"json::diff<42>"#, json!(42)).await.unwrap();
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!([]));
+    }
+
+    #[test]
+    fn escape_pointer() {
+        assert_eq!(escape("a/b~c"), "a~1b~0c");
+    }
+}
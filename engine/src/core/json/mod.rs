@@ -13,9 +13,14 @@ use std::pin::Pin;
 use crate::lang::{PatternMeta, Severity};
 use std::sync::Arc;
 
+mod diff;
+mod valid_against;
+
 pub fn package() -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["json"]));
     pkg.register_function("json".into(), JSON);
+    pkg.register_function("diff".into(), diff::Diff);
+    pkg.register_function("valid-against".into(), valid_against::ValidAgainst);
     pkg
 }
 
@@ -0,0 +1,697 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use regex::Regex;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("valid-against.adoc");
+
+const SCHEMA: &str = "schema";
+
+/// Keywords which are either validated, or only annotate the schema.
+const KEYWORDS: &[&str] = &[
+    "type",
+    "enum",
+    "const",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "prefixItems",
+    "minItems",
+    "maxItems",
+    "uniqueItems",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "$ref",
+    "$defs",
+    "definitions",
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+];
+
+#[derive(Debug)]
+pub struct ValidAgainst;
+
+impl Function for ValidAgainst {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![SCHEMA.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(schema) = bindings.get(SCHEMA) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let (schema, supporting) = match schema.try_get_resolved_value() {
+                Some(value) => (RuntimeValue::from(&value).as_json(), vec![]),
+                None => {
+                    let result = schema
+                        .evaluate(input.clone(), ctx.push()?, &Default::default(), world)
+                        .await?;
+                    if result.severity() >= Severity::Error {
+                        return Ok((Severity::Error, vec![result]).into());
+                    }
+                    (result.output().as_json(), vec![result])
+                }
+            };
+
+            let validator = Validator::new(&schema);
+            validator.check(String::new(), &schema);
+            let mut errors = Vec::new();
+            validator.validate(&mut errors, String::new(), &schema, &input.as_json());
+
+            // a broken schema is a problem of the parameter, not of the input
+            let defects = validator.defects.into_inner();
+            if !defects.is_empty() {
+                return Ok(FunctionEvaluationResult {
+                    severity: Severity::Error,
+                    output: Output::Identity,
+                    rationale: Some(Arc::new(Rationale::InvalidArgument(
+                        defects.join(", ").into(),
+                    ))),
+                    supporting: Arc::new(supporting),
+                });
+            }
+
+            if errors.is_empty() {
+                Ok(FunctionEvaluationResult {
+                    severity: Severity::None,
+                    output: Output::Identity,
+                    rationale: None,
+                    supporting: Arc::new(supporting),
+                })
+            } else {
+                Ok(FunctionEvaluationResult {
+                    severity: Severity::Error,
+                    output: Output::Identity,
+                    rationale: Some(Arc::new(Rationale::Reason(
+                        errors.join(", ").into(),
+                        Arc::new(Rationale::Nothing),
+                    ))),
+                    supporting: Arc::new(supporting),
+                })
+            }
+        })
+    }
+}
+
+/// Validator for the subset of JSON Schema listed in the documentation.
+struct Validator<'s> {
+    root: &'s Value,
+    /// References currently being followed, along with the instance they
+    /// are applied to, used to detect `$ref` cycles which do not descend
+    /// into the instance.
+    active: RefCell<Vec<(&'s str, *const Value)>>,
+    /// Defects of the schema itself, found while validating.
+    defects: RefCell<Vec<String>>,
+}
+
+impl<'s> Validator<'s> {
+    fn new(root: &'s Value) -> Self {
+        Self {
+            root,
+            active: Default::default(),
+            defects: Default::default(),
+        }
+    }
+
+    fn defect(&self, path: &str, msg: &str) {
+        self.defects.borrow_mut().push(error(path, msg));
+    }
+
+    /// Record a defect for each keyword of `schema` and its subschemas which is not supported,
+    /// as ignoring it would accept instances the schema rejects.
+    fn check(&self, path: String, schema: &'s Value) {
+        let Value::Object(schema) = schema else {
+            return;
+        };
+        for (keyword, value) in schema {
+            let location = format!("{path}/{}", escape(keyword));
+            match (keyword.as_str(), value) {
+                ("properties" | "$defs" | "definitions", Value::Object(schemas)) => {
+                    for (name, schema) in schemas {
+                        self.check(format!("{location}/{}", escape(name)), schema);
+                    }
+                }
+                ("prefixItems" | "allOf" | "anyOf" | "oneOf", Value::Array(schemas)) => {
+                    for (idx, schema) in schemas.iter().enumerate() {
+                        self.check(format!("{location}/{idx}"), schema);
+                    }
+                }
+                ("additionalProperties" | "items" | "not", schema) => self.check(location, schema),
+                ("$ref", Value::String(reference)) if !reference.starts_with('#') => self.defect(
+                    &path,
+                    &format!("unsupported non-local reference '{reference}'"),
+                ),
+                (keyword, _) if KEYWORDS.contains(&keyword) => {}
+                (keyword, _) => self.defect(&path, &format!("unsupported keyword '{keyword}'")),
+            }
+        }
+    }
+
+    fn is_valid(&self, schema: &'s Value, instance: &Value) -> bool {
+        let mut errors = Vec::new();
+        self.validate(&mut errors, String::new(), schema, instance);
+        errors.is_empty()
+    }
+
+    /// Append an error message for each violation of `schema` by `instance`.
+    fn validate(
+        &self,
+        errors: &mut Vec<String>,
+        path: String,
+        schema: &'s Value,
+        instance: &Value,
+    ) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                errors.push(error(&path, "no value is allowed"));
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => {
+                self.defect(&path, "schema must be an object or a boolean");
+                return;
+            }
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.root.pointer(pointer))
+            {
+                Some(target) => {
+                    let key = (reference, instance as *const Value);
+                    if self.active.borrow().contains(&key) {
+                        self.defect(&path, &format!("cyclic reference '{reference}'"));
+                    } else {
+                        self.active.borrow_mut().push(key);
+                        self.validate(errors, path.clone(), target, instance);
+                        self.active.borrow_mut().pop();
+                    }
+                }
+                None => self.defect(&path, &format!("unresolvable reference '{reference}'")),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let matches = match expected {
+                Value::String(expected) => is_type(expected, instance),
+                Value::Array(expected) => expected
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .any(|expected| is_type(expected, instance)),
+                _ => true,
+            };
+            if !matches {
+                let expected = match expected {
+                    Value::String(expected) => expected.clone(),
+                    expected => expected.to_string(),
+                };
+                errors.push(error(
+                    &path,
+                    &format!("expected type {expected}, found {}", type_name(instance)),
+                ));
+            }
+        }
+
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.contains(instance) {
+                errors.push(error(
+                    &path,
+                    &format!("{instance} is not one of {}", Value::Array(values.clone())),
+                ));
+            }
+        }
+
+        if let Some(value) = schema.get("const") {
+            if value != instance {
+                errors.push(error(&path, &format!("expected {value}, found {instance}")));
+            }
+        }
+
+        match instance {
+            Value::Object(instance) => self.validate_object(errors, &path, schema, instance),
+            Value::Array(instance) => self.validate_array(errors, &path, schema, instance),
+            Value::String(instance) => self.validate_string(errors, &path, schema, instance),
+            Value::Number(_) => validate_number(errors, &path, schema, instance),
+            _ => {}
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.validate(errors, path.clone(), schema, instance);
+            }
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+            if !schemas.iter().any(|schema| self.is_valid(schema, instance)) {
+                errors.push(error(&path, "does not match any of the schemas in anyOf"));
+            }
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            let matching = schemas
+                .iter()
+                .filter(|schema| self.is_valid(schema, instance))
+                .count();
+            if matching != 1 {
+                errors.push(error(
+                    &path,
+                    &format!(
+                        "must match exactly one of the schemas in oneOf, but matches {matching}"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(schema) = schema.get("not") {
+            if self.is_valid(schema, instance) {
+                errors.push(error(&path, "must not match the schema in not"));
+            }
+        }
+    }
+
+    fn validate_object(
+        &self,
+        errors: &mut Vec<String>,
+        path: &str,
+        schema: &'s Map<String, Value>,
+        instance: &Map<String, Value>,
+    ) {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !instance.contains_key(name) {
+                    errors.push(error(path, &format!("missing required property '{name}'")));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, value) in instance {
+            let path = format!("{path}/{}", escape(name));
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(schema) => self.validate(errors, path, schema, value),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        errors.push(error(&path, "additional property is not allowed"))
+                    }
+                    Some(schema) => self.validate(errors, path, schema, value),
+                    None => {}
+                },
+            }
+        }
+    }
+
+    fn validate_array(
+        &self,
+        errors: &mut Vec<String>,
+        path: &str,
+        schema: &'s Map<String, Value>,
+        instance: &[Value],
+    ) {
+        let prefix = match schema.get("prefixItems") {
+            Some(Value::Array(prefix)) => prefix.as_slice(),
+            _ => &[],
+        };
+        for (idx, value) in instance.iter().enumerate() {
+            let path = format!("{path}/{idx}");
+            match prefix.get(idx) {
+                Some(schema) => self.validate(errors, path, schema, value),
+                None => {
+                    if let Some(schema) = schema.get("items") {
+                        self.validate(errors, path, schema, value);
+                    }
+                }
+            }
+        }
+
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (instance.len() as u64) < min {
+                errors.push(error(path, &format!("must have at least {min} items")));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if (instance.len() as u64) > max {
+                errors.push(error(path, &format!("must have at most {max} items")));
+            }
+        }
+        if let Some(Value::Bool(true)) = schema.get("uniqueItems") {
+            let unique = instance
+                .iter()
+                .enumerate()
+                .all(|(idx, value)| !instance[idx + 1..].contains(value));
+            if !unique {
+                errors.push(error(path, "items must be unique"));
+            }
+        }
+    }
+
+    fn validate_string(
+        &self,
+        errors: &mut Vec<String>,
+        path: &str,
+        schema: &Map<String, Value>,
+        instance: &str,
+    ) {
+        let len = instance.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if len < min {
+                errors.push(error(
+                    path,
+                    &format!("must be at least {min} characters long"),
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if len > max {
+                errors.push(error(
+                    path,
+                    &format!("must be at most {max} characters long"),
+                ));
+            }
+        }
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            match Regex::new(pattern) {
+                Ok(regex) if regex.is_match(instance) => {}
+                Ok(_) => errors.push(error(path, &format!("does not match pattern '{pattern}'"))),
+                Err(_) => self.defect(path, &format!("invalid pattern '{pattern}'")),
+            }
+        }
+    }
+}
+
+fn validate_number(
+    errors: &mut Vec<String>,
+    path: &str,
+    schema: &Map<String, Value>,
+    instance: &Value,
+) {
+    let Some(value) = instance.as_f64() else {
+        return;
+    };
+    let bound = |name: &str| schema.get(name).and_then(Value::as_f64);
+
+    if let Some(min) = bound("minimum") {
+        if value < min {
+            errors.push(error(
+                path,
+                &format!("{instance} is less than the minimum of {min}"),
+            ));
+        }
+    }
+    if let Some(max) = bound("maximum") {
+        if value > max {
+            errors.push(error(
+                path,
+                &format!("{instance} is greater than the maximum of {max}"),
+            ));
+        }
+    }
+    if let Some(min) = bound("exclusiveMinimum") {
+        if value <= min {
+            errors.push(error(
+                path,
+                &format!("{instance} must be greater than {min}"),
+            ));
+        }
+    }
+    if let Some(max) = bound("exclusiveMaximum") {
+        if value >= max {
+            errors.push(error(path, &format!("{instance} must be less than {max}")));
+        }
+    }
+}
+
+fn is_type(expected: &str, instance: &Value) -> bool {
+    match expected {
+        "integer" => match instance {
+            Value::Number(number) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().map_or(false, |v| v.fract() == 0.0)
+            }
+            _ => false,
+        },
+        expected => expected == type_name(instance),
+    }
+}
+
+fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn error(path: &str, msg: &str) -> String {
+    if path.is_empty() {
+        format!("/: {msg}")
+    } else {
+        format!("{path}: {msg}")
+    }
+}
+
+/// Escape a key for use in a JSON pointer (RFC 6901).
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::{MemDataSource, MemDataSourceType};
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::{EvalContext, EvaluationResult};
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    async fn eval(schema: &str, value: Value) -> EvaluationResult {
+        let config = json!({
+            "type": "object",
+            "required": ["name", "replicas"],
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "replicas": { "type": "integer", "minimum": 1, "maximum": 3 },
+                "ports": {
+                    "type": "array",
+                    "items": { "$ref": "#/$defs/port" },
+                },
+            },
+            "additionalProperties": false,
+            "$defs": {
+                "port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+            },
+        });
+        let tree = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } },
+                    },
+                },
+            },
+        });
+        let unsupported = json!({
+            "type": "object",
+            "title": "contact",
+            "properties": {
+                "email": { "type": "string", "format": "email" },
+            },
+            "patternProperties": {
+                "^x-": { "type": "string" },
+            },
+        });
+        let cycle = json!({
+            "$ref": "#/$defs/a",
+            "$defs": {
+                "a": { "$ref": "#/$defs/b" },
+                "b": { "$ref": "#/$defs/a" },
+            },
+        });
+        let tags = json!({
+            "type": "array",
+            "uniqueItems": true,
+            "items": {
+                "anyOf": [
+                    { "type": "string", "pattern": "^[a-z]+$" },
+                    { "const": 42 },
+                ],
+            },
+        });
+
+        let src = Ephemeral::new(
+            "test",
+            format!(r#"pattern test = json::valid-against<data::from<"{schema}">(json::json)>"#),
+        );
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+        builder.data(MemDataSource::new(HashMap::from([
+            (
+                "config.json".to_string(),
+                MemDataSourceType::String(config.to_string()),
+            ),
+            (
+                "tags.json".to_string(),
+                MemDataSourceType::String(tags.to_string()),
+            ),
+            (
+                "tree.json".to_string(),
+                MemDataSourceType::String(tree.to_string()),
+            ),
+            (
+                "unsupported.json".to_string(),
+                MemDataSourceType::String(unsupported.to_string()),
+            ),
+            (
+                "cycle.json".to_string(),
+                MemDataSourceType::String(cycle.to_string()),
+            ),
+        ])));
+        let runtime = builder.finish().await.unwrap();
+
+        runtime
+            .evaluate("test::test", value, EvalContext::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn valid_instance() {
+        let result = eval(
+            "config.json",
+            json!({
+                "name": "app",
+                "replicas": 2,
+                "ports": [80, 443],
+            }),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn invalid_instance() {
+        let result = eval(
+            "config.json",
+            json!({
+                "replicas": 5,
+                "ports": [80, "443"],
+                "image": "app:latest",
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "/: missing required property 'name', \
+            /replicas: 5 is greater than the maximum of 3, \
+            /ports/1: expected type integer, found string, \
+            /image: additional property is not allowed"
+        );
+    }
+
+    #[tokio::test]
+    async fn combined_schemas() {
+        assert_satisfied!(eval("tags.json", json!(["a", 42, "b"])).await);
+        assert_not_satisfied!(eval("tags.json", json!(["a", "a"])).await);
+
+        let result = eval("tags.json", json!(["a", "B"])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "/1: does not match any of the schemas in anyOf"
+        );
+    }
+
+    #[tokio::test]
+    async fn recursive_schema() {
+        let result = eval(
+            "tree.json",
+            json!({ "children": [{ "children": [] }, { "children": [{}] }] }),
+        )
+        .await;
+        assert_satisfied!(result);
+
+        let result = eval("tree.json", json!({ "children": [{ "children": 1 }] })).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn cyclic_schema() {
+        let result = eval("cycle.json", json!(42)).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: /: cyclic reference '#/$defs/a'"
+        );
+    }
+
+    #[tokio::test]
+    async fn unsupported_keyword() {
+        let result = eval("unsupported.json", json!({ "email": "not an address" })).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: /properties/email: unsupported keyword 'format', \
+            /: unsupported keyword 'patternProperties'"
+        );
+    }
+
+    #[tokio::test]
+    async fn boolean_schema() {
+        assert_satisfied!(test_pattern("json::valid-against<true>", json!(42)).await);
+        assert_not_satisfied!(test_pattern("json::valid-against<false>", json!(42)).await);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{EvaluationResult, ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("all-values.adoc");
+
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct AllValues;
+
+impl Function for AllValues {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let mut supporting: Vec<EvaluationResult> = Vec::new();
+            let mut failed = None;
+            for (key, value) in object.iter() {
+                let result = pattern
+                    .evaluate(value.clone(), ctx.push()?, &Default::default(), world)
+                    .await?;
+                if failed.is_none() && result.severity() >= Severity::Error {
+                    failed = Some(key.clone());
+                }
+                supporting.push(result);
+            }
+
+            let severity = supporting.iter().map(|s| s.severity()).collect();
+            let rationale = failed.map(|key| {
+                Arc::new(Rationale::Reason(
+                    format!("value of field '{key}' does not satisfy the pattern").into(),
+                    Arc::new(Rationale::Nothing),
+                ))
+            });
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: Output::Identity,
+                rationale,
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn call_matching() {
+        let result = test_pattern(
+            r#"lang::all-values<integer>"#,
+            json!({
+                "cpu": 2,
+                "memory": 512,
+                "replicas": 3,
+            }),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_not_matching() {
+        let result = test_pattern(
+            r#"lang::all-values<integer>"#,
+            json!({
+                "cpu": 2,
+                "memory": "512Mi",
+                "replicas": 3,
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "value of field 'memory' does not satisfy the pattern"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_with_field_requirements() {
+        let result = test_pattern(
+            r#"lang::all-values<integer> && { replicas: $(self >= 2) }"#,
+            json!({
+                "cpu": 2,
+                "replicas": 1,
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_empty() {
+        let result = test_pattern(r#"lang::all-values<integer>"#, json!({})).await;
+
+        assert_satisfied!(result);
+    }
+}
@@ -50,6 +50,10 @@ impl Function for And {
                             .await?;
                         severity = max(severity, result.severity());
                         supporting.push(result);
+
+                        if ctx.options.short_circuit && severity >= Severity::Error {
+                            break;
+                        }
                     }
 
                     return Ok((severity, supporting).into());
@@ -0,0 +1,137 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{EvaluationResult, ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("any-value.adoc");
+
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct AnyValue;
+
+impl Function for AnyValue {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let mut supporting: Vec<EvaluationResult> = Vec::new();
+            let mut satisfied = false;
+            for (_, value) in object.iter() {
+                let result = pattern
+                    .evaluate(value.clone(), ctx.push()?, &Default::default(), world)
+                    .await?;
+                satisfied |= result.severity() < Severity::Error;
+                supporting.push(result);
+                // a match decides the outcome already
+                if ctx.options.short_circuit && satisfied {
+                    break;
+                }
+            }
+
+            if satisfied {
+                return Ok(FunctionEvaluationResult {
+                    severity: Severity::None,
+                    output: Output::Identity,
+                    rationale: None,
+                    supporting: Arc::new(supporting),
+                });
+            }
+
+            Ok(FunctionEvaluationResult {
+                severity: Severity::Error,
+                output: Output::Identity,
+                rationale: Some(Arc::new(Rationale::Reason(
+                    "none of the values satisfies the pattern".into(),
+                    Arc::new(Rationale::Nothing),
+                ))),
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn call_matching() {
+        let result = test_pattern(
+            r#"lang::any-value<"approved">"#,
+            json!({
+                "build": "pending",
+                "review": "requested",
+                "security": "approved",
+                "release": "pending",
+            }),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_not_matching() {
+        let result = test_pattern(
+            r#"lang::any-value<"approved">"#,
+            json!({
+                "build": "pending",
+                "review": "requested",
+                "security": "rejected",
+                "release": "pending",
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "none of the values satisfies the pattern"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_empty() {
+        let result = test_pattern(r#"lang::any-value<integer>"#, json!({})).await;
+
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_not_an_object() {
+        let result = test_pattern(r#"lang::any-value<integer>"#, json!([1, 2])).await;
+
+        assert_not_satisfied!(result);
+    }
+}
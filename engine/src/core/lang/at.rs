@@ -0,0 +1,225 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("at.adoc");
+
+const PATH: &str = "path";
+
+#[derive(Debug)]
+pub struct At;
+
+impl Function for At {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATH.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(path)) =
+                bindings.get(PATH).and_then(|p| p.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("path must be a string literal"));
+            };
+
+            let steps = match parse(&path) {
+                Ok(steps) => steps,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+
+            let mut current = input;
+            // the path traversed so far, for reporting
+            let mut walked = String::new();
+            for step in steps {
+                let at = if walked.is_empty() {
+                    "the input"
+                } else {
+                    &walked
+                };
+                let next = match &step {
+                    Step::Field(name) => {
+                        let Some(object) = current.try_get_object() else {
+                            return Ok(unsatisfied(format!(
+                                "{at} is not an object, but {}",
+                                current.type_name()
+                            )));
+                        };
+                        if !walked.is_empty() {
+                            walked.push('.');
+                        }
+                        walked.push_str(name);
+                        let Some(value) = object.get(name) else {
+                            return Ok(
+                                (Severity::Error, Rationale::MissingField(walked.into())).into()
+                            );
+                        };
+                        value
+                    }
+                    Step::Index(idx) => {
+                        let Some(list) = current.try_get_list() else {
+                            return Ok(unsatisfied(format!(
+                                "{at} is not a list, but {}",
+                                current.type_name()
+                            )));
+                        };
+                        let Some(value) = list.get(*idx) else {
+                            return Ok(unsatisfied(format!(
+                                "{at} has no element {idx}, only {} elements",
+                                list.len()
+                            )));
+                        };
+                        walked.push_str(&format!("[{idx}]"));
+                        value.clone()
+                    }
+                };
+                current = next;
+            }
+
+            Ok(Output::Transform(current).into())
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Step {
+    Field(String),
+    Index(usize),
+}
+
+/// Parse a dotted path like `a.b[0].c` into its steps.
+fn parse(path: &str) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+    for segment in path.split('.') {
+        let (name, mut indexes) = match segment.find('[') {
+            Some(pos) => segment.split_at(pos),
+            None => (segment, ""),
+        };
+        // a leading index, like `[0].a`, applies to the input itself
+        if !name.is_empty() {
+            steps.push(Step::Field(name.to_string()));
+        } else if indexes.is_empty() || !steps.is_empty() {
+            return Err(format!("invalid path {path}: empty segment"));
+        }
+
+        while !indexes.is_empty() {
+            let Some(end) = indexes.find(']').filter(|_| indexes.starts_with('[')) else {
+                return Err(format!("invalid path {path}: malformed index in {segment}"));
+            };
+            let Ok(idx) = indexes[1..end].parse() else {
+                return Err(format!("invalid path {path}: index in {segment} is not a number"));
+            };
+            steps.push(Step::Index(idx));
+            indexes = &indexes[end + 1..];
+        }
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    fn sbom() -> serde_json::Value {
+        json!({
+            "metadata": {
+                "component": { "name": "app", "version": "1.0" },
+            },
+            "components": [
+                { "name": "a", "hashes": [{ "alg": "SHA-256" }] },
+                { "name": "b" },
+            ],
+        })
+    }
+
+    #[test]
+    fn parse_path() {
+        assert_eq!(
+            parse("a.b[0][1].c").unwrap(),
+            vec![
+                Step::Field("a".into()),
+                Step::Field("b".into()),
+                Step::Index(0),
+                Step::Index(1),
+                Step::Field("c".into()),
+            ]
+        );
+        assert_eq!(
+            parse("[2].a").unwrap(),
+            vec![Step::Index(2), Step::Field("a".into())]
+        );
+        assert!(parse("a..b").is_err());
+        assert!(parse("a[x]").is_err());
+        assert!(parse("a[0").is_err());
+    }
+
+    #[tokio::test]
+    async fn object_traversal() {
+        let result = test_pattern(r#"lang::at<"metadata.component.name">"#, sbom()).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!("app"));
+    }
+
+    #[tokio::test]
+    async fn list_indexing() {
+        let result = test_pattern(r#"lang::at<"components[0].hashes[0].alg">"#, sbom()).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!("SHA-256"));
+
+        let result = test_pattern(r#"lang::at<"components[0]">({ name: "a" })"#, sbom()).await;
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn missing_segment() {
+        let result = test_pattern(r#"lang::at<"components[1].hashes[0].alg">"#, sbom()).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "missing field: components[1].hashes"
+        );
+
+        let result = test_pattern(r#"lang::at<"components[2].name">"#, sbom()).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "components has no element 2, only 2 elements"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_an_object() {
+        let result = test_pattern(r#"lang::at<"metadata.component.name.first">"#, sbom()).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "metadata.component.name is not an object, but string"
+        );
+    }
+}
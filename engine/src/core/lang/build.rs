@@ -0,0 +1,166 @@
+use crate::core::lang::object_fields;
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{EvaluationResult, ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("build.adoc");
+
+const FIELDS: &str = "fields";
+
+#[derive(Debug)]
+pub struct Build;
+
+impl Function for Build {
+    fn parameters(&self) -> Vec<String> {
+        vec![FIELDS.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(fields) = bindings.get(FIELDS).and_then(|p| object_fields(&p, world)) else {
+                return Ok(invalid_arg("fields must be an object, mapping field names to patterns"));
+            };
+
+            let mut severity = Severity::None;
+            let mut output = Object::new();
+            let mut supporting: Vec<EvaluationResult> = Vec::with_capacity(fields.len());
+
+            for field in fields {
+                let result = field
+                    .ty()
+                    .evaluate(input.clone(), ctx.push()?, bindings, world)
+                    .await?;
+
+                if result.severity() >= Severity::Error {
+                    // optional fields are left out
+                    if field.optional() {
+                        supporting.push(result);
+                        continue;
+                    }
+
+                    let rationale = Rationale::Labeled(
+                        format!("field '{}' not built", field.name()).into(),
+                        result.rationale.clone(),
+                    );
+                    supporting.push(result);
+                    return Ok(FunctionEvaluationResult {
+                        severity: Severity::Error,
+                        output: Output::Identity,
+                        rationale: Some(Arc::new(rationale)),
+                        supporting: Arc::new(supporting),
+                    });
+                }
+
+                severity = severity.max(result.severity());
+                output.set(field.name(), (*result.output()).clone());
+                supporting.push(result);
+            }
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: Output::Transform(Arc::new(output.into())),
+                rationale: None,
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::{test_pattern, test_patterns};
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn summary() {
+        let result = test_pattern(
+            r#"lang::build<{
+                name: self.metadata.name,
+                components: self.components | list::count,
+            }>"#,
+            json!({
+                "metadata": {"name": "web", "version": "1.0.0"},
+                "components": ["serde", "tokio", "ring"],
+            }),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({"name": "web", "components": 3})
+        );
+    }
+
+    #[tokio::test]
+    async fn summary_by_reference() {
+        let result = test_patterns(
+            r#"
+pattern summary = {
+    name: self.metadata.name,
+    version?: self.metadata.version,
+}
+
+pattern test-pattern = lang::build<summary>
+"#,
+            json!({"metadata": {"name": "web"}}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"name": "web"}));
+    }
+
+    #[tokio::test]
+    async fn missing_field() {
+        let result = test_pattern(
+            r#"lang::build<{
+                name: self.metadata.name,
+                components: self.components | list::count,
+            }>"#,
+            json!({"metadata": {"name": "web"}}),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert!(
+            result
+                .rationale()
+                .reason()
+                .starts_with("field 'components' not built"),
+            "{}",
+            result.rationale().reason()
+        );
+    }
+
+    #[tokio::test]
+    async fn not_an_object() {
+        let result = test_pattern(r#"lang::build<"name">"#, json!({})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: fields must be an object, mapping field names to patterns"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::core::lang::object_fields;
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("by-version.adoc");
+
+const FIELD: &str = "field";
+const VERSIONS: &str = "versions";
+
+#[derive(Debug)]
+pub struct ByVersion;
+
+impl Function for ByVersion {
+    fn parameters(&self) -> Vec<String> {
+        vec![FIELD.into(), VERSIONS.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(field)) =
+                bindings.get(FIELD).and_then(|p| p.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("field must be a string literal"));
+            };
+
+            let Some(versions) = bindings.get(VERSIONS).and_then(|p| object_fields(&p, world)) else {
+                return Ok(invalid_arg(
+                    "versions must be an object, mapping versions to patterns",
+                ));
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let Some(value) = object.get(&field) else {
+                return Ok((Severity::Error, Rationale::MissingField(field)).into());
+            };
+
+            let version = match &*value {
+                RuntimeValue::String(version) => version.to_string(),
+                RuntimeValue::Integer(version) => version.to_string(),
+                other => {
+                    return Ok(unsatisfied(format!(
+                        "version field {field} must be a string or integer, but is {}",
+                        other.type_name()
+                    )));
+                }
+            };
+
+            let Some(pattern) = versions
+                .iter()
+                .find(|f| f.name() == version)
+                .map(|f| f.ty())
+            else {
+                return Ok(unsatisfied(format!("unsupported version {version}")));
+            };
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            Ok(FunctionEvaluationResult {
+                severity: result.severity(),
+                output: result.raw_output().clone(),
+                rationale: Some(Arc::new(Rationale::Labeled(
+                    format!("version {version}").into(),
+                    result.rationale.clone(),
+                ))),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"
+lang::by-version<"apiVersion", { v1: deployment-v1, v2: deployment-v2 }>
+
+pattern deployment-v1 = {
+    replicas: integer,
+}
+
+pattern deployment-v2 = {
+    scale: { replicas: integer },
+}
+"#;
+
+    #[tokio::test]
+    async fn first_version() {
+        let result = test_pattern(PATTERN, json!({"apiVersion": "v1", "replicas": 3})).await;
+        assert_satisfied!(&result);
+        assert!(result.rationale().reason().starts_with("version v1"));
+
+        let result = test_pattern(
+            PATTERN,
+            json!({"apiVersion": "v1", "scale": {"replicas": 3}}),
+        )
+        .await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn second_version() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"apiVersion": "v2", "scale": {"replicas": 3}}),
+        )
+        .await;
+        assert_satisfied!(result);
+
+        let result = test_pattern(PATTERN, json!({"apiVersion": "v2", "replicas": 3})).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn unknown_version() {
+        let result = test_pattern(PATTERN, json!({"apiVersion": "v3", "replicas": 3})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(result.rationale().reason(), "unsupported version v3");
+    }
+
+    #[tokio::test]
+    async fn missing_version() {
+        let result = test_pattern(PATTERN, json!({"replicas": 3})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(result.rationale().reason(), "missing field: apiVersion");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::core::lang::object_fields;
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("closed-object.adoc");
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct ClosedObject;
+
+impl Function for ClosedObject {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+            let Some(declared) = object_fields(&pattern, world) else {
+                return Ok(invalid_arg("pattern must be an object pattern"));
+            };
+
+            let result = pattern
+                .evaluate(input.clone(), ctx.push()?, &Default::default(), world)
+                .await?;
+
+            let unexpected: Vec<_> = input
+                .try_get_object()
+                .map(|object| {
+                    object
+                        .iter()
+                        .map(|(name, _)| name.as_ref())
+                        .filter(|name| !declared.iter().any(|d| d.name() == *name))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let (severity, rationale) = if unexpected.is_empty() {
+                (result.severity(), None)
+            } else {
+                (
+                    Severity::Error,
+                    Some(Arc::new(Rationale::Reason(
+                        format!("unexpected fields: {}", unexpected.join(", ")).into(),
+                        Arc::new(Rationale::Nothing),
+                    ))),
+                )
+            };
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: result.raw_output().clone(),
+                rationale,
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"
+lang::closed-object<component>
+
+pattern component = {
+    name: string,
+    version?: string,
+}
+"#;
+
+    #[tokio::test]
+    async fn declared_fields() {
+        assert_satisfied!(test_pattern(PATTERN, json!({"name": "foo", "version": "1.0"})).await);
+        assert_satisfied!(test_pattern(PATTERN, json!({"name": "foo"})).await);
+        assert_satisfied!(
+            test_pattern(
+                "lang::closed-object<{ name: string }>",
+                json!({"name": "foo"})
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn unexpected_fields() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"name": "foo", "license": "MIT", "supplier": "bar"}),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "unexpected fields: license, supplier"
+        );
+    }
+
+    #[tokio::test]
+    async fn pattern_not_satisfied() {
+        assert_not_satisfied!(test_pattern(PATTERN, json!({"name": 42})).await);
+        assert_not_satisfied!(test_pattern(PATTERN, json!("foo")).await);
+    }
+
+    #[tokio::test]
+    async fn not_an_object_pattern() {
+        let result = test_pattern("lang::closed-object<string>", json!("foo")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: pattern must be an object pattern"
+        );
+    }
+}
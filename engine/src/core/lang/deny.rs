@@ -0,0 +1,103 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("deny.adoc");
+
+const PATTERN: &str = "pattern";
+const MESSAGE: &str = "message";
+
+#[derive(Debug)]
+pub struct Deny;
+
+impl Function for Deny {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), MESSAGE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+            let Some(ValuePattern::String(message)) =
+                bindings.get(MESSAGE).and_then(|p| p.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("message must be a string"));
+            };
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            if result.severity() == Severity::Error {
+                // the denied pattern did not match, so the input is accepted
+                return Ok(FunctionEvaluationResult {
+                    severity: Severity::None,
+                    output: Output::Identity,
+                    rationale: None,
+                    supporting: Arc::new(vec![result]),
+                });
+            }
+
+            Ok(FunctionEvaluationResult {
+                severity: Severity::Error,
+                output: Output::Identity,
+                rationale: Some(Arc::new(Rationale::Reason(
+                    message.into(),
+                    Arc::new(Rationale::Nothing),
+                ))),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::Response;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str =
+        r#"lang::deny<{ privileged: true }, "privileged containers are not allowed">"#;
+
+    #[tokio::test]
+    async fn inner_matching() {
+        let result = test_pattern(PATTERN, json!({"name": "nginx", "privileged": true})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "privileged containers are not allowed"
+        );
+    }
+
+    #[tokio::test]
+    async fn inner_not_matching() {
+        assert_satisfied!(
+            test_pattern(PATTERN, json!({"name": "nginx", "privileged": false})).await
+        );
+        assert_satisfied!(test_pattern(PATTERN, json!({"name": "nginx"})).await);
+    }
+}
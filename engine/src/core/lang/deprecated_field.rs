@@ -0,0 +1,113 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("deprecated-field.adoc");
+
+const NAME: &str = "name";
+const MESSAGE: &str = "message";
+
+#[derive(Debug)]
+pub struct DeprecatedField;
+
+impl Function for DeprecatedField {
+    fn parameters(&self) -> Vec<String> {
+        vec![NAME.into(), MESSAGE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(name)) =
+                bindings.get(NAME).and_then(|p| p.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("field name must be a string"));
+            };
+            let Some(ValuePattern::String(message)) =
+                bindings.get(MESSAGE).and_then(|p| p.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("message must be a string"));
+            };
+
+            let present = input
+                .try_get_object()
+                .map(|object| object.get(&name).is_some())
+                .unwrap_or_default();
+
+            if !present {
+                return Ok(Output::Identity.into());
+            }
+
+            // only warn, the input is still accepted
+            Ok(FunctionEvaluationResult {
+                severity: Severity::Warning,
+                output: Output::Identity,
+                rationale: Some(Arc::new(Rationale::Reason(
+                    format!("field '{name}' is deprecated: {message}").into(),
+                    Arc::new(Rationale::Anything),
+                ))),
+                supporting: Arc::new(vec![]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assert_satisfied;
+    use crate::lang::Severity;
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::Response;
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::deprecated-field<"image", "use 'images' instead">"#;
+
+    #[tokio::test]
+    async fn field_present() {
+        let result = test_pattern(PATTERN, json!({"image": "nginx"})).await;
+
+        assert_eq!(result.severity(), Severity::Warning);
+        assert_eq!(
+            Response::new(&result).reason,
+            "field 'image' is deprecated: use 'images' instead"
+        );
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn field_absent() {
+        let result = test_pattern(PATTERN, json!({"images": ["nginx"]})).await;
+
+        assert_eq!(result.severity(), Severity::None);
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn combined() {
+        let result = test_pattern(
+            &format!("{PATTERN} && {{ image: string }}"),
+            json!({"image": "nginx"}),
+        )
+        .await;
+
+        assert_eq!(result.severity(), Severity::Warning);
+    }
+}
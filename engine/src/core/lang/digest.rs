@@ -0,0 +1,74 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use sha2::{Digest as _, Sha256};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("digest.adoc");
+
+#[derive(Debug)]
+pub struct Digest;
+
+impl Function for Digest {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            // hashing the canonical form makes the digest independent of the order of fields
+            let mut hasher = Sha256::new();
+            hasher.update(input.as_canonical_json().as_bytes());
+            let digest = format!("{:x}", hasher.finalize());
+
+            Ok(Output::Transform(Arc::new(digest.into())).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assert_satisfied;
+    use crate::runtime::testutil::test_pattern;
+    use serde_json::json;
+
+    const DIGEST: &str = "63e8063d9dc6f0fd5a24b4706818a165fd57c3531b74466cf5dea62bff09b0b6";
+
+    #[tokio::test]
+    async fn digest() {
+        let result = test_pattern("lang::digest", json!({"a": 1, "b": [true, "x"]})).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!(DIGEST));
+    }
+
+    #[tokio::test]
+    async fn stable_across_key_order() {
+        let result = test_pattern("lang::digest", json!({"b": [true, "x"], "a": 1})).await;
+        assert_eq!(result.output().as_json(), json!(DIGEST));
+
+        // the order of list items is significant
+        let result = test_pattern("lang::digest", json!({"a": 1, "b": ["x", true]})).await;
+        assert_ne!(result.output().as_json(), json!(DIGEST));
+    }
+
+    #[tokio::test]
+    async fn compare() {
+        let pattern = format!(r#"lang::digest("{DIGEST}")"#);
+
+        assert_satisfied!(test_pattern(&pattern, json!({"b": [true, "x"], "a": 1})).await);
+    }
+}
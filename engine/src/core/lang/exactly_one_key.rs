@@ -0,0 +1,126 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, InnerPattern};
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("exactly-one-key.adoc");
+
+const KEYS: &str = "keys";
+
+#[derive(Debug)]
+pub struct ExactlyOneKey;
+
+impl Function for ExactlyOneKey {
+    fn parameters(&self) -> Vec<String> {
+        vec![KEYS.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let keys = match get_keys(bindings) {
+                Ok(keys) => keys,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let present: Vec<&str> = keys
+                .iter()
+                .filter(|key| object.get(key).is_some())
+                .map(|key| &**key)
+                .collect();
+
+            match present.as_slice() {
+                [_] => Ok(Output::Identity.into()),
+                [] => Ok(unsatisfied(format!(
+                    "none of the keys {} is present",
+                    keys.join(", ")
+                ))),
+                present => Ok(unsatisfied(format!(
+                    "only one of the keys {} may be present, found {}",
+                    keys.join(", "),
+                    present.join(", ")
+                ))),
+            }
+        })
+    }
+}
+
+fn get_keys(bindings: &Bindings) -> Result<Vec<Arc<str>>, &'static str> {
+    match bindings.get(KEYS).map(|p| p.inner()) {
+        Some(InnerPattern::List(items)) => items
+            .iter()
+            .map(|item| match item.try_get_resolved_value() {
+                Some(ValuePattern::String(key)) => Some(key),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("keys must be string literals"),
+        _ => Err("keys must be a list"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::exactly-one-key<["git", "http", "oci"]>"#;
+
+    #[tokio::test]
+    async fn no_key() {
+        let result = test_pattern(PATTERN, json!({"name": "source"})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "none of the keys git, http, oci is present"
+        );
+    }
+
+    #[tokio::test]
+    async fn one_key() {
+        let result = test_pattern(PATTERN, json!({"name": "source", "oci": {}})).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn two_keys() {
+        let result = test_pattern(PATTERN, json!({"git": {}, "oci": {}})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "only one of the keys git, http, oci may be present, found git, oci"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_an_object() {
+        let result = test_pattern(PATTERN, json!("git")).await;
+
+        assert_not_satisfied!(result);
+    }
+}
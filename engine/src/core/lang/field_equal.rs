@@ -0,0 +1,143 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("field-equal.adoc");
+
+const A: &str = "a";
+const B: &str = "b";
+
+#[derive(Debug)]
+pub struct FieldEqual;
+
+impl Function for FieldEqual {
+    fn parameters(&self) -> Vec<String> {
+        vec![A.into(), B.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let a = match get_field_name(A, bindings) {
+                Ok(name) => name,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+            let b = match get_field_name(B, bindings) {
+                Ok(name) => name,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+            let Some(left) = object.get(&a) else {
+                return Ok((Severity::Error, Rationale::MissingField(a)).into());
+            };
+            let Some(right) = object.get(&b) else {
+                return Ok((Severity::Error, Rationale::MissingField(b)).into());
+            };
+
+            if left == right {
+                Ok(Output::Identity.into())
+            } else {
+                Ok(unsatisfied(format!(
+                    "field '{a}' ({}) is not equal to field '{b}' ({})",
+                    left.as_json(),
+                    right.as_json()
+                )))
+            }
+        })
+    }
+}
+
+fn get_field_name(param: &str, bindings: &Bindings) -> Result<Arc<str>, String> {
+    match bindings.get(param).and_then(|p| p.try_get_resolved_value()) {
+        Some(ValuePattern::String(name)) => Ok(name),
+        _ => Err(format!("invalid field name specified for {param}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::rationale::Rationale;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn call_matching() {
+        let result = test_pattern(
+            r#"lang::field-equal<"password", "confirm">"#,
+            json!({
+                "password": "s3cr3t",
+                "confirm": "s3cr3t",
+            }),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_not_matching() {
+        let result = test_pattern(
+            r#"lang::field-equal<"password", "confirm">"#,
+            json!({
+                "password": "s3cr3t",
+                "confirm": "secret",
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        let rationale = match result.rationale() {
+            Rationale::Bound(inner, _) => inner.as_ref(),
+            other => other,
+        };
+        if let Rationale::Function {
+            rationale: Some(rationale),
+            ..
+        } = rationale
+        {
+            if let Rationale::Reason(msg, _) = &**rationale {
+                assert_eq!(
+                    msg.as_ref(),
+                    r#"field 'password' ("s3cr3t") is not equal to field 'confirm' ("secret")"#
+                );
+                return;
+            }
+        }
+        panic!("unexpected rationale: {rationale:?}");
+    }
+
+    #[tokio::test]
+    async fn call_missing_field() {
+        let result = test_pattern(
+            r#"lang::field-equal<"password", "confirm">"#,
+            json!({
+                "password": "s3cr3t",
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(result);
+    }
+}
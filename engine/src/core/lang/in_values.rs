@@ -0,0 +1,110 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, InnerPattern};
+use crate::lang::{PatternMeta, ValuePattern};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("in.adoc");
+
+const VALUES: &str = "values";
+
+#[derive(Debug)]
+pub struct InValues;
+
+impl Function for InValues {
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUES.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let values = match get_values(bindings) {
+                Ok(values) => values,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+
+            if values.iter().any(|value| value.is_equal(&input)) {
+                Ok(Output::Identity.into())
+            } else {
+                let allowed = values
+                    .iter()
+                    .map(|value| RuntimeValue::from(value).as_json())
+                    .collect::<Vec<_>>();
+                let msg = format!(
+                    "{} is not one of {}",
+                    input.as_json(),
+                    serde_json::Value::Array(allowed)
+                );
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+fn get_values(bindings: &Bindings) -> Result<Vec<ValuePattern>, &'static str> {
+    match bindings.get(VALUES).map(|p| p.inner()) {
+        Some(InnerPattern::List(items)) => items
+            .iter()
+            .map(|item| item.try_get_resolved_value())
+            .collect::<Option<Vec<_>>>()
+            .ok_or("values must be literals"),
+        _ => Err("values must be a list"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::rationale::Rationale;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn call_allowed() {
+        let result = test_pattern(r#"lang::in<["low", "medium", "high"]>"#, json!("medium")).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_disallowed() {
+        let result =
+            test_pattern(r#"lang::in<["low", "medium", "high"]>"#, json!("critical")).await;
+
+        assert_not_satisfied!(&result);
+        let rationale = match result.rationale() {
+            Rationale::Bound(inner, _) => inner.as_ref(),
+            other => other,
+        };
+        if let Rationale::Function {
+            rationale: Some(rationale),
+            ..
+        } = rationale
+        {
+            if let Rationale::Reason(msg, _) = &**rationale {
+                assert_eq!(
+                    msg.as_ref(),
+                    r#""critical" is not one of ["low","medium","high"]"#
+                );
+                return;
+            }
+        }
+        panic!("unexpected rationale: {rationale:?}");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("labeled.adoc");
+
+const NAME: &str = "name";
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct Labeled;
+
+impl Function for Labeled {
+    fn parameters(&self) -> Vec<String> {
+        vec![NAME.into(), PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(label)) =
+                bindings.get(NAME).and_then(|name| name.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("label must be a string"));
+            };
+
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            Ok(FunctionEvaluationResult {
+                severity: result.severity(),
+                output: result.output.clone(),
+                rationale: Some(Arc::new(Rationale::Labeled(
+                    label,
+                    result.rationale.clone(),
+                ))),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::Response;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn label_satisfied() {
+        let result = test_pattern(
+            r#"lang::labeled<"age check", { age: $(self >= 18) }>"#,
+            json!({"age": 42}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "age check: Because all fields were satisfied"
+        );
+    }
+
+    #[tokio::test]
+    async fn label_not_satisfied() {
+        let result = test_pattern(
+            r#"{ name: string, age: lang::labeled<"age check", $(self >= 18)> }"#,
+            json!({"name": "Bob", "age": 12}),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+
+        let mut labels = Vec::new();
+        Response::new(&result).walk_tree(|r| {
+            if r.reason.starts_with("age check") {
+                labels.push(r.reason.clone());
+            }
+            true
+        });
+        assert_eq!(
+            labels,
+            vec!["age check: The expression defined in the pattern is not satisfied"]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::core::string::iequals::fold_case;
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("loose-equal.adoc");
+const VALUE: &str = "value";
+
+#[derive(Debug)]
+pub struct LooseEqual;
+
+impl Function for LooseEqual {
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(expected)) = bindings
+                .get(VALUE)
+                .and_then(|value| value.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("value must be a string literal"));
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            if normalize(value) == normalize(&expected) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = format!(
+                    "expected {expected:?} (ignoring case and whitespace), found {value:?}"
+                );
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+/// Trim the value, collapse each run of whitespace into a single space, and fold its case.
+fn normalize(value: &str) -> String {
+    fold_case(&value.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::loose-equal<"Apache License 2.0">"#;
+
+    #[tokio::test]
+    async fn surrounding_whitespace() {
+        assert_satisfied!(test_pattern(PATTERN, json!("  Apache License 2.0\n")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!("\tApache License 2.0")).await);
+    }
+
+    #[tokio::test]
+    async fn inner_whitespace() {
+        assert_satisfied!(test_pattern(PATTERN, json!("Apache  License\t\n2.0")).await);
+        assert_not_satisfied!(test_pattern(PATTERN, json!("ApacheLicense 2.0")).await);
+    }
+
+    #[tokio::test]
+    async fn case() {
+        assert_satisfied!(test_pattern(PATTERN, json!("apache license 2.0")).await);
+        assert_satisfied!(test_pattern(PATTERN, json!(" APACHE   LICENSE 2.0 ")).await);
+    }
+
+    #[tokio::test]
+    async fn reports_expected_value() {
+        let result = test_pattern(PATTERN, json!("MIT License")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"expected "Apache License 2.0" (ignoring case and whitespace), found "MIT License""#
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_string() {
+        assert_not_satisfied!(test_pattern(r#"lang::loose-equal<"42">"#, json!(42)).await);
+    }
+}
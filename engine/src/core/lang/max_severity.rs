@@ -0,0 +1,154 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("max-severity.adoc");
+
+const PATTERN: &str = "pattern";
+const LEVEL: &str = "level";
+
+#[derive(Debug)]
+pub struct MaxSeverity;
+
+impl Function for MaxSeverity {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), LEVEL.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let level = match bindings
+                .get(LEVEL)
+                .and_then(|level| level.try_get_resolved_value())
+            {
+                Some(ValuePattern::String(level)) => match parse_severity(&level) {
+                    Some(level) => level,
+                    None => return Ok(invalid_arg(format!("unknown severity {level:?}"))),
+                },
+                _ => return Ok(invalid_arg("level must be a string literal")),
+            };
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            let (severity, label) = if result.severity() <= level {
+                // tolerated errors are still worth a warning
+                (
+                    result.severity().min(Severity::Warning),
+                    format!("severity {} is at most {level}", result.severity()),
+                )
+            } else {
+                (
+                    Severity::Error,
+                    format!("severity {} exceeds {level}", result.severity()),
+                )
+            };
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: Output::Identity,
+                rationale: Some(Arc::new(Rationale::Labeled(
+                    label.into(),
+                    result.rationale.clone(),
+                ))),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+fn parse_severity(level: &str) -> Option<Severity> {
+    match level {
+        "none" => Some(Severity::None),
+        "advice" => Some(Severity::Advice),
+        "warning" => Some(Severity::Warning),
+        "error" => Some(Severity::Error),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lang::Severity;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn satisfied() {
+        let result = test_pattern(r#"lang::max-severity<42, "none">"#, json!(42)).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.severity(), Severity::None);
+    }
+
+    #[tokio::test]
+    async fn tolerate_warning() {
+        let result = test_pattern(
+            r#"lang::max-severity<lang::deprecated-field<"name", "use 'full-name'">, "warning">"#,
+            json!({"name": "bob"}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.severity(), Severity::Warning);
+    }
+
+    #[tokio::test]
+    async fn tolerate_error() {
+        let result = test_pattern(r#"lang::max-severity<42, "error">"#, json!(43)).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.severity(), Severity::Warning);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("severity error is at most error"));
+    }
+
+    #[tokio::test]
+    async fn exceeds_level() {
+        let result = test_pattern(r#"lang::max-severity<42, "warning">"#, json!(43)).await;
+
+        assert_not_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("severity error exceeds warning"));
+    }
+
+    #[tokio::test]
+    async fn unknown_level() {
+        let result = test_pattern(r#"lang::max-severity<42, "fatal">"#, json!(42)).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"invalid argument: unknown severity "fatal""#
+        );
+    }
+}
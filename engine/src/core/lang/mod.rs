@@ -1,18 +1,72 @@
+use crate::core::lang::all_values::AllValues;
 use crate::core::lang::and::And;
+use crate::core::lang::any_value::AnyValue;
+use crate::core::lang::at::At;
+use crate::core::lang::build::Build;
+use crate::core::lang::by_version::ByVersion;
 use crate::core::lang::chain::Chain;
+use crate::core::lang::closed_object::ClosedObject;
+use crate::core::lang::deny::Deny;
+use crate::core::lang::deprecated_field::DeprecatedField;
+use crate::core::lang::digest::Digest;
+use crate::core::lang::exactly_one_key::ExactlyOneKey;
+use crate::core::lang::field_equal::FieldEqual;
+use crate::core::lang::in_values::InValues;
+use crate::core::lang::labeled::Labeled;
+use crate::core::lang::loose_equal::LooseEqual;
+use crate::core::lang::max_severity::MaxSeverity;
 use crate::core::lang::not::Not;
+use crate::core::lang::not_empty::NotEmpty;
+use crate::core::lang::object_count_matching::ObjectCountMatching;
+use crate::core::lang::optional::Optional;
 use crate::core::lang::or::Or;
+use crate::core::lang::or_else::OrElse;
+use crate::core::lang::reason_field::ReasonField;
 use crate::core::lang::refine::Refine;
+use crate::core::lang::requires::Requires;
+use crate::core::lang::response::ResponseOf;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::lang::timeout::Timeout;
+use crate::core::lang::to_string::Stringify;
 use crate::core::lang::traverse::Traverse;
+use crate::core::lang::unless::Unless;
+use crate::lang::lir::{Field, InnerPattern, Pattern};
 use crate::package::Package;
-use crate::runtime::PackagePath;
+use crate::runtime::{PackagePath, World};
+use std::sync::Arc;
 
+mod all_values;
 mod and;
+mod any_value;
+mod at;
+mod build;
+mod by_version;
 mod chain;
+mod closed_object;
+mod deny;
+mod deprecated_field;
+mod digest;
+mod exactly_one_key;
+mod field_equal;
+mod in_values;
+mod labeled;
+mod loose_equal;
+mod max_severity;
 mod not;
+mod not_empty;
+mod object_count_matching;
+mod optional;
 mod or;
+mod or_else;
+mod reason_field;
 mod refine;
+mod requires;
+mod response;
+#[cfg(not(target_arch = "wasm32"))]
+mod timeout;
+mod to_string;
 mod traverse;
+mod unless;
 
 pub fn package() -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["lang"]));
@@ -20,7 +74,84 @@ pub fn package() -> Package {
     pkg.register_function("or".into(), Or);
     pkg.register_function("refine".into(), Refine);
     pkg.register_function("traverse".into(), Traverse);
+    pkg.register_function("at".into(), At);
     pkg.register_function("chain".into(), Chain);
+    pkg.register_function("or-else".into(), OrElse);
+    pkg.register_function("optional".into(), Optional);
     pkg.register_function("not".into(), Not);
+    pkg.register_function("field-equal".into(), FieldEqual);
+    pkg.register_function("loose-equal".into(), LooseEqual);
+    pkg.register_function("requires".into(), Requires);
+    pkg.register_function("exactly-one-key".into(), ExactlyOneKey);
+    pkg.register_function("object-count-matching".into(), ObjectCountMatching);
+    pkg.register_function("labeled".into(), Labeled);
+    pkg.register_function("reason-field".into(), ReasonField);
+    pkg.register_function("unless".into(), Unless);
+    pkg.register_function("max-severity".into(), MaxSeverity);
+    pkg.register_function("by-version".into(), ByVersion);
+    pkg.register_function("in".into(), InValues);
+    pkg.register_function("all-values".into(), AllValues);
+    pkg.register_function("any-value".into(), AnyValue);
+    pkg.register_function("not-empty".into(), NotEmpty);
+    pkg.register_function("closed-object".into(), ClosedObject);
+    pkg.register_function("deprecated-field".into(), DeprecatedField);
+    pkg.register_function("deny".into(), Deny);
+    pkg.register_function("digest".into(), Digest);
+    pkg.register_function("response".into(), ResponseOf);
+    pkg.register_function("to-string".into(), Stringify);
+    pkg.register_function("build".into(), Build);
+    #[cfg(not(target_arch = "wasm32"))]
+    pkg.register_function("timeout".into(), Timeout);
     pkg
 }
+
+/// Maximum number of references followed when looking up an object pattern.
+const MAX_REFERENCES: usize = 32;
+
+/// Get the fields of an object pattern, following references to it.
+fn object_fields(pattern: &Arc<Pattern>, world: &World) -> Option<Vec<Arc<Field>>> {
+    let mut pattern = pattern.clone();
+    for _ in 0..MAX_REFERENCES {
+        pattern = match pattern.inner() {
+            InnerPattern::Object(object) => return Some(object.fields().clone()),
+            InnerPattern::Ref(_, slot, _) => world.get_by_slot(*slot)?,
+            InnerPattern::Bound(primary, _) | InnerPattern::Deref(primary) => primary.clone(),
+            _ => return None,
+        };
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lang::Severity;
+    use crate::runtime::testutil::{evaluate_with_package, Instrumented};
+    use crate::runtime::EvalOptions;
+
+    async fn evaluate(pattern: &str, short_circuit: bool) -> (Severity, usize) {
+        let instrumented = Instrumented::default();
+        let options = EvalOptions {
+            short_circuit,
+            ..Default::default()
+        };
+        let result = evaluate_with_package(instrumented.package(), pattern, 42, options).await;
+
+        (result.severity(), instrumented.calls())
+    }
+
+    #[tokio::test]
+    async fn or_short_circuit() {
+        let pattern = "42 || instrumented::term";
+
+        assert_eq!(evaluate(pattern, false).await, (Severity::None, 1));
+        assert_eq!(evaluate(pattern, true).await, (Severity::None, 0));
+    }
+
+    #[tokio::test]
+    async fn and_short_circuit() {
+        let pattern = "43 && instrumented::term";
+
+        assert_eq!(evaluate(pattern, false).await, (Severity::Error, 1));
+        assert_eq!(evaluate(pattern, true).await, (Severity::Error, 0));
+    }
+}
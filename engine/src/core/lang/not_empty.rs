@@ -0,0 +1,93 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("not-empty.adoc");
+
+#[derive(Debug)]
+pub struct NotEmpty;
+
+impl Function for NotEmpty {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let empty = match &*input {
+                RuntimeValue::Null => true,
+                RuntimeValue::String(value) => value.is_empty(),
+                RuntimeValue::List(value) => value.is_empty(),
+                RuntimeValue::Object(value) => value.iter().next().is_none(),
+                RuntimeValue::Octets(value) => value.is_empty(),
+                _ => false,
+            };
+
+            if empty {
+                let msg = match &*input {
+                    RuntimeValue::Null => "value is null".to_string(),
+                    other => format!("{} is empty", other.type_name()),
+                };
+                Ok(unsatisfied(msg))
+            } else {
+                Ok(Output::Identity.into())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    async fn assert_empty(value: serde_json::Value, reason: &str) {
+        let result = test_pattern("lang::not-empty", value).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(result.rationale().reason(), reason);
+    }
+
+    #[tokio::test]
+    async fn empty_string() {
+        assert_empty(json!(""), "string is empty").await;
+        assert_satisfied!(test_pattern("lang::not-empty", json!("foo")).await);
+    }
+
+    #[tokio::test]
+    async fn empty_list() {
+        assert_empty(json!([]), "list is empty").await;
+        assert_satisfied!(test_pattern("lang::not-empty", json!([1])).await);
+    }
+
+    #[tokio::test]
+    async fn empty_object() {
+        assert_empty(json!({}), "object is empty").await;
+        assert_satisfied!(test_pattern("lang::not-empty", json!({"foo": "bar"})).await);
+    }
+
+    #[tokio::test]
+    async fn null() {
+        assert_empty(json!(null), "value is null").await;
+    }
+
+    #[tokio::test]
+    async fn other_types() {
+        assert_satisfied!(test_pattern("lang::not-empty", json!(0)).await);
+        assert_satisfied!(test_pattern("lang::not-empty", json!(false)).await);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{EvaluationResult, ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("object-count-matching.adoc");
+
+const PATTERN: &str = "pattern";
+const N: &str = "n";
+
+#[derive(Debug)]
+pub struct ObjectCountMatching;
+
+impl Function for ObjectCountMatching {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), N.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let (Some(pattern), Some(expected_count)) = (bindings.get(PATTERN), bindings.get(N))
+            else {
+                return Ok(Severity::Error.into());
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let mut supporting: Vec<EvaluationResult> = Vec::new();
+            let mut count = 0usize;
+            for (_, value) in object.iter() {
+                let result = pattern
+                    .evaluate(value.clone(), ctx.push()?, &Default::default(), world)
+                    .await?;
+                if result.severity() < Severity::Error {
+                    count += 1;
+                }
+                supporting.push(result);
+            }
+
+            let count_result = expected_count
+                .evaluate(
+                    Arc::new(count.into()),
+                    ctx.push()?,
+                    &Default::default(),
+                    world,
+                )
+                .await?;
+
+            let (severity, rationale) = if count_result.severity() < Severity::Error {
+                (Severity::None, None)
+            } else {
+                (
+                    Severity::Error,
+                    Some(Arc::new(Rationale::Reason(
+                        format!(
+                            "{count} of {} values satisfied the pattern",
+                            supporting.len()
+                        )
+                        .into(),
+                        Arc::new(Rationale::Nothing),
+                    ))),
+                )
+            };
+            supporting.push(count_result);
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: Output::Identity,
+                rationale,
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    fn labels() -> serde_json::Value {
+        json!({
+            "reviewed": true,
+            "signed": true,
+            "tested": false,
+            "released": "no",
+        })
+    }
+
+    #[tokio::test]
+    async fn call_matching_exact_count() {
+        let result = test_pattern(r#"lang::object-count-matching<true, 2>"#, labels()).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_matching_at_least() {
+        let result = test_pattern(
+            r#"lang::object-count-matching<true, $(self >= 1)>"#,
+            labels(),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn call_not_matching() {
+        let result = test_pattern(r#"lang::object-count-matching<true, 3>"#, labels()).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2 of 4 values satisfied the pattern"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_not_an_object() {
+        let result = test_pattern(r#"lang::object-count-matching<true, 0>"#, json!([true])).await;
+
+        assert_not_satisfied!(result);
+    }
+}
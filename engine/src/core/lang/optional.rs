@@ -0,0 +1,130 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("optional.adoc");
+
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct Optional;
+
+impl Function for Optional {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            // values encoded as null are absent, missing fields are only passed
+            // on if declared optional (`field?:`) in the object pattern
+            if matches!(*input, RuntimeValue::Null) {
+                return Ok(Output::Identity.into());
+            }
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            Ok(FunctionEvaluationResult {
+                severity: result.severity(),
+                output: result.raw_output().clone(),
+                rationale: None,
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"{
+        spec: {
+            template: {
+                labels?: lang::optional<lang::all-values<string>>,
+                owner: lang::optional<string>,
+            },
+        },
+    }"#;
+
+    #[tokio::test]
+    async fn present_valid() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"spec": {"template": {"labels": {"app": "web"}, "owner": "ops"}}}),
+        )
+        .await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn present_invalid() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"spec": {"template": {"labels": {"replicas": 3}, "owner": "ops"}}}),
+        )
+        .await;
+        assert_not_satisfied!(result);
+
+        let result = test_pattern(PATTERN, json!({"spec": {"template": {"owner": 42}}})).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn absent() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"spec": {"template": {"labels": null, "owner": null}}}),
+        )
+        .await;
+        assert_satisfied!(result);
+
+        let result = test_pattern(PATTERN, json!({"spec": {"template": {"owner": null}}})).await;
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn missing() {
+        // `labels?` is declared optional in the object pattern
+        let result = test_pattern(PATTERN, json!({"spec": {"template": {"owner": "ops"}}})).await;
+        assert_satisfied!(result);
+
+        // `owner` is not, so the object pattern fails before the function is called
+        let result = test_pattern(PATTERN, json!({"spec": {"template": {}}})).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn transforms() {
+        let result = test_pattern(r#"lang::optional<base64::base64>"#, json!("aGVsbG8=")).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_octets(), Some(&b"hello".to_vec()));
+    }
+}
@@ -80,6 +80,10 @@ impl Function for Or {
 
                         // record result
                         supporting.push(result);
+
+                        if ctx.options.short_circuit && satisfied {
+                            break;
+                        }
                     }
 
                     // eval our severity (max non-failed, or failed)
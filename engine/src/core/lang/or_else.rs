@@ -0,0 +1,170 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("or-else.adoc");
+
+const PATTERN: &str = "pattern";
+const FALLBACK: &str = "fallback";
+
+#[derive(Debug)]
+pub struct OrElse;
+
+impl Function for OrElse {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), FALLBACK.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+            let Some(fallback) = bindings
+                .get(FALLBACK)
+                .and_then(|fallback| fallback.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("fallback must be a value"));
+            };
+
+            let fallback = Output::Transform(Arc::new(RuntimeValue::from(&fallback)));
+
+            let result = match pattern.evaluate(input, ctx.push()?, bindings, world).await {
+                Ok(result) => result,
+                // limits apply to the evaluation as a whole, falling back mustn't lift them
+                Err(err @ (RuntimeError::RecursionLimit(..) | RuntimeError::BudgetExceeded(_))) => {
+                    return Err(err)
+                }
+                Err(err) => {
+                    return Ok(FunctionEvaluationResult {
+                        severity: Severity::None,
+                        output: fallback,
+                        rationale: Some(Arc::new(Rationale::Labeled(
+                            "pattern failed to evaluate, using the fallback".into(),
+                            Arc::new(Rationale::Reason(
+                                err.to_string().into(),
+                                Arc::new(Rationale::Nothing),
+                            )),
+                        ))),
+                        supporting: Arc::new(vec![]),
+                    });
+                }
+            };
+
+            if result.severity() < Severity::Error {
+                return Ok(FunctionEvaluationResult {
+                    severity: result.severity(),
+                    output: result.raw_output().clone(),
+                    rationale: None,
+                    supporting: Arc::new(vec![result]),
+                });
+            }
+
+            Ok(FunctionEvaluationResult {
+                severity: Severity::None,
+                output: fallback,
+                rationale: Some(Arc::new(Rationale::Labeled(
+                    "pattern not satisfied, using the fallback".into(),
+                    result.rationale.clone(),
+                ))),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn primary() {
+        let result = test_pattern(
+            r#"lang::or-else<base64::base64, "unknown">"#,
+            json!("aGVsbG8="),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_octets(), Some(&b"hello".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn fallback() {
+        let result = test_pattern(
+            r#"lang::or-else<base64::base64, "unknown">"#,
+            json!("not base64!"),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output(), Arc::new(json!("unknown").into()));
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("pattern not satisfied, using the fallback"));
+    }
+
+    #[tokio::test]
+    async fn runtime_error() {
+        // a directory can't be read as data
+        let result =
+            test_pattern(r#"lang::or-else<data::contains<"data">, "unknown">"#, "MIT").await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output(), Arc::new(json!("unknown").into()));
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("pattern failed to evaluate, using the fallback"));
+    }
+
+    #[tokio::test]
+    async fn continue_chain() {
+        let pattern = r#"lang::or-else<self.version, "0.0.0"> | semver::parse"#;
+
+        let result = test_pattern(pattern, json!({ "name": "unversioned" })).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output(),
+            Arc::new(json!({"major": 0, "minor": 0, "patch": 0}).into())
+        );
+
+        let result = test_pattern(pattern, json!({ "version": "1.2.3" })).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output(),
+            Arc::new(json!({"major": 1, "minor": 2, "patch": 3}).into())
+        );
+
+        // the fallback is used as input of the rest of the chain, which can still fail
+        let result = test_pattern(
+            r#"lang::or-else<self.version, "none"> | semver::parse"#,
+            json!({}),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+    }
+}
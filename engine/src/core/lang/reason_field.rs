@@ -0,0 +1,137 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("reason-field.adoc");
+
+const NAME: &str = "name";
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct ReasonField;
+
+impl Function for ReasonField {
+    fn parameters(&self) -> Vec<String> {
+        vec![NAME.into(), PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(field)) =
+                bindings.get(NAME).and_then(|name| name.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("field name must be a string"));
+            };
+
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let reason = input
+                .try_get_object()
+                .and_then(|object| object.get(&*field))
+                .and_then(|value| value.try_get_str().map(Arc::<str>::from));
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            let severity = result.severity();
+            let rationale = match reason {
+                Some(reason) if severity >= Severity::Error => {
+                    Rationale::Reason(reason, result.rationale.clone())
+                }
+                // fall back to the reason of the pattern
+                _ => result.rationale.as_ref().clone(),
+            };
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: result.output.clone(),
+                rationale: Some(Arc::new(rationale)),
+                supporting: Arc::new(vec![result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::Response;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::reason-field<"note", { signed: true }>"#;
+
+    #[tokio::test]
+    async fn reason_from_field() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"signed": false, "note": "policy v2 requires signed artifacts"}),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "policy v2 requires signed artifacts"
+        );
+    }
+
+    #[tokio::test]
+    async fn reason_without_field() {
+        let result = test_pattern(PATTERN, json!({"signed": false})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "Because not all fields were satisfied"
+        );
+    }
+
+    #[tokio::test]
+    async fn reason_not_a_string() {
+        let result = test_pattern(PATTERN, json!({"signed": false, "note": 2})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "Because not all fields were satisfied"
+        );
+    }
+
+    #[tokio::test]
+    async fn reason_satisfied() {
+        let result = test_pattern(
+            PATTERN,
+            json!({"signed": true, "note": "policy v2 requires signed artifacts"}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            Response::new(&result).reason,
+            "Because all fields were satisfied"
+        );
+    }
+}
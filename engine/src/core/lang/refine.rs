@@ -53,3 +53,57 @@ impl Function for Refine {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::value::RuntimeValue;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn refine_transformed() {
+        let result = test_pattern(r#"string::length($(self == 5))"#, json!("hello")).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(5));
+
+        let result = test_pattern(r#"string::length($(self == 5))"#, json!("hi")).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn refine_does_not_see_original() {
+        let result = test_pattern(r#"string::length("hello")"#, json!("hello")).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn refine_composed_transforms() {
+        let result = test_pattern(
+            r#"string::split<",">(list::count($(self == 3)))"#,
+            json!("a,b,c"),
+        )
+        .await;
+        assert_satisfied!(&result);
+        // the output of the refinement is the output of the whole pattern
+        assert_eq!(*result.output(), RuntimeValue::Integer(3));
+
+        let result = test_pattern(
+            r#"string::split<",">(list::count($(self == 3)))"#,
+            json!("a,b"),
+        )
+        .await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn refine_chained_refinements() {
+        let result = test_pattern(
+            r#"string::split<",">(list::all<string>)(list::count(2))"#,
+            json!("a,b"),
+        )
+        .await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(2));
+    }
+}
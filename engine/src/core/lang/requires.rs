@@ -0,0 +1,116 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("requires.adoc");
+
+const IF_FIELD: &str = "if-field";
+const THEN_FIELD: &str = "then-field";
+
+#[derive(Debug)]
+pub struct Requires;
+
+impl Function for Requires {
+    fn parameters(&self) -> Vec<String> {
+        vec![IF_FIELD.into(), THEN_FIELD.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let condition = match get_field_name(IF_FIELD, bindings) {
+                Ok(name) => name,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+            let required = match get_field_name(THEN_FIELD, bindings) {
+                Ok(name) => name,
+                Err(msg) => return Ok(invalid_arg(msg)),
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            if object.get(&condition).is_some() && object.get(&required).is_none() {
+                Ok(unsatisfied(format!(
+                    "field '{required}' is required when field '{condition}' is present"
+                )))
+            } else {
+                Ok(Output::Identity.into())
+            }
+        })
+    }
+}
+
+fn get_field_name(param: &str, bindings: &Bindings) -> Result<Arc<str>, String> {
+    match bindings.get(param).and_then(|p| p.try_get_resolved_value()) {
+        Some(ValuePattern::String(name)) => Ok(name),
+        _ => Err(format!("invalid field name specified for {param}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::requires<"tls", "certificate">"#;
+
+    #[tokio::test]
+    async fn both_present() {
+        let result = test_pattern(PATTERN, json!({"tls": true, "certificate": "cert.pem"})).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn only_condition_present() {
+        let result = test_pattern(PATTERN, json!({"tls": true})).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "field 'certificate' is required when field 'tls' is present"
+        );
+    }
+
+    #[tokio::test]
+    async fn only_required_present() {
+        let result = test_pattern(PATTERN, json!({"certificate": "cert.pem"})).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn none_present() {
+        let result = test_pattern(PATTERN, json!({"port": 8080})).await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn not_an_object() {
+        let result = test_pattern(PATTERN, json!(["tls"])).await;
+
+        assert_not_satisfied!(result);
+    }
+}
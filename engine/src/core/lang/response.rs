@@ -0,0 +1,83 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, Response, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("response.adoc");
+
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct ResponseOf;
+
+impl Function for ResponseOf {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            // the response is the subject of the next pass, failing the pattern doesn't fail this
+            match serde_json::to_value(Response::new(&result)) {
+                Ok(response) => Ok(Output::Transform(Arc::new(response.into())).into()),
+                Err(err) => Ok(invalid_arg(format!("failed to encode response: {err}"))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::{test_pattern, test_patterns};
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn response_as_output() {
+        let result = test_pattern("lang::response<{ name: string }>", json!({"name": 42})).await;
+
+        assert_satisfied!(&result);
+        let output = result.output().as_json();
+        assert_eq!(output["severity"], json!("error"));
+        assert_eq!(output["input"], json!({"name": 42}));
+    }
+
+    #[tokio::test]
+    async fn assert_on_severity() {
+        let patterns = r#"
+pattern component = {
+    name: string,
+}
+
+pattern test-pattern = lang::response<component> | { severity: "error" }
+"#;
+
+        assert_satisfied!(test_patterns(patterns, json!({"name": 42})).await);
+        assert_not_satisfied!(test_patterns(patterns, json!({"name": "bob"})).await);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DOCUMENTATION: &str = include_str!("timeout.adoc");
+
+const PATTERN: &str = "pattern";
+const MILLIS: &str = "millis";
+
+#[derive(Debug)]
+pub struct Timeout;
+
+impl Function for Timeout {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), MILLIS.into()]
+    }
+
+    fn deterministic(&self) -> bool {
+        // whether it times out depends on the load at the time
+        false
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+            let millis = match bindings
+                .get(MILLIS)
+                .and_then(|millis| millis.try_get_resolved_value())
+            {
+                Some(ValuePattern::Integer(millis)) if millis >= 0 => millis as u64,
+                _ => return Ok(invalid_arg("millis must be a non-negative integer literal")),
+            };
+
+            // timing out needs a Tokio runtime, which must have its timers enabled too
+            if tokio::runtime::Handle::try_current().is_err() {
+                return Ok(unsatisfied("timeouts require a Tokio runtime"));
+            }
+
+            let evaluation = pattern.evaluate(input, ctx.push()?, bindings, world);
+            match tokio::time::timeout(Duration::from_millis(millis), evaluation).await {
+                Ok(result) => {
+                    let result = result?;
+                    Ok((result.severity(), vec![result]).into())
+                }
+                // only this evaluation is abandoned, the rest carries on
+                Err(_) => Ok(unsatisfied(format!(
+                    "evaluation timed out after {millis}ms"
+                ))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lang::Severity;
+    use crate::runtime::testutil::{evaluate_with_package, Instrumented};
+    use crate::runtime::{EvalOptions, EvaluationResult, Response};
+    use futures_util::FutureExt;
+    use serde_json::json;
+    use std::time::Duration;
+
+    async fn evaluate(pattern: &str, value: serde_json::Value) -> EvaluationResult {
+        // satisfied by 42, after a second
+        let slow = Instrumented::default().delayed(Duration::from_secs(1));
+        evaluate_with_package(slow.package(), pattern, value, EvalOptions::new()).await
+    }
+
+    #[tokio::test]
+    async fn within_budget() {
+        let result = evaluate("lang::timeout<string, 1000>", json!("bob")).await;
+        assert_eq!(result.severity(), Severity::None);
+
+        let result = evaluate("lang::timeout<string, 1000>", json!(42)).await;
+        assert_eq!(result.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn without_runtime() {
+        // nothing to wait for, so that it completes without a runtime
+        let result = evaluate("lang::timeout<string, 1000>", json!("bob"))
+            .now_or_never()
+            .unwrap();
+        assert_eq!(result.severity(), Severity::Error);
+        assert_eq!(
+            result.rationale().reason(),
+            "timeouts require a Tokio runtime"
+        );
+    }
+
+    #[tokio::test]
+    async fn timed_out_while_siblings_succeed() {
+        let result = evaluate(
+            "{ slow: lang::timeout<instrumented::term, 10>, fast: lang::timeout<string, 1000> }",
+            json!({"slow": 42, "fast": "bob"}),
+        )
+        .await;
+        assert_eq!(result.severity(), Severity::Error);
+
+        let response = Response::new(&result);
+        let field = |name: &str| {
+            response
+                .rationale
+                .iter()
+                .find(|r| r.name.to_string() == name)
+                .unwrap()
+        };
+        assert_eq!(field("slow").severity, Severity::Error);
+        assert_eq!(field("fast").severity, Severity::None);
+
+        // the slow branch is abandoned, an alternative still satisfies the pattern
+        let result = evaluate(
+            "lang::timeout<instrumented::term, 10> || integer",
+            json!(42),
+        )
+        .await;
+        assert_eq!(result.severity(), Severity::None);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("to-string.adoc");
+
+#[derive(Debug)]
+pub struct Stringify;
+
+impl Function for Stringify {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            match &*input {
+                RuntimeValue::String(_) => Ok(Output::Identity.into()),
+                RuntimeValue::Integer(_)
+                | RuntimeValue::Decimal(_)
+                | RuntimeValue::Boolean(_)
+                | RuntimeValue::Timestamp(_) => {
+                    Ok(Output::Transform(Arc::new(input.to_string().into())).into())
+                }
+                other => {
+                    let msg = format!("{} can't be converted to a string", other.type_name());
+                    Ok(unsatisfied(msg))
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    async fn assert_converted(value: serde_json::Value, expected: &str) {
+        let result = test_pattern("lang::to-string", value).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!(expected));
+    }
+
+    #[tokio::test]
+    async fn scalars() {
+        assert_converted(json!(42), "42").await;
+        assert_converted(json!(-1), "-1").await;
+        assert_converted(json!(1.5), "1.5").await;
+        assert_converted(json!(true), "true").await;
+        assert_converted(json!(false), "false").await;
+        assert_converted(json!("foo"), "foo").await;
+    }
+
+    #[tokio::test]
+    async fn feeds_string_functions() {
+        assert_satisfied!(test_pattern("lang::to-string(string::length(4))", json!(2023)).await);
+        assert_not_satisfied!(test_pattern("lang::to-string(\"2023\")", json!(2024)).await);
+    }
+
+    #[tokio::test]
+    async fn rejected() {
+        let result = test_pattern("lang::to-string", json!({"name": "bob"})).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "object can't be converted to a string"
+        );
+
+        let result = test_pattern("lang::to-string", json!([1, 2])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "list can't be converted to a string"
+        );
+
+        assert_not_satisfied!(test_pattern("lang::to-string", json!(null)).await);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("unless.adoc");
+
+const CONDITION: &str = "condition";
+const PATTERN: &str = "pattern";
+
+#[derive(Debug)]
+pub struct Unless;
+
+impl Function for Unless {
+    fn parameters(&self) -> Vec<String> {
+        vec![CONDITION.into(), PATTERN.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let (Some(condition), Some(pattern)) = (bindings.get(CONDITION), bindings.get(PATTERN))
+            else {
+                return Ok(Severity::Error.into());
+            };
+
+            let guard = condition
+                .evaluate(input.clone(), ctx.push()?, bindings, world)
+                .await?;
+
+            if guard.severity() < Severity::Error {
+                // the guard fired, the pattern is not required
+                return Ok(FunctionEvaluationResult {
+                    severity: Severity::None,
+                    output: Output::Identity,
+                    rationale: Some(Arc::new(Rationale::Labeled(
+                        "condition satisfied, pattern not required".into(),
+                        guard.rationale.clone(),
+                    ))),
+                    supporting: Arc::new(vec![guard]),
+                });
+            }
+
+            let result = pattern
+                .evaluate(input, ctx.push()?, bindings, world)
+                .await?;
+
+            Ok(FunctionEvaluationResult {
+                severity: result.severity(),
+                output: Output::Identity,
+                rationale: Some(Arc::new(Rationale::Labeled(
+                    "condition not satisfied, pattern required".into(),
+                    result.rationale.clone(),
+                ))),
+                supporting: Arc::new(vec![guard, result]),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const PATTERN: &str = r#"lang::unless<{ exempt: true }, { signature: string }>"#;
+
+    #[tokio::test]
+    async fn guarded() {
+        let result = test_pattern(PATTERN, json!({"exempt": true})).await;
+
+        assert_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("condition satisfied, pattern not required"));
+    }
+
+    #[tokio::test]
+    async fn unguarded_satisfied() {
+        let result = test_pattern(PATTERN, json!({"exempt": false, "signature": "abc"})).await;
+
+        assert_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("condition not satisfied, pattern required"));
+    }
+
+    #[tokio::test]
+    async fn unguarded_not_satisfied() {
+        let result = test_pattern(PATTERN, json!({"name": "unsigned"})).await;
+
+        assert_not_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with("condition not satisfied, pattern required"));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Aggregate a list of numbers into a single one.
+#[derive(Debug)]
+pub enum Aggregate {
+    Sum,
+    Min,
+    Max,
+    Avg,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Number {
+    Integer(i64),
+    Decimal(f64),
+}
+
+impl Number {
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Decimal(value) => value,
+        }
+    }
+
+    fn plus(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Integer(lhs), Self::Integer(rhs)) => match lhs.checked_add(rhs) {
+                Some(sum) => Self::Integer(sum),
+                None => Self::Decimal(lhs as f64 + rhs as f64),
+            },
+            (lhs, rhs) => Self::Decimal(lhs.as_f64() + rhs.as_f64()),
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Integer(lhs), Self::Integer(rhs)) => Self::Integer(lhs.min(rhs)),
+            (lhs, rhs) => Self::Decimal(lhs.as_f64().min(rhs.as_f64())),
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Integer(lhs), Self::Integer(rhs)) => Self::Integer(lhs.max(rhs)),
+            (lhs, rhs) => Self::Decimal(lhs.as_f64().max(rhs.as_f64())),
+        }
+    }
+}
+
+impl From<Number> for RuntimeValue {
+    fn from(value: Number) -> Self {
+        match value {
+            Number::Integer(value) => RuntimeValue::Integer(value),
+            Number::Decimal(value) => RuntimeValue::Decimal(value),
+        }
+    }
+}
+
+impl Aggregate {
+    fn aggregate(&self, numbers: &[Number]) -> Result<Number, &'static str> {
+        let mut iter = numbers.iter().copied();
+        match self {
+            Self::Sum => Ok(iter.fold(Number::Integer(0), Number::plus)),
+            Self::Min => iter
+                .reduce(Number::min)
+                .ok_or("list is empty, it has no minimum"),
+            Self::Max => iter
+                .reduce(Number::max)
+                .ok_or("list is empty, it has no maximum"),
+            Self::Avg => match iter.next() {
+                Some(first) => {
+                    let sum = iter.fold(first, Number::plus);
+                    Ok(Number::Decimal(sum.as_f64() / numbers.len() as f64))
+                }
+                None => Err("list is empty, it has no average"),
+            },
+        }
+    }
+}
+
+impl Function for Aggregate {
+    fn metadata(&self) -> PatternMeta {
+        let documentation = match self {
+            Self::Sum => include_str!("sum.adoc"),
+            Self::Min => include_str!("min.adoc"),
+            Self::Max => include_str!("max.adoc"),
+            Self::Avg => include_str!("avg.adoc"),
+        };
+        PatternMeta {
+            documentation: documentation.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(list) = input.try_get_list() else {
+                return Ok((Severity::Error, Rationale::NotAList).into());
+            };
+
+            let mut numbers = Vec::with_capacity(list.len());
+            for (idx, item) in list.iter().enumerate() {
+                match &**item {
+                    RuntimeValue::Integer(value) => numbers.push(Number::Integer(*value)),
+                    RuntimeValue::Decimal(value) => numbers.push(Number::Decimal(*value)),
+                    other => {
+                        let msg = format!(
+                            "element at index {idx} is not a number, but {}",
+                            other.type_name()
+                        );
+                        return Ok(unsatisfied(msg));
+                    }
+                }
+            }
+
+            match self.aggregate(&numbers) {
+                Ok(result) => Ok(Output::Transform(Arc::new(result.into())).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::value::RuntimeValue;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn sum() {
+        let result = test_pattern("list::sum($(self < 100))", json!([1, 2, 3])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(6));
+
+        let result = test_pattern("list::sum", json!([1, 2.5])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Decimal(3.5));
+
+        let result = test_pattern("list::sum($(self < 100))", json!([50, 60])).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn sum_empty() {
+        let result = test_pattern("list::sum", json!([])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(0));
+    }
+
+    #[tokio::test]
+    async fn min() {
+        let result = test_pattern("list::min", json!([3, 1, 2])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(1));
+
+        let result = test_pattern("list::min", json!([3, 1.5, 2])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Decimal(1.5));
+    }
+
+    #[tokio::test]
+    async fn min_empty() {
+        let result = test_pattern("list::min", json!([])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "list is empty, it has no minimum"
+        );
+    }
+
+    #[tokio::test]
+    async fn max() {
+        let result = test_pattern("list::max($(self <= 10))", json!([3, 10, 2])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Integer(10));
+
+        let result = test_pattern("list::max", json!([3, 1.5])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Decimal(3.0));
+    }
+
+    #[tokio::test]
+    async fn max_empty() {
+        let result = test_pattern("list::max", json!([])).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn avg() {
+        let result = test_pattern("list::avg", json!([1, 2])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Decimal(1.5));
+
+        let result = test_pattern("list::avg($(self >= 4.0))", json!([5, 4.5, 3])).await;
+        assert_satisfied!(&result);
+        assert_eq!(*result.output(), RuntimeValue::Decimal(4.166666666666667));
+    }
+
+    #[tokio::test]
+    async fn avg_empty() {
+        let result = test_pattern("list::avg", json!([])).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn not_a_number() {
+        let result = test_pattern("list::sum", json!([1, "two", 3])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "element at index 1 is not a number, but string"
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::core::{list::PATTERN, Function, FunctionEvaluationResult};
+use crate::core::list::{evaluate_until, PATTERN};
+use crate::core::{Function, FunctionEvaluationResult};
 use crate::lang::lir::Bindings;
 use crate::runtime::{ExecutionContext, RuntimeError, World};
 use crate::value::RuntimeValue;
@@ -37,14 +38,9 @@ impl Function for All {
         Box::pin(async move {
             if let Some(list) = input.try_get_list() {
                 let pattern = bindings.get(PATTERN).unwrap();
-                let mut supporting = Vec::new();
-                for item in list {
-                    supporting.push(
-                        pattern
-                            .evaluate(item.clone(), ctx.push()?, &Default::default(), world)
-                            .await?,
-                    );
-                }
+                // an error decides the outcome already
+                let supporting =
+                    evaluate_until(ctx, world, &pattern, list, |s| s == Severity::Error).await?;
 
                 let severity = supporting.iter().map(|s| s.severity()).collect();
                 Ok((severity, supporting).into())
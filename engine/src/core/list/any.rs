@@ -1,4 +1,5 @@
-use crate::core::{list::PATTERN, Function, FunctionEvaluationResult};
+use crate::core::list::{evaluate_until, PATTERN};
+use crate::core::{Function, FunctionEvaluationResult};
 use crate::lang::lir::Bindings;
 use crate::runtime::{ExecutionContext, RuntimeError, World};
 use crate::value::RuntimeValue;
@@ -37,17 +38,13 @@ impl Function for Any {
         Box::pin(async move {
             if let Some(list) = input.try_get_list() {
                 let pattern = bindings.get(PATTERN).unwrap();
-                let mut supporting = Vec::with_capacity(list.len());
-                let mut severity = Severity::Error;
-                for item in list {
-                    let result = pattern
-                        .evaluate(item.clone(), ctx.push()?, &Default::default(), world)
-                        .await?;
-                    if result.severity() < Severity::Error {
-                        severity = Severity::None;
-                    }
-                    supporting.push(result);
-                }
+                // a match decides the outcome already
+                let supporting =
+                    evaluate_until(ctx, world, &pattern, list, |s| s < Severity::Error).await?;
+                let severity = match supporting.iter().any(|s| s.severity() < Severity::Error) {
+                    true => Severity::None,
+                    false => Severity::Error,
+                };
 
                 Ok((severity, supporting).into())
             } else {
@@ -1,12 +1,11 @@
-use crate::core::{Function, FunctionEvaluationResult};
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
 use crate::lang::lir::{Bindings, InnerPattern};
-use crate::runtime::rationale::Rationale;
 use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
 use crate::value::RuntimeValue;
 use std::future::Future;
 use std::pin::Pin;
 
-use crate::lang::{PatternMeta, Severity};
+use crate::lang::PatternMeta;
 use std::sync::Arc;
 
 const DOCUMENTATION: &str = include_str!("concat.adoc");
@@ -39,7 +38,7 @@ impl Function for Concat {
                 let list = match get_parameter(LIST, bindings) {
                     Ok(value) => value,
                     Err(msg) => {
-                        return invalid_arg(msg);
+                        return Ok(invalid_arg(msg));
                     }
                 };
                 let mut input_list = input_list.clone();
@@ -75,13 +74,10 @@ fn get_parameter(param: &str, bindings: &Bindings) -> Result<Vec<Arc<RuntimeValu
     }
 }
 
-fn invalid_arg(msg: impl Into<Arc<str>>) -> Result<FunctionEvaluationResult, RuntimeError> {
-    Ok((Severity::Error, Rationale::InvalidArgument(msg.into())).into())
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::runtime::rationale::Rationale;
     use crate::{assert_not_satisfied, assert_satisfied, runtime::testutil::test_pattern};
     use serde_json::json;
 
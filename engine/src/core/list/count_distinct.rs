@@ -0,0 +1,129 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("count-distinct.adoc");
+const FIELD: &str = "field";
+
+#[derive(Debug)]
+pub struct CountDistinct;
+
+impl Function for CountDistinct {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![FIELD.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(field)) = bindings
+                .get(FIELD)
+                .and_then(|field| field.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("field must be a string literal"));
+            };
+
+            let Some(list) = input.try_get_list() else {
+                return Ok((Severity::Error, Rationale::NotAList).into());
+            };
+
+            // values are compared by their canonical form, as runtime values can't be hashed
+            let distinct: HashSet<_> = list
+                .iter()
+                .filter_map(|item| item.try_get_object().and_then(|o| o.get(&field)))
+                .map(|value| value.as_canonical_json())
+                .collect();
+
+            Ok(Output::Transform(Arc::new(distinct.len().into())).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn repeated_values() {
+        let result = test_pattern(
+            r#"list::count-distinct<"license">"#,
+            json!([
+                {"name": "serde", "license": "MIT"},
+                {"name": "tokio", "license": "MIT"},
+                {"name": "ring", "license": "ISC"},
+                {"name": "openssl", "license": "Apache-2.0"},
+                {"name": "log", "license": "Apache-2.0"},
+                {"name": "unlicensed"},
+            ]),
+        )
+        .await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_integer().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn unique_values() {
+        let result = test_pattern(
+            r#"list::count-distinct<"license">"#,
+            json!([
+                {"name": "serde", "license": "MIT"},
+                {"name": "ring", "license": "ISC"},
+            ]),
+        )
+        .await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_integer().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn refined() {
+        let components = json!([
+            {"license": "MIT"},
+            {"license": "ISC"},
+            {"license": "MIT"},
+        ]);
+
+        assert_satisfied!(
+            test_pattern(
+                r#"list::count-distinct<"license">( $(self <= 2) )"#,
+                components.clone()
+            )
+            .await
+        );
+        assert_not_satisfied!(
+            test_pattern(
+                r#"list::count-distinct<"license">( $(self <= 1) )"#,
+                components
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_list() {
+        assert_not_satisfied!(
+            test_pattern(r#"list::count-distinct<"license">"#, json!("MIT")).await
+        );
+    }
+}
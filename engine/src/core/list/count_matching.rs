@@ -0,0 +1,175 @@
+use crate::core::list::PATTERN;
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{EvaluationResult, ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("count-matching.adoc");
+
+const LOWER: &str = "lower";
+const UPPER: &str = "upper";
+
+#[derive(Debug)]
+pub struct CountMatching;
+
+impl Function for CountMatching {
+    fn parameters(&self) -> Vec<String> {
+        vec![PATTERN.into(), LOWER.into(), UPPER.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(pattern) = bindings.get(PATTERN) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let (lower, upper) = match (bound(LOWER, bindings), bound(UPPER, bindings)) {
+                (Ok(lower), Ok(upper)) => (lower, upper),
+                (Err(msg), _) | (_, Err(msg)) => return Ok(invalid_arg(msg)),
+            };
+            if lower > upper {
+                return Ok(invalid_arg(
+                    "lower bound cannot be greater than upper bound",
+                ));
+            }
+
+            let Some(list) = input.try_get_list() else {
+                return Ok((Severity::Error, Rationale::NotAList).into());
+            };
+
+            let mut supporting: Vec<EvaluationResult> = Vec::new();
+            let mut count = 0usize;
+            for item in list {
+                let result = pattern
+                    .evaluate(item.clone(), ctx.push()?, &Default::default(), world)
+                    .await?;
+                if result.severity() < Severity::Error {
+                    count += 1;
+                }
+                supporting.push(result);
+            }
+
+            let (severity, rationale) = if (lower..=upper).contains(&count) {
+                (Severity::None, None)
+            } else {
+                (
+                    Severity::Error,
+                    Some(Arc::new(Rationale::Reason(
+                        format!(
+                            "{count} of {} elements satisfied the pattern, expected between {lower} and {upper}",
+                            supporting.len()
+                        )
+                        .into(),
+                        Arc::new(Rationale::Nothing),
+                    ))),
+                )
+            };
+
+            Ok(FunctionEvaluationResult {
+                severity,
+                output: Output::Identity,
+                rationale,
+                supporting: Arc::new(supporting),
+            })
+        })
+    }
+}
+
+fn bound(param: &str, bindings: &Bindings) -> Result<usize, String> {
+    match bindings.get(param).and_then(|p| p.try_get_resolved_value()) {
+        Some(ValuePattern::Integer(value)) if value >= 0 => Ok(value as usize),
+        _ => Err(format!("{param} bound must be a non-negative integer")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    fn signatures(n: usize) -> serde_json::Value {
+        let mut items = vec![json!({"keyid": "abc"}); n];
+        items.push(json!({ "keyid": null }));
+        json!(items)
+    }
+
+    #[tokio::test]
+    async fn call_below_range() {
+        let result = test_pattern(
+            r#"list::count-matching<{keyid: string}, 1, 3>"#,
+            signatures(0),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "0 of 1 elements satisfied the pattern, expected between 1 and 3"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_within_range() {
+        for n in 1..=3 {
+            let result = test_pattern(
+                r#"list::count-matching<{keyid: string}, 1, 3>"#,
+                signatures(n),
+            )
+            .await;
+
+            assert_satisfied!(result);
+        }
+    }
+
+    #[tokio::test]
+    async fn call_above_range() {
+        let result = test_pattern(
+            r#"list::count-matching<{keyid: string}, 1, 3>"#,
+            signatures(4),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "4 of 5 elements satisfied the pattern, expected between 1 and 3"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_invalid_range() {
+        let result = test_pattern(r#"list::count-matching<42, 3, 1>"#, json!([42])).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: lower bound cannot be greater than upper bound"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_not_a_list() {
+        let result = test_pattern(r#"list::count-matching<42, 0, 1>"#, json!(42)).await;
+
+        assert_not_satisfied!(result);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Pick a single element of a list.
+#[derive(Debug)]
+pub enum Element {
+    First,
+    Last,
+}
+
+impl Function for Element {
+    fn metadata(&self) -> PatternMeta {
+        let documentation = match self {
+            Self::First => include_str!("first.adoc"),
+            Self::Last => include_str!("last.adoc"),
+        };
+        PatternMeta {
+            documentation: documentation.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(list) = input.try_get_list() else {
+                return Ok((Severity::Error, Rationale::NotAList).into());
+            };
+
+            let element = match self {
+                Self::First => list.first(),
+                Self::Last => list.last(),
+            };
+
+            match element {
+                Some(element) => Ok(Output::Transform(element.clone()).into()),
+                None => {
+                    let msg = match self {
+                        Self::First => "list is empty, it has no first element",
+                        Self::Last => "list is empty, it has no last element",
+                    };
+                    Ok(unsatisfied(msg))
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn first() {
+        let result = test_pattern("list::first", json!([{"name": "bob"}, 2, 3])).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"name": "bob"}));
+
+        assert_satisfied!(
+            test_pattern("list::first({ name: \"bob\" })", json!([{"name": "bob"}])).await
+        );
+        assert_not_satisfied!(test_pattern("list::first(2)", json!([1, 2])).await);
+    }
+
+    #[tokio::test]
+    async fn last() {
+        let result = test_pattern("list::last", json!([1, 2, 3])).await;
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!(3));
+
+        assert_not_satisfied!(test_pattern("list::last(1)", json!([1, 2])).await);
+    }
+
+    #[tokio::test]
+    async fn empty_list() {
+        let result = test_pattern("list::first", json!([])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "list is empty, it has no first element"
+        );
+
+        let result = test_pattern("list::last", json!([])).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "list is empty, it has no last element"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_list() {
+        assert_not_satisfied!(test_pattern("list::first", json!("foo")).await);
+    }
+}
@@ -1,16 +1,20 @@
 use crate::{
     lang::{lir::Pattern, Severity},
     package::Package,
-    runtime::{ExecutionContext, PackagePath, RuntimeError, World},
+    runtime::{EvaluationResult, ExecutionContext, PackagePath, RuntimeError, World},
     value::RuntimeValue,
 };
 use std::sync::Arc;
 
+pub mod aggregate;
 pub mod all;
 pub mod any;
 pub mod concat;
 pub mod contains;
 pub mod count;
+pub mod count_distinct;
+pub mod count_matching;
+pub mod element;
 pub mod filter;
 pub mod head;
 pub mod map;
@@ -18,6 +22,8 @@ pub mod none;
 pub mod slice;
 pub mod some;
 pub mod tail;
+pub mod unique_by;
+pub mod zip;
 
 const COUNT: &str = "count";
 const PATTERN: &str = "pattern";
@@ -35,12 +41,55 @@ pub fn package() -> Package {
     pkg.register_function("append".into(), concat::Concat);
     pkg.register_function("count".into(), count::Count);
     pkg.register_function("length".into(), count::Count);
+    pkg.register_function("count-matching".into(), count_matching::CountMatching);
+    pkg.register_function("count-distinct".into(), count_distinct::CountDistinct);
     pkg.register_function("contains-all".into(), contains::ContainsAll);
     pkg.register_function("filter".into(), filter::Filter);
     pkg.register_function("map".into(), map::Map);
+    pkg.register_function("sum".into(), aggregate::Aggregate::Sum);
+    pkg.register_function("min".into(), aggregate::Aggregate::Min);
+    pkg.register_function("max".into(), aggregate::Aggregate::Max);
+    pkg.register_function("avg".into(), aggregate::Aggregate::Avg);
+    pkg.register_function("first".into(), element::Element::First);
+    pkg.register_function("last".into(), element::Element::Last);
+    pkg.register_function("unique-by".into(), unique_by::UniqueBy);
+    pkg.register_function("zip".into(), zip::Zip);
     pkg
 }
 
+/// Evaluate a pattern against the items of a list, one at a time.
+///
+/// With the `short_circuit` evaluation option enabled, items are only evaluated until the first
+/// result satisfying `stop`, the remaining items are skipped. The results of all evaluated items
+/// are returned.
+pub(crate) async fn evaluate_until<'i, I, F>(
+    ctx: ExecutionContext<'_>,
+    world: &World,
+    pattern: &Arc<Pattern>,
+    items: I,
+    stop: F,
+) -> Result<Vec<EvaluationResult>, RuntimeError>
+where
+    I: IntoIterator<Item = &'i Arc<RuntimeValue>>,
+    F: Fn(Severity) -> bool,
+{
+    let short_circuit = ctx.options.short_circuit;
+    let mut results = Vec::new();
+
+    for item in items {
+        let result = pattern
+            .evaluate(item.clone(), ctx.push()?, &Default::default(), world)
+            .await?;
+        let done = short_circuit && stop(result.severity());
+        results.push(result);
+        if done {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
 /// Split a list of values at given predicate.
 ///
 /// This takes an iterator of values, splitting it in two lists. Filling the first one until the
@@ -88,3 +137,53 @@ where
 
     Ok((greedy, i.collect()))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::lang::Severity;
+    use crate::runtime::testutil::{evaluate_with_package, Instrumented};
+    use crate::runtime::EvalOptions;
+
+    async fn evaluate(
+        pattern: &str,
+        value: serde_json::Value,
+        short_circuit: bool,
+    ) -> (Severity, usize) {
+        let instrumented = Instrumented::default();
+        let options = EvalOptions {
+            short_circuit,
+            ..Default::default()
+        };
+        let result = evaluate_with_package(instrumented.package(), pattern, value, options).await;
+
+        (result.severity(), instrumented.calls())
+    }
+
+    #[tokio::test]
+    async fn all_short_circuit() {
+        let mut items = vec![42; 10_000];
+        items[10] = 0;
+        let items = serde_json::json!(items);
+        let pattern = "list::all<instrumented::term>";
+
+        assert_eq!(
+            evaluate(pattern, items.clone(), false).await,
+            (Severity::Error, 10_000)
+        );
+        assert_eq!(evaluate(pattern, items, true).await, (Severity::Error, 11));
+    }
+
+    #[tokio::test]
+    async fn any_short_circuit() {
+        let mut items = vec![0; 10_000];
+        items[10] = 42;
+        let items = serde_json::json!(items);
+        let pattern = "list::any<instrumented::term>";
+
+        assert_eq!(
+            evaluate(pattern, items.clone(), false).await,
+            (Severity::None, 10_000)
+        );
+        assert_eq!(evaluate(pattern, items, true).await, (Severity::None, 11));
+    }
+}
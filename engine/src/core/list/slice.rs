@@ -1,7 +1,6 @@
-use crate::core::{Function, FunctionEvaluationResult};
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
 use crate::lang::lir::{Bindings, InnerPattern};
-use crate::lang::{Severity, ValuePattern};
-use crate::runtime::rationale::Rationale;
+use crate::lang::ValuePattern;
 use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
 use crate::value::RuntimeValue;
 use std::future::Future;
@@ -41,17 +40,17 @@ impl Function for Slice {
                 let start = match get_parameter(START, bindings) {
                     Ok(value) => value,
                     Err(msg) => {
-                        return invalid_arg(msg);
+                        return Ok(invalid_arg(msg));
                     }
                 };
                 let end = match get_parameter(END, bindings) {
                     Ok(value) => value,
                     Err(msg) => {
-                        return invalid_arg(msg);
+                        return Ok(invalid_arg(msg));
                     }
                 };
                 if start > end {
-                    return invalid_arg("start index cannot be greater than end index");
+                    return Ok(invalid_arg("start index cannot be greater than end index"));
                 }
                 let s = &list[start..end];
                 return Ok(Output::Transform(Arc::new(RuntimeValue::List(s.to_vec()))).into());
@@ -74,14 +73,11 @@ fn get_parameter(param: &str, bindings: &Bindings) -> Result<usize, String> {
     }
 }
 
-fn invalid_arg(msg: impl Into<Arc<str>>) -> Result<FunctionEvaluationResult, RuntimeError> {
-    Ok((Severity::Error, Rationale::InvalidArgument(msg.into())).into())
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::lang::builder::Builder;
+    use crate::runtime::rationale::Rationale;
     use crate::runtime::sources::Ephemeral;
     use crate::runtime::EvalContext;
     use crate::{assert_not_satisfied, assert_satisfied};
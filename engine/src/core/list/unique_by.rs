@@ -0,0 +1,131 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("unique-by.adoc");
+const FIELD: &str = "field";
+
+#[derive(Debug)]
+pub struct UniqueBy;
+
+impl Function for UniqueBy {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![FIELD.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(field)) = bindings
+                .get(FIELD)
+                .and_then(|field| field.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("field must be a string literal"));
+            };
+
+            let Some(list) = input.try_get_list() else {
+                return Ok((Severity::Error, Rationale::NotAList).into());
+            };
+
+            // values are keyed by their canonical form, as runtime values can't be hashed
+            let mut seen = HashMap::new();
+            for (idx, item) in list.iter().enumerate() {
+                let Some(value) = item.try_get_object().and_then(|o| o.get(&field)) else {
+                    continue;
+                };
+                let key = value.as_canonical_json();
+                if let Some(first) = seen.get(&key) {
+                    let msg =
+                        format!("duplicate {field} {key}, found at elements {first} and {idx}");
+                    return Ok(unsatisfied(msg));
+                }
+                seen.insert(key, idx);
+            }
+
+            Ok(Output::Identity.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn unique() {
+        let result = test_pattern(
+            r#"list::unique-by<"name">"#,
+            json!([
+                {"name": "serde", "version": "1.0.0"},
+                {"name": "tokio", "version": "1.0.0"},
+                {"version": "2.0.0"},
+                "not an object",
+            ]),
+        )
+        .await;
+        assert_satisfied!(result);
+
+        assert_satisfied!(test_pattern(r#"list::unique-by<"name">"#, json!([])).await);
+    }
+
+    #[tokio::test]
+    async fn duplicates() {
+        let result = test_pattern(
+            r#"list::unique-by<"name">"#,
+            json!([
+                {"name": "serde", "version": "1.0.0"},
+                {"name": "tokio", "version": "1.0.0"},
+                {"name": "serde", "version": "1.0.1"},
+            ]),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"duplicate name "serde", found at elements 0 and 2"#
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_objects() {
+        let result = test_pattern(
+            r#"list::unique-by<"purl">"#,
+            json!([
+                {"purl": {"type": "cargo", "name": "serde"}},
+                {"purl": {"name": "serde", "type": "cargo"}},
+            ]),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"duplicate purl {"name":"serde","type":"cargo"}, found at elements 0 and 1"#
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_list() {
+        assert_not_satisfied!(test_pattern(r#"list::unique-by<"name">"#, json!("foo")).await);
+    }
+}
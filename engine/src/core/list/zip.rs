@@ -0,0 +1,163 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::rationale::Rationale;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("zip.adoc");
+const FIRST: &str = "first";
+const SECOND: &str = "second";
+
+#[derive(Debug)]
+pub struct Zip;
+
+impl Function for Zip {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![FIRST.into(), SECOND.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let (Some(first), Some(second)) = (field(FIRST, bindings), field(SECOND, bindings))
+            else {
+                return Ok(invalid_arg("fields must be string literals"));
+            };
+
+            let Some(object) = input.try_get_object() else {
+                return Ok((Severity::Error, Rationale::NotAnObject).into());
+            };
+
+            let mut lists = Vec::with_capacity(2);
+            for name in [&first, &second] {
+                let Some(value) = object.get(name) else {
+                    return Ok((Severity::Error, Rationale::MissingField(name.clone())).into());
+                };
+                let Some(list) = value.try_get_list() else {
+                    return Ok(unsatisfied(format!(
+                        "field {name} is not a list, but {}",
+                        value.type_name()
+                    )));
+                };
+                lists.push(list.clone());
+            }
+
+            if lists[0].len() != lists[1].len() {
+                return Ok(unsatisfied(format!(
+                    "field {first} has {} elements, but field {second} has {}",
+                    lists[0].len(),
+                    lists[1].len()
+                )));
+            }
+
+            let pairs: Vec<RuntimeValue> = lists[0]
+                .iter()
+                .zip(lists[1].iter())
+                .map(|(a, b)| {
+                    Object::new()
+                        .with(first.clone(), (**a).clone())
+                        .with(second.clone(), (**b).clone())
+                        .into()
+                })
+                .collect();
+
+            Ok(Output::Transform(Arc::new(pairs.into())).into())
+        })
+    }
+}
+
+fn field(param: &str, bindings: &Bindings) -> Option<Arc<str>> {
+    match bindings.get(param).and_then(|p| p.try_get_resolved_value()) {
+        Some(ValuePattern::String(name)) => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn equal_length() {
+        let result = test_pattern(
+            r#"list::zip<"names", "hashes">"#,
+            json!({
+                "names": ["a", "b"],
+                "hashes": ["1", "2"],
+            }),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!([
+                {"names": "a", "hashes": "1"},
+                {"names": "b", "hashes": "2"},
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_pairs() {
+        let pattern = r#"
+            list::zip<"names", "hashes">(list::all<{ names: string, hashes: integer }>)
+        "#;
+
+        let result = test_pattern(pattern, json!({"names": ["a"], "hashes": [1]})).await;
+        assert_satisfied!(result);
+
+        let result = test_pattern(pattern, json!({"names": ["a"], "hashes": ["1"]})).await;
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn mismatched_length() {
+        let result = test_pattern(
+            r#"list::zip<"names", "hashes">"#,
+            json!({
+                "names": ["a", "b"],
+                "hashes": ["1"],
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "field names has 2 elements, but field hashes has 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_list() {
+        let result = test_pattern(
+            r#"list::zip<"names", "hashes">"#,
+            json!({
+                "names": ["a"],
+                "hashes": "1",
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(result);
+    }
+}
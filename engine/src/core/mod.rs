@@ -19,6 +19,8 @@ pub mod cyclonedx;
 pub mod data;
 #[cfg(feature = "debug")]
 pub mod debug;
+pub mod dsse;
+pub mod email;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod external;
 #[cfg(not(target_arch = "wasm32"))]
@@ -33,13 +35,16 @@ pub mod lang;
 pub mod list;
 pub mod maven;
 pub mod net;
+pub mod octets;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod openvex;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod osv;
 pub mod pem;
+pub mod purl;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod rhsa;
+pub mod sbom;
 pub mod semver;
 #[cfg(feature = "showcase")]
 pub mod showcase;
@@ -52,6 +57,45 @@ pub mod timestamp;
 pub mod uri;
 pub mod x509;
 
+/// Selection of the core packages added to a world.
+#[derive(Clone, Debug, Default)]
+pub enum CorePackages {
+    /// All core packages available with the enabled features.
+    #[default]
+    All,
+    /// Only the core packages with the given names.
+    Only(Vec<String>),
+    /// All core packages, except the ones with the given names.
+    Except(Vec<String>),
+}
+
+impl CorePackages {
+    pub fn only<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Only(names.into_iter().map(Into::into).collect())
+    }
+
+    pub fn except<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Except(names.into_iter().map(Into::into).collect())
+    }
+
+    /// Check if the package with the given name is selected.
+    pub fn includes(&self, name: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(names) => names.iter().any(|n| n == name),
+            Self::Except(names) => !names.iter().any(|n| n == name),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FunctionEvaluationResult {
     /// Severity reported by the function
@@ -130,6 +174,21 @@ impl From<(Severity, Arc<Rationale>)> for FunctionEvaluationResult {
     }
 }
 
+/// The result of a function called with an argument it can't work with, like a parameter
+/// of the wrong type.
+pub(crate) fn invalid_arg(msg: impl Into<Arc<str>>) -> FunctionEvaluationResult {
+    (Severity::Error, Rationale::InvalidArgument(msg.into())).into()
+}
+
+/// The result of a function whose input doesn't satisfy it, for the given reason.
+pub(crate) fn unsatisfied(reason: impl Into<Arc<str>>) -> FunctionEvaluationResult {
+    (
+        Severity::Error,
+        Rationale::Reason(reason.into(), Arc::new(Rationale::Nothing)),
+    )
+        .into()
+}
+
 #[derive(Debug)]
 pub enum FunctionInput {
     Anything,
@@ -188,6 +247,22 @@ pub trait Function: Sync + Send + Debug {
         Default::default()
     }
 
+    /// Whether the function always produces the same result for the same input and bindings.
+    ///
+    /// Functions reaching out to external resources, or depending on the current time, must
+    /// return `false`, which prevents patterns using them from being memoized.
+    fn deterministic(&self) -> bool {
+        true
+    }
+
+    /// Whether the function is an implementation detail of the engine, rather than one to write
+    /// policies with.
+    ///
+    /// Internal functions are left out of [`World::functions`].
+    fn internal(&self) -> bool {
+        false
+    }
+
     fn call<'v>(
         &'v self,
         input: Arc<RuntimeValue>,
@@ -223,6 +298,11 @@ pub trait BlockingFunction: Sync + Send + Debug {
         Default::default()
     }
 
+    /// See [`Function::deterministic`].
+    fn deterministic(&self) -> bool {
+        true
+    }
+
     fn call(
         &self,
         input: Arc<RuntimeValue>,
@@ -252,6 +332,10 @@ where
         BlockingFunction::parameters(self)
     }
 
+    fn deterministic(&self) -> bool {
+        BlockingFunction::deterministic(self)
+    }
+
     fn call<'v>(
         &'v self,
         input: Arc<RuntimeValue>,
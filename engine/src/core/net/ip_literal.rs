@@ -0,0 +1,72 @@
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::ExecutionContext;
+use crate::{
+    core::{unsatisfied, Function, FunctionEvaluationResult},
+    lang::lir::Bindings,
+    runtime::{Output, RuntimeError, World},
+    value::RuntimeValue,
+};
+use std::{future::Future, pin::Pin, sync::Arc};
+use std::{net::IpAddr, str::FromStr};
+
+const DOCUMENTATION: &str = include_str!("ip-literal.adoc");
+
+#[derive(Debug)]
+pub struct IpLiteral;
+
+impl Function for IpLiteral {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            // only parsing, never resolving
+            match IpAddr::from_str(value) {
+                Ok(_) => Ok(Output::Identity.into()),
+                Err(_) => Ok(unsatisfied(format!(
+                    "'{value}' is not an IP address literal"
+                ))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_ip() {
+        assert_satisfied!(test_pattern("net::ip-literal", json!("10.0.0.1")).await);
+        assert_satisfied!(test_pattern("net::ip-literal", json!("2001:db8::1")).await);
+    }
+
+    #[tokio::test]
+    async fn test_hostname() {
+        assert_not_satisfied!(test_pattern("net::ip-literal", json!("localhost")).await);
+        assert_not_satisfied!(test_pattern("net::ip-literal", json!("example.com")).await);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        assert_not_satisfied!(test_pattern("net::ip-literal", json!("10.0.0.256")).await);
+        assert_not_satisfied!(test_pattern("net::ip-literal", json!("[::1]")).await);
+        assert_not_satisfied!(test_pattern("net::ip-literal", json!(42)).await);
+    }
+}
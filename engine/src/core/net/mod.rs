@@ -2,9 +2,11 @@ use crate::package::Package;
 use crate::runtime::PackagePath;
 
 mod inet4addr;
+mod ip_literal;
 
 pub fn package() -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["net"]));
     pkg.register_function("inet4addr".into(), inet4addr::Inet4Addr);
+    pkg.register_function("ip-literal".into(), ip_literal::IpLiteral);
     pkg
 }
@@ -0,0 +1,108 @@
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use flate2::read::GzDecoder;
+use std::future::Future;
+use std::io::Read;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("gunzip.adoc");
+
+/// Maximum size of decompressed data (16 MiB), guarding against decompression bombs.
+pub const MAX_DECOMPRESSED_SIZE: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug)]
+pub struct Gunzip;
+
+impl Function for Gunzip {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(octets) = input.try_get_octets() else {
+                return Ok(Severity::Error.into());
+            };
+
+            match gunzip(octets, MAX_DECOMPRESSED_SIZE) {
+                Ok(decompressed) => Ok(Output::Transform(Arc::new(decompressed.into())).into()),
+                Err(msg) => Ok(unsatisfied(msg)),
+            }
+        })
+    }
+}
+
+/// Decompress gzip data, failing if it expands beyond `limit` octets.
+fn gunzip(compressed: &[u8], limit: u64) -> Result<Vec<u8>, String> {
+    let mut decompressed = Vec::new();
+    // read one more octet than allowed, to detect exceeding the limit
+    GzDecoder::new(compressed)
+        .take(limit + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| format!("input is not valid gzip data: {err}"))?;
+
+    if decompressed.len() as u64 > limit {
+        return Err(format!("decompressed data exceeds {limit} octets"));
+    }
+
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> RuntimeValue {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        RuntimeValue::Octets(encoder.finish().unwrap())
+    }
+
+    #[tokio::test]
+    async fn decompress() {
+        let result = test_pattern("octets::gunzip", gzip(b"hello world")).await;
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output(),
+            Arc::new(RuntimeValue::Octets(b"hello world".to_vec()))
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_input() {
+        let result = test_pattern("octets::gunzip", RuntimeValue::Octets(b"plain".to_vec())).await;
+        assert_not_satisfied!(&result);
+
+        assert_not_satisfied!(test_pattern("octets::gunzip", "not octets").await);
+    }
+
+    #[test]
+    fn reject_bomb() {
+        let RuntimeValue::Octets(compressed) = gzip(&[0; 4096]) else {
+            unreachable!()
+        };
+        assert_eq!(gunzip(&compressed, 4096).map(|d| d.len()), Ok(4096));
+        assert_eq!(
+            gunzip(&compressed, 1024),
+            Err("decompressed data exceeds 1024 octets".to_string())
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use base64::engine::{general_purpose::STANDARD, Engine as _};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("magic.adoc");
+const SIGNATURE: &str = "signature";
+
+const BASE64_PREFIX: &str = "base64:";
+
+#[derive(Debug)]
+pub struct Magic;
+
+impl Function for Magic {
+    fn parameters(&self) -> Vec<String> {
+        vec![SIGNATURE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let signature = match bindings
+                .get(SIGNATURE)
+                .and_then(|signature| signature.try_get_resolved_value())
+            {
+                Some(ValuePattern::String(signature)) => match decode(&signature) {
+                    Ok(signature) => signature,
+                    Err(msg) => return Ok(invalid_arg(msg)),
+                },
+                Some(ValuePattern::Octets(signature)) => signature,
+                _ => return Ok(invalid_arg("signature must be a string literal")),
+            };
+
+            let Some(octets) = input.try_get_octets() else {
+                return Ok(Severity::Error.into());
+            };
+
+            if octets.starts_with(&signature) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = format!(
+                    "input does not start with the signature {}",
+                    to_hex(&signature)
+                );
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+/// Decode a signature, hex encoded unless prefixed with `base64:`.
+///
+/// Hex encoded signatures may separate the octets by spaces or colons, like `89 50 4e 47`.
+fn decode(signature: &str) -> Result<Vec<u8>, String> {
+    if let Some(encoded) = signature.strip_prefix(BASE64_PREFIX) {
+        return STANDARD
+            .decode(encoded)
+            .map_err(|err| format!("signature is not valid base64: {err}"));
+    }
+
+    let digits: Vec<u8> = signature
+        .bytes()
+        .filter(|b| !matches!(b, b' ' | b':'))
+        .collect();
+    if digits.is_empty() || digits.len() % 2 != 0 {
+        return Err(format!("signature '{signature}' is not valid hex"));
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("signature '{signature}' is not valid hex"))
+        })
+        .collect()
+}
+
+fn to_hex(octets: &[u8]) -> String {
+    octets.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::value::RuntimeValue;
+    use crate::{assert_not_satisfied, assert_satisfied};
+
+    const PNG: &[u8] = &[
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+        0x52,
+    ];
+
+    fn octets(octets: &[u8]) -> RuntimeValue {
+        RuntimeValue::Octets(octets.to_vec())
+    }
+
+    #[tokio::test]
+    async fn png_signature() {
+        assert_satisfied!(test_pattern("octets::is-png", octets(PNG)).await);
+        assert_satisfied!(test_pattern(r#"octets::magic<"89 50 4E 47">"#, octets(PNG)).await);
+        assert_satisfied!(test_pattern(r#"octets::magic<"base64:iVBORw==">"#, octets(PNG)).await);
+    }
+
+    #[tokio::test]
+    async fn not_matching() {
+        let blob = octets(b"PK\x03\x04 not an image");
+
+        let result = test_pattern("octets::is-png", blob.clone()).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "input does not start with the signature 89504e470d0a1a0a"
+        );
+
+        assert_satisfied!(test_pattern("octets::is-zip", blob).await);
+        // shorter than the signature
+        assert_not_satisfied!(test_pattern("octets::is-png", octets(&PNG[..4])).await);
+        assert_not_satisfied!(test_pattern("octets::is-png", "\u{89}PNG").await);
+    }
+
+    #[tokio::test]
+    async fn invalid_signature() {
+        let result = test_pattern(r#"octets::magic<"89504">"#, octets(PNG)).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: signature '89504' is not valid hex"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::package::Package;
+use crate::runtime::PackagePath;
+
+mod gunzip;
+mod magic;
+
+pub fn package() -> Package {
+    let mut pkg = Package::new(PackagePath::from_parts(vec!["octets"]))
+        .with_documentation("Utilities for working with binary data");
+    pkg.register_source("".into(), include_str!("octets.dog"));
+    pkg.register_function("gunzip".into(), gunzip::Gunzip);
+    pkg.register_function("magic".into(), magic::Magic);
+    pkg
+}
@@ -23,6 +23,9 @@ impl Function for FromCsaf {
     fn order(&self) -> u8 {
         132
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
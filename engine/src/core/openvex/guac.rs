@@ -20,6 +20,9 @@ impl Function for FromGuac {
     fn order(&self) -> u8 {
         132
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
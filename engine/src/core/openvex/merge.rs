@@ -19,6 +19,9 @@ impl Function for Merge {
     fn order(&self) -> u8 {
         9
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
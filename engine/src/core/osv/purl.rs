@@ -116,6 +116,9 @@ impl Function for ScanPurl {
         // Reaching out to the network
         200
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
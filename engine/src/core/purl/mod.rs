@@ -0,0 +1,13 @@
+use crate::package::Package;
+use crate::runtime::PackagePath;
+
+mod package_type;
+mod parse;
+
+pub fn package() -> Package {
+    let mut pkg = Package::new(PackagePath::from_parts(vec!["purl"]))
+        .with_documentation("Functions for working with Package URLs (purl)".to_string());
+    pkg.register_function("parse".into(), parse::Parse);
+    pkg.register_function("type".into(), package_type::PackageType);
+    pkg
+}
@@ -0,0 +1,85 @@
+use crate::core::purl::parse::parse_purl;
+use crate::core::{unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("type.adoc");
+
+const VALUE: &str = "value";
+
+#[derive(Debug)]
+pub struct PackageType;
+
+impl Function for PackageType {
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUE.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(value) = bindings.get(VALUE) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let purl = match input.try_get_str().map(parse_purl) {
+                Some(Ok(purl)) => purl,
+                Some(Err(msg)) => return Ok(unsatisfied(msg)),
+                None => return Ok(unsatisfied("input is not a String")),
+            };
+
+            let Some(package_type) = purl.get("type") else {
+                return Ok(Severity::Error.into());
+            };
+
+            let result = value
+                .evaluate(package_type, ctx.push()?, &Default::default(), world)
+                .await?;
+            Ok((result.severity(), vec![result]).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+
+    #[tokio::test]
+    async fn type_matching() {
+        let result = test_pattern(r#"purl::type<"npm">"#, "pkg:npm/%40angular/core@13.0.0").await;
+
+        assert_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn type_not_matching() {
+        let result = test_pattern(r#"purl::type<"maven">"#, "pkg:npm/%40angular/core@13.0.0").await;
+
+        assert_not_satisfied!(result);
+    }
+
+    #[tokio::test]
+    async fn type_malformed() {
+        let result = test_pattern(r#"purl::type<"npm">"#, "npm/%40angular/core@13.0.0").await;
+
+        assert_not_satisfied!(result);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::core::{unsatisfied, BlockingFunction, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("parse.adoc");
+
+#[derive(Debug)]
+pub struct Parse;
+
+impl BlockingFunction for Parse {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call(
+        &self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'_>,
+        _bindings: &Bindings,
+        _world: &World,
+    ) -> Result<FunctionEvaluationResult, RuntimeError> {
+        match input.try_get_str().map(parse_purl) {
+            Some(Ok(purl)) => Ok(Output::Transform(Arc::new(purl.into())).into()),
+            Some(Err(msg)) => Ok(unsatisfied(msg)),
+            None => Ok(unsatisfied("input is not a String")),
+        }
+    }
+}
+
+/// Parse a purl string into an object of its components.
+pub(crate) fn parse_purl(purl: &str) -> Result<Object, String> {
+    let remainder = purl
+        .strip_prefix("pkg:")
+        .ok_or_else(|| format!("purl must start with 'pkg:': {purl}"))?
+        .trim_start_matches('/');
+
+    let mut result = Object::new();
+
+    let (remainder, subpath) = match remainder.split_once('#') {
+        Some((remainder, subpath)) => (remainder, Some(subpath)),
+        None => (remainder, None),
+    };
+    let (remainder, qualifiers) = match remainder.split_once('?') {
+        Some((remainder, qualifiers)) => (remainder, Some(qualifiers)),
+        None => (remainder, None),
+    };
+    let (remainder, version) = match remainder.rsplit_once('@') {
+        Some((remainder, version)) if !remainder.is_empty() && !version.contains('/') => {
+            (remainder, Some(version))
+        }
+        _ => (remainder, None),
+    };
+
+    let segments = remainder
+        .trim_end_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    let (r#type, namespace, name) = match segments.as_slice() {
+        [r#type, namespace @ .., name] if !r#type.is_empty() && !name.is_empty() => {
+            (r#type, namespace, name)
+        }
+        _ => return Err(format!("purl is missing a type or name: {purl}")),
+    };
+
+    result.set("type", r#type.to_lowercase());
+    if !namespace.is_empty() {
+        let namespace = namespace
+            .iter()
+            .map(|segment| percent_decode(segment))
+            .collect::<Result<Vec<_>, _>>()?;
+        result.set("namespace", namespace.join("/"));
+    }
+    result.set("name", percent_decode(name)?);
+    if let Some(version) = version {
+        result.set("version", percent_decode(version)?);
+    }
+    if let Some(qualifiers) = qualifiers {
+        let mut values = Object::new();
+        for qualifier in qualifiers.split('&').filter(|q| !q.is_empty()) {
+            let (key, value) = qualifier
+                .split_once('=')
+                .ok_or_else(|| format!("invalid purl qualifier: {qualifier}"))?;
+            values.set(key.to_lowercase(), percent_decode(value)?);
+        }
+        result.set("qualifiers", values);
+    }
+    if let Some(subpath) = subpath {
+        result.set("subpath", percent_decode(subpath.trim_matches('/'))?);
+    }
+
+    Ok(result)
+}
+
+fn percent_decode(segment: &str) -> Result<String, String> {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let byte = segment
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| format!("invalid percent encoding: {segment}"))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| format!("invalid percent encoding: {segment}"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn parse_encoded_namespace() {
+        let result = test_pattern(r#"purl::parse"#, "pkg:npm/%40angular/core@13.0.0").await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({
+                "type": "npm",
+                "namespace": "@angular",
+                "name": "core",
+                "version": "13.0.0",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_qualifiers_and_subpath() {
+        let result = test_pattern(
+            r#"purl::parse"#,
+            "pkg:rpm/fedora/curl@7.50.3-1.fc25?arch=i386&distro=fedora-25#docs/man",
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({
+                "type": "rpm",
+                "namespace": "fedora",
+                "name": "curl",
+                "version": "7.50.3-1.fc25",
+                "qualifiers": {
+                    "arch": "i386",
+                    "distro": "fedora-25",
+                },
+                "subpath": "docs/man",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_malformed() {
+        let result = test_pattern(r#"purl::parse"#, "npm/%40angular/core@13.0.0").await;
+        assert_not_satisfied!(result);
+
+        let result = test_pattern(r#"purl::parse"#, "pkg:npm").await;
+        assert_not_satisfied!(result);
+
+        let result = test_pattern(r#"purl::parse"#, "pkg:npm/%4").await;
+        assert_not_satisfied!(result);
+    }
+}
@@ -22,6 +22,9 @@ impl Function for FindAdvisory {
     fn order(&self) -> u8 {
         132
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
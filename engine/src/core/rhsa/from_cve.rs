@@ -21,6 +21,10 @@ impl Function for FromCve {
         132
     }
 
+    fn deterministic(&self) -> bool {
+        false
+    }
+
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
@@ -0,0 +1,231 @@
+use crate::core::{Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("components.adoc");
+
+#[derive(Debug)]
+pub struct Components;
+
+impl Function for Components {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let components = input.try_get_object().and_then(|sbom| {
+                if is_cyclonedx(sbom) {
+                    let mut components = Vec::new();
+                    cyclonedx_components(sbom, &mut components);
+                    Some(components)
+                } else if is_spdx(sbom) {
+                    Some(spdx_components(sbom))
+                } else {
+                    None
+                }
+            });
+
+            match components {
+                Some(components) => {
+                    Ok(Output::Transform(Arc::new(RuntimeValue::List(components))).into())
+                }
+                None => Ok(Severity::Error.into()),
+            }
+        })
+    }
+}
+
+fn is_cyclonedx(sbom: &Object) -> bool {
+    matches!(
+        sbom.get("bomFormat")
+            .as_deref()
+            .and_then(RuntimeValue::try_get_str),
+        Some("CycloneDX")
+    )
+}
+
+fn is_spdx(sbom: &Object) -> bool {
+    matches!(
+        sbom.get("spdxVersion").as_deref().and_then(RuntimeValue::try_get_str),
+        Some(version) if version.starts_with("SPDX-")
+    )
+}
+
+/// Collect the components of a CycloneDX BOM, including nested components.
+fn cyclonedx_components(parent: &Object, components: &mut Vec<Arc<RuntimeValue>>) {
+    if let Some(list) = parent.get("components") {
+        for component in list.try_get_list().into_iter().flatten() {
+            if let Some(component) = component.try_get_object() {
+                components.push(normalize(
+                    field(component, "name"),
+                    field(component, "version"),
+                    field(component, "purl"),
+                ));
+                cyclonedx_components(component, components);
+            }
+        }
+    }
+}
+
+fn spdx_components(sbom: &Object) -> Vec<Arc<RuntimeValue>> {
+    let mut components = Vec::new();
+    if let Some(list) = sbom.get("packages") {
+        for package in list.try_get_list().into_iter().flatten() {
+            if let Some(package) = package.try_get_object() {
+                components.push(normalize(
+                    field(package, "name"),
+                    field(package, "versionInfo"),
+                    spdx_purl(package),
+                ));
+            }
+        }
+    }
+    components
+}
+
+fn spdx_purl(package: &Object) -> RuntimeValue {
+    if let Some(refs) = package.get("externalRefs") {
+        for external in refs.try_get_list().into_iter().flatten() {
+            if let Some(external) = external.try_get_object() {
+                if let (RuntimeValue::String(ty), locator) = (
+                    field(external, "referenceType"),
+                    field(external, "referenceLocator"),
+                ) {
+                    if ty.as_ref() == "purl" {
+                        return locator;
+                    }
+                }
+            }
+        }
+    }
+    RuntimeValue::Null
+}
+
+fn field(object: &Object, name: &str) -> RuntimeValue {
+    object
+        .get(name)
+        .map(|value| (*value).clone())
+        .unwrap_or(RuntimeValue::Null)
+}
+
+fn normalize(name: RuntimeValue, version: RuntimeValue, purl: RuntimeValue) -> Arc<RuntimeValue> {
+    Arc::new(
+        Object::new()
+            .with("name", name)
+            .with("version", version)
+            .with("purl", purl)
+            .into(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    fn expected() -> serde_json::Value {
+        json!([
+            {
+                "name": "log4j-core",
+                "version": "2.17.1",
+                "purl": "pkg:maven/org.apache.logging.log4j/log4j-core@2.17.1",
+            },
+            {
+                "name": "left-pad",
+                "version": "1.3.0",
+                "purl": null,
+            },
+        ])
+    }
+
+    #[tokio::test]
+    async fn cyclonedx_components() {
+        let result = test_pattern(
+            "sbom::components",
+            json!({
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.4",
+                "components": [
+                    {
+                        "type": "library",
+                        "name": "log4j-core",
+                        "version": "2.17.1",
+                        "purl": "pkg:maven/org.apache.logging.log4j/log4j-core@2.17.1",
+                    },
+                    {
+                        "type": "library",
+                        "name": "left-pad",
+                        "version": "1.3.0",
+                    },
+                ]
+            }),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), expected());
+    }
+
+    #[tokio::test]
+    async fn spdx_components() {
+        let result = test_pattern(
+            "sbom::components",
+            json!({
+                "spdxVersion": "SPDX-2.3",
+                "SPDXID": "SPDXRef-DOCUMENT",
+                "packages": [
+                    {
+                        "SPDXID": "SPDXRef-log4j",
+                        "name": "log4j-core",
+                        "versionInfo": "2.17.1",
+                        "externalRefs": [
+                            {
+                                "referenceCategory": "PACKAGE-MANAGER",
+                                "referenceType": "purl",
+                                "referenceLocator": "pkg:maven/org.apache.logging.log4j/log4j-core@2.17.1",
+                            }
+                        ]
+                    },
+                    {
+                        "SPDXID": "SPDXRef-left-pad",
+                        "name": "left-pad",
+                        "versionInfo": "1.3.0",
+                    },
+                ]
+            }),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), expected());
+    }
+
+    #[tokio::test]
+    async fn unknown_format() {
+        let result = test_pattern(
+            "sbom::components",
+            json!({
+                "components": [],
+            }),
+        )
+        .await;
+
+        assert_not_satisfied!(result);
+    }
+}
@@ -0,0 +1,11 @@
+use crate::package::Package;
+use crate::runtime::PackagePath;
+
+mod components;
+
+pub fn package() -> Package {
+    let mut pkg = Package::new(PackagePath::from_parts(vec!["sbom"]))
+        .with_documentation("Format agnostic functions for working with SBOMs".to_string());
+    pkg.register_function("components".into(), components::Components);
+    pkg
+}
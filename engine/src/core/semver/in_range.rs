@@ -0,0 +1,202 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult, FunctionInput};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, Pattern, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use semver::{Version, VersionReq};
+
+const DOCUMENTATION: &str = include_str!("in-range.adoc");
+const REQUIREMENT: &str = "requirement";
+
+#[derive(Debug)]
+pub struct InRange;
+
+impl Function for InRange {
+    fn input(&self, _bindings: &[Arc<Pattern>]) -> FunctionInput {
+        FunctionInput::String
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![REQUIREMENT.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(requirement)) = bindings
+                .get(REQUIREMENT)
+                .and_then(|requirement| requirement.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("requirement must be a string literal"));
+            };
+
+            let requirement = match VersionReq::parse(&requirement) {
+                Ok(requirement) => requirement,
+                Err(err) => {
+                    return Ok(invalid_arg(format!(
+                        "invalid version requirement {requirement:?}: {err}"
+                    )));
+                }
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            let Ok(version) = Version::parse(value) else {
+                return Ok(unsatisfied(format!("{value:?} is not a semantic version")));
+            };
+
+            // pre-releases only match comparators of the same version, having a pre-release too
+            if requirement.matches(&version) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = format!("version {version} does not match {requirement}");
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::{test_pattern, test_patterns};
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn caret() {
+        let pattern = r#"semver::in-range<"^1.2.3">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3")).await);
+        assert_satisfied!(test_pattern(pattern, json!("1.9.0")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.2")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("2.0.0")).await);
+
+        // zero major versions are only compatible within the minor version
+        let pattern = r#"semver::in-range<"^0.2.3">"#;
+        assert_satisfied!(test_pattern(pattern, json!("0.2.9")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("0.3.0")).await);
+    }
+
+    #[tokio::test]
+    async fn tilde() {
+        let pattern = r#"semver::in-range<"~1.2.3">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3")).await);
+        assert_satisfied!(test_pattern(pattern, json!("1.2.9")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.3.0")).await);
+    }
+
+    #[tokio::test]
+    async fn exact() {
+        let pattern = r#"semver::in-range<"=1.2.3">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3")).await);
+        // build metadata is ignored
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3+20230401")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.4")).await);
+
+        let result = test_pattern(pattern, json!("1.2.3-beta.1")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "version 1.2.3-beta.1 does not match =1.2.3"
+        );
+    }
+
+    #[tokio::test]
+    async fn pre_releases() {
+        // pre-releases are excluded from ranges
+        let pattern = r#"semver::in-range<"^1.2.3">"#;
+        assert_not_satisfied!(test_pattern(pattern, json!("1.3.0-alpha.1")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.3-alpha.1")).await);
+
+        let pattern = r#"semver::in-range<">=1.0.0, <2.0.0">"#;
+        assert_not_satisfied!(test_pattern(pattern, json!("1.5.0-rc.1")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("2.0.0-rc.1")).await);
+
+        // unless requested explicitly, for the same version
+        let pattern = r#"semver::in-range<"^1.2.3-beta.2">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3-beta.2")).await);
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3-rc.1")).await);
+        assert_satisfied!(test_pattern(pattern, json!("1.4.0")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.3-beta.1")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.4.0-beta.2")).await);
+
+        let pattern = r#"semver::in-range<"~1.2.3-beta.2">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3-beta.10")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.4-beta.2")).await);
+
+        let pattern = r#"semver::in-range<"=1.2.3-beta.2">"#;
+        assert_satisfied!(test_pattern(pattern, json!("1.2.3-beta.2")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.3-beta.3")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("1.2.3")).await);
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        let pattern = r#"semver::in-range<"^1.2.3">"#;
+        let result = test_pattern(pattern, json!("1.2")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#""1.2" is not a semantic version"#
+        );
+        assert_not_satisfied!(test_pattern(pattern, json!(123)).await);
+
+        let result = test_pattern(r#"semver::in-range<"not a range">"#, json!("1.2.3")).await;
+        assert_not_satisfied!(&result);
+        assert!(result
+            .rationale()
+            .reason()
+            .starts_with(r#"invalid argument: invalid version requirement "not a range""#));
+    }
+
+    #[tokio::test]
+    async fn dependencies() {
+        let result = test_patterns(
+            r#"
+pattern denied = {
+    name: "log4j-core",
+    version: semver::in-range<">=2.0.0, <2.17.1">,
+}
+
+pattern test-pattern = list::none<denied>
+"#,
+            json!([
+                {"name": "log4j-core", "version": "2.17.1"},
+                {"name": "serde", "version": "2.0.0"},
+            ]),
+        )
+        .await;
+        assert_satisfied!(result);
+
+        let result = test_patterns(
+            r#"
+pattern denied = {
+    name: "log4j-core",
+    version: semver::in-range<">=2.0.0, <2.17.1">,
+}
+
+pattern test-pattern = list::none<denied>
+"#,
+            json!([{"name": "log4j-core", "version": "2.14.0"}]),
+        )
+        .await;
+        assert_not_satisfied!(result);
+    }
+}
@@ -1,5 +1,7 @@
+mod in_range;
 mod parse;
 
+use crate::core::semver::in_range::InRange;
 use crate::core::semver::parse::SemverParse;
 use crate::package::Package;
 use crate::runtime::PackagePath;
@@ -8,5 +10,6 @@ pub fn package() -> Package {
     let mut pkg = Package::new(PackagePath::from_parts(vec!["semver"]));
     pkg.register_source("".into(), include_str!("semver.dog"));
     pkg.register_function("parse".into(), SemverParse);
+    pkg.register_function("in-range".into(), InRange);
     pkg
 }
@@ -36,6 +36,9 @@ impl Function for SHA256 {
         // Reaching out to the network
         200
     }
+    fn deterministic(&self) -> bool {
+        false
+    }
     fn metadata(&self) -> PatternMeta {
         PatternMeta {
             documentation: DOCUMENTATION.into(),
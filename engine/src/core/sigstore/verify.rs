@@ -1,8 +1,7 @@
-use crate::core::{Function, FunctionEvaluationResult};
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
 use crate::lang::lir::{Bindings, InnerPattern};
 use crate::lang::PatternMeta;
 use crate::lang::{Severity, ValuePattern};
-use crate::runtime::rationale::Rationale;
 use crate::runtime::{ExecutionContext, World};
 use crate::runtime::{Output, RuntimeError};
 use crate::value::RuntimeValue;
@@ -27,6 +26,10 @@ impl Function for VerifyBlob {
         200
     }
 
+    fn deterministic(&self) -> bool {
+        false
+    }
+
     fn parameters(&self) -> Vec<String> {
         vec![CERTIFICATE.into(), SIGNATURE.into()]
     }
@@ -50,13 +53,13 @@ impl Function for VerifyBlob {
                 let cert = match get_parameter(CERTIFICATE, bindings) {
                     Ok(value) => value,
                     Err(msg) => {
-                        return invalid_arg(msg);
+                        return Ok(invalid_arg(msg));
                     }
                 };
                 let sig = match get_parameter(SIGNATURE, bindings) {
                     Ok(value) => value,
                     Err(msg) => {
-                        return invalid_arg(msg);
+                        return Ok(invalid_arg(msg));
                     }
                 };
 
@@ -103,10 +106,6 @@ fn get_parameter(param: &str, bindings: &Bindings) -> Result<String, String> {
     }
 }
 
-fn invalid_arg(msg: impl Into<Arc<str>>) -> Result<FunctionEvaluationResult, RuntimeError> {
-    Ok((Severity::Error, Rationale::InvalidArgument(msg.into())).into())
-}
-
 #[cfg(test)]
 mod test {
 
@@ -0,0 +1,144 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult, FunctionInput};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, Pattern, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const PREFIX_DOCUMENTATION: &str = include_str!("starts-with.adoc");
+const SUFFIX_DOCUMENTATION: &str = include_str!("ends-with.adoc");
+const PREFIX: &str = "prefix";
+const SUFFIX: &str = "suffix";
+
+#[derive(Debug)]
+pub enum Affix {
+    Prefix,
+    Suffix,
+}
+
+impl Affix {
+    fn parameter(&self) -> &'static str {
+        match self {
+            Self::Prefix => PREFIX,
+            Self::Suffix => SUFFIX,
+        }
+    }
+
+    fn matches(&self, value: &str, affix: &str) -> bool {
+        // both are valid UTF-8, so a match always ends on a character boundary
+        match self {
+            Self::Prefix => value.starts_with(affix),
+            Self::Suffix => value.ends_with(affix),
+        }
+    }
+}
+
+impl Function for Affix {
+    fn input(&self, _bindings: &[Arc<Pattern>]) -> FunctionInput {
+        FunctionInput::String
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        let documentation = match self {
+            Self::Prefix => PREFIX_DOCUMENTATION,
+            Self::Suffix => SUFFIX_DOCUMENTATION,
+        };
+        PatternMeta {
+            documentation: documentation.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![self.parameter().into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let parameter = self.parameter();
+            let Some(ValuePattern::String(affix)) = bindings
+                .get(parameter)
+                .and_then(|affix| affix.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg(format!("{parameter} must be a string literal")));
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            if self.matches(value, &affix) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = match self {
+                    Self::Prefix => format!("expected {value:?} to start with {affix:?}"),
+                    Self::Suffix => format!("expected {value:?} to end with {affix:?}"),
+                };
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn starts_with() {
+        let pattern = r#"string::starts-with<"quay.io/">"#;
+        assert_satisfied!(test_pattern(pattern, json!("quay.io/seedwing/swio")).await);
+
+        let result = test_pattern(pattern, json!("docker.io/seedwing/swio")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"expected "docker.io/seedwing/swio" to start with "quay.io/""#
+        );
+    }
+
+    #[tokio::test]
+    async fn ends_with() {
+        let pattern = r#"string::ends-with<".redhat.com">"#;
+        assert_satisfied!(test_pattern(pattern, json!("registry.redhat.com")).await);
+
+        let result = test_pattern(pattern, json!("registry.redhat.io")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"expected "registry.redhat.io" to end with ".redhat.com""#
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_affix() {
+        assert_satisfied!(test_pattern(r#"string::starts-with<"">"#, json!("anything")).await);
+        assert_satisfied!(test_pattern(r#"string::ends-with<"">"#, json!("anything")).await);
+        assert_satisfied!(test_pattern(r#"string::starts-with<"">"#, json!("")).await);
+    }
+
+    #[tokio::test]
+    async fn non_ascii() {
+        assert_satisfied!(test_pattern(r#"string::starts-with<"été">"#, json!("été 2023")).await);
+        assert_satisfied!(test_pattern(r#"string::ends-with<"ß">"#, json!("Straße")).await);
+        assert_satisfied!(test_pattern(r#"string::ends-with<"🦀">"#, json!("rust 🦀")).await);
+        // "e" is not a prefix of "é", even though it is the base of the character
+        assert_not_satisfied!(test_pattern(r#"string::starts-with<"e">"#, json!("été")).await);
+        assert_not_satisfied!(test_pattern(r#"string::ends-with<"ss">"#, json!("Straße")).await);
+    }
+
+    #[tokio::test]
+    async fn not_a_string() {
+        assert_not_satisfied!(test_pattern(r#"string::starts-with<"4">"#, json!(42)).await);
+    }
+}
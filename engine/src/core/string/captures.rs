@@ -0,0 +1,137 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::{Object, RuntimeValue};
+use regex::Regex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("captures.adoc");
+const REGEXP: &str = "regexp";
+
+#[derive(Debug)]
+pub struct Captures;
+
+impl Function for Captures {
+    fn order(&self) -> u8 {
+        10
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![REGEXP.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(regexp)) = bindings
+                .get(REGEXP)
+                .and_then(|regexp| regexp.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("regexp must be a string literal"));
+            };
+            let regexp = match Regex::new(&regexp) {
+                Ok(regexp) => regexp,
+                Err(err) => {
+                    let msg = format!("invalid regular expression: {err}");
+                    return Ok(invalid_arg(msg));
+                }
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+            let Some(captures) = regexp.captures(value) else {
+                return Ok(Severity::Error.into());
+            };
+
+            let mut result = Object::new();
+            for (idx, name) in regexp.capture_names().enumerate() {
+                if let Some(group) = captures.get(idx) {
+                    result.set(idx.to_string(), group.as_str());
+                    if let Some(name) = name {
+                        result.set(name, group.as_str());
+                    }
+                }
+            }
+
+            Ok(Output::Transform(Arc::new(result.into())).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const IMAGE: &str = r#"string::captures<"^(?P<name>[^:]+):(?P<tag>.+)$">"#;
+
+    #[tokio::test]
+    async fn captures() {
+        let result = test_pattern(IMAGE, json!("quay.io/app:1.0")).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().as_json(),
+            json!({
+                "0": "quay.io/app:1.0",
+                "1": "quay.io/app",
+                "name": "quay.io/app",
+                "2": "1.0",
+                "tag": "1.0",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn no_match() {
+        assert_not_satisfied!(test_pattern(IMAGE, json!("quay.io/app")).await);
+        assert_not_satisfied!(test_pattern(IMAGE, json!(42)).await);
+    }
+
+    #[tokio::test]
+    async fn optional_group() {
+        let result = test_pattern(r#"string::captures<"^a(b)?(c)$">"#, json!("ac")).await;
+
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"0": "ac", "2": "c"}));
+    }
+
+    #[tokio::test]
+    async fn object_field() {
+        let result = test_pattern(
+            &format!("{{ image: {IMAGE}, replicas: integer }}"),
+            json!({"image": "quay.io/app:1.0", "replicas": 2}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        let output = result.output().as_json();
+        assert_eq!(output["image"]["tag"], json!("1.0"));
+        assert_eq!(output["replicas"], json!(2));
+    }
+
+    #[tokio::test]
+    async fn refine_object_field() {
+        let pattern = format!(r#"{{ image: {IMAGE}({{ tag: "latest" }}) }}"#);
+
+        assert_satisfied!(test_pattern(&pattern, json!({"image": "quay.io/app:latest"})).await);
+        assert_not_satisfied!(test_pattern(&pattern, json!({"image": "quay.io/app:1.0"})).await);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::{Bindings, ValuePattern};
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("iequals.adoc");
+const VALUE: &str = "value";
+
+#[derive(Debug)]
+pub struct IEquals;
+
+impl Function for IEquals {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![VALUE.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let Some(ValuePattern::String(expected)) = bindings
+                .get(VALUE)
+                .and_then(|value| value.try_get_resolved_value())
+            else {
+                return Ok(invalid_arg("value must be a string literal"));
+            };
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            if fold_case(value) == fold_case(&expected) {
+                Ok(Output::Identity.into())
+            } else {
+                let msg = format!("expected {expected:?} (ignoring case), found {value:?}");
+                Ok(unsatisfied(msg))
+            }
+        })
+    }
+}
+
+/// Approximate Unicode full case folding.
+///
+/// Upper casing first expands characters like `ß` into `SS`, and maps all variants of a letter
+/// (like the Greek final sigma) onto one, which lower casing then normalizes.
+pub(crate) fn fold_case(value: &str) -> String {
+    value.to_uppercase().to_lowercase()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn ascii() {
+        let pattern = r#"string::iequals<"Content-Type">"#;
+        assert_satisfied!(test_pattern(pattern, json!("content-type")).await);
+        assert_satisfied!(test_pattern(pattern, json!("CONTENT-TYPE")).await);
+        assert_not_satisfied!(test_pattern(pattern, json!("content-length")).await);
+    }
+
+    #[tokio::test]
+    async fn non_ascii() {
+        assert_satisfied!(test_pattern(r#"string::iequals<"ÉCOLE">"#, json!("école")).await);
+        assert_satisfied!(test_pattern(r#"string::iequals<"Straße">"#, json!("STRASSE")).await);
+        assert_satisfied!(test_pattern(r#"string::iequals<"ΣΟΦΟΣ">"#, json!("σοφοσ")).await);
+        // final sigma
+        assert_satisfied!(test_pattern(r#"string::iequals<"ΣΟΦΟΣ">"#, json!("σοφος")).await);
+        assert_not_satisfied!(test_pattern(r#"string::iequals<"Straße">"#, json!("STRASE")).await);
+    }
+
+    #[tokio::test]
+    async fn reports_expected_value() {
+        let result = test_pattern(r#"string::iequals<"admin">"#, json!("root")).await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            r#"expected "admin" (ignoring case), found "root""#
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_string() {
+        assert_not_satisfied!(test_pattern(r#"string::iequals<"42">"#, json!(42)).await);
+    }
+}
@@ -30,7 +30,7 @@ impl Function for Length {
     ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
         Box::pin(async move {
             if let Some(value) = input.try_get_str() {
-                Ok(Output::Transform(Arc::new(value.len().into())).into())
+                Ok(Output::Transform(Arc::new(value.chars().count().into())).into())
             } else {
                 Ok(Severity::Error.into())
             }
@@ -116,6 +116,28 @@ mod test {
         assert_not_satisfied!(result.unwrap());
     }
 
+    #[tokio::test]
+    async fn call_counting_characters() {
+        let src = Ephemeral::new(
+            "test",
+            r#"
+            pattern three = string::length( $(self == 3) )
+        "#,
+        );
+
+        let mut builder = Builder::new();
+
+        let _result = builder.build(src.iter());
+
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate("test::three", json!("äöü"), EvalContext::default())
+            .await;
+
+        assert_satisfied!(result.unwrap());
+    }
+
     #[tokio::test]
     async fn call_non_matching_not_a_string() {
         let src = Ephemeral::new(
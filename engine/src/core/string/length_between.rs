@@ -0,0 +1,126 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity, ValuePattern};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("length-between.adoc");
+
+const MIN: &str = "min";
+const MAX: &str = "max";
+
+#[derive(Debug)]
+pub struct LengthBetween;
+
+impl Function for LengthBetween {
+    fn parameters(&self) -> Vec<String> {
+        vec![MIN.into(), MAX.into()]
+    }
+
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let (min, max) = match (bound(MIN, bindings), bound(MAX, bindings)) {
+                (Ok(min), Ok(max)) => (min, max),
+                (Err(msg), _) | (_, Err(msg)) => return Ok(invalid_arg(msg)),
+            };
+            if min > max {
+                return Ok(invalid_arg("min cannot be greater than max"));
+            }
+
+            let Some(value) = input.try_get_str() else {
+                return Ok(Severity::Error.into());
+            };
+
+            // count characters, the same way string::length does
+            let length = value.chars().count();
+            if (min..=max).contains(&length) {
+                Ok(Output::Identity.into())
+            } else {
+                Ok(unsatisfied(format!(
+                    "length {length} is not between {min} and {max}"
+                )))
+            }
+        })
+    }
+}
+
+fn bound(param: &str, bindings: &Bindings) -> Result<usize, String> {
+    match bindings.get(param).and_then(|p| p.try_get_resolved_value()) {
+        Some(ValuePattern::Integer(value)) if value >= 0 => Ok(value as usize),
+        _ => Err(format!("{param} must be a non-negative integer")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runtime::testutil::test_pattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn call_at_boundaries() {
+        assert_satisfied!(test_pattern("string::length-between<2, 4>", "ab").await);
+        assert_satisfied!(test_pattern("string::length-between<2, 4>", "abcd").await);
+    }
+
+    #[tokio::test]
+    async fn call_outside_boundaries() {
+        let result = test_pattern("string::length-between<2, 4>", "a").await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "length 1 is not between 2 and 4"
+        );
+
+        let result = test_pattern("string::length-between<2, 4>", "abcde").await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "length 5 is not between 2 and 4"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_multi_byte() {
+        // four characters, but twelve bytes
+        assert_satisfied!(test_pattern("string::length-between<2, 4>", "日本語だ").await);
+
+        let result = test_pattern("string::length-between<2, 4>", "🦀🦀🦀🦀🦀").await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "length 5 is not between 2 and 4"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_invalid_range() {
+        let result = test_pattern("string::length-between<4, 2>", "abc").await;
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: min cannot be greater than max"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_not_a_string() {
+        assert_not_satisfied!(test_pattern("string::length-between<0, 4>", json!(42)).await);
+    }
+}
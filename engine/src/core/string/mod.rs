@@ -1,14 +1,24 @@
+mod affix;
+mod captures;
 mod concat;
 mod contains;
+pub(crate) mod iequals;
 mod length;
+mod length_between;
 mod regexp;
 mod split;
+mod to_yaml;
 
+use crate::core::string::affix::Affix;
+use crate::core::string::captures::Captures;
 use crate::core::string::concat::Concat;
 use crate::core::string::contains::Contains;
+use crate::core::string::iequals::IEquals;
 use crate::core::string::length::Length;
+use crate::core::string::length_between::LengthBetween;
 use crate::core::string::regexp::Regexp;
 use crate::core::string::split::Split;
+use crate::core::string::to_yaml::ToYaml;
 
 use crate::package::Package;
 use crate::runtime::PackagePath;
@@ -18,10 +28,16 @@ pub fn package() -> Package {
         .with_documentation("Utilities for working with strings");
     pkg.register_function("length".into(), Length);
     pkg.register_function("count".into(), Length);
+    pkg.register_function("length-between".into(), LengthBetween);
     pkg.register_function("regexp".into(), Regexp);
+    pkg.register_function("captures".into(), Captures);
     pkg.register_function("prepend".into(), Concat::Prepend);
     pkg.register_function("append".into(), Concat::Append);
     pkg.register_function("contains".into(), Contains);
+    pkg.register_function("starts-with".into(), Affix::Prefix);
+    pkg.register_function("ends-with".into(), Affix::Suffix);
     pkg.register_function("split".into(), Split);
+    pkg.register_function("iequals".into(), IEquals);
+    pkg.register_function("to-yaml".into(), ToYaml);
     pkg
 }
@@ -0,0 +1,59 @@
+use crate::core::{invalid_arg, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::PatternMeta;
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("to-yaml.adoc");
+
+#[derive(Debug)]
+pub struct ToYaml;
+
+impl Function for ToYaml {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        _ctx: ExecutionContext<'v>,
+        _bindings: &'v Bindings,
+        _world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            match serde_yaml::to_string(&input.as_yaml()) {
+                Ok(yaml) => Ok(Output::Transform(Arc::new(yaml.into())).into()),
+                Err(err) => Ok(invalid_arg(format!("unable to encode as YAML: {err}"))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assert_satisfied;
+    use crate::runtime::testutil::test_pattern;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn object_to_yaml() {
+        let result = test_pattern(
+            "string::to-yaml",
+            json!({"name": "bob", "labels": ["a", "b"]}),
+        )
+        .await;
+
+        assert_satisfied!(&result);
+        assert_eq!(
+            result.output().try_get_str(),
+            Some("name: bob\nlabels:\n- a\n- b\n")
+        );
+    }
+}
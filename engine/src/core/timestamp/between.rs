@@ -0,0 +1,196 @@
+use crate::core::{invalid_arg, unsatisfied, Function, FunctionEvaluationResult};
+use crate::lang::lir::Bindings;
+use crate::lang::{PatternMeta, Severity};
+use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
+use crate::value::RuntimeValue;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const DOCUMENTATION: &str = include_str!("between.adoc");
+
+const START: &str = "start";
+const END: &str = "end";
+
+#[derive(Debug)]
+pub struct Between;
+
+impl Function for Between {
+    fn metadata(&self) -> PatternMeta {
+        PatternMeta {
+            documentation: DOCUMENTATION.into(),
+            ..Default::default()
+        }
+    }
+
+    fn parameters(&self) -> Vec<String> {
+        vec![START.into(), END.into()]
+    }
+
+    fn call<'v>(
+        &'v self,
+        input: Arc<RuntimeValue>,
+        ctx: ExecutionContext<'v>,
+        bindings: &'v Bindings,
+        world: &'v World,
+    ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>> {
+        Box::pin(async move {
+            let mut bounds = Vec::with_capacity(2);
+            for name in [START, END] {
+                let Some(bound) = bindings.get(name) else {
+                    return Ok(Severity::Error.into());
+                };
+                let bound = match bound.try_get_resolved_value() {
+                    Some(value) => RuntimeValue::from(&value),
+                    None => {
+                        let result = bound
+                            .evaluate(input.clone(), ctx.push()?, &Default::default(), world)
+                            .await?;
+                        if result.severity() >= Severity::Error {
+                            return Ok((Severity::Error, vec![result]).into());
+                        }
+                        (*result.output()).clone()
+                    }
+                };
+                match timestamp(&bound) {
+                    Some(bound) => bounds.push(bound),
+                    None => {
+                        let msg = format!("{name} must be a timestamp, but is {bound}");
+                        return Ok(invalid_arg(msg));
+                    }
+                }
+            }
+            let (start, end) = (bounds[0], bounds[1]);
+
+            if start > end {
+                let msg = format!(
+                    "start {} is after end {}",
+                    start.to_rfc3339(),
+                    end.to_rfc3339()
+                );
+                return Ok(invalid_arg(msg));
+            }
+
+            match timestamp(&input) {
+                Some(value) if start <= value && value <= end => Ok(Output::Identity.into()),
+                Some(value) => Ok(unsatisfied(format!(
+                    "{} is not between {} and {}",
+                    value.to_rfc3339(),
+                    start.to_rfc3339(),
+                    end.to_rfc3339()
+                ))),
+                None => Ok(unsatisfied("input is not a timestamp")),
+            }
+        })
+    }
+}
+
+fn timestamp(value: &RuntimeValue) -> Option<DateTime<Utc>> {
+    match value {
+        RuntimeValue::Timestamp(value) => Some(*value),
+        RuntimeValue::String(value) => DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|value| value.with_timezone(&Utc)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::testutil::test_pattern;
+    use crate::runtime::EvalContext;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const YEAR: &str = r#"timestamp::between<"2023-01-01T00:00:00Z", "2023-12-31T23:59:59Z">"#;
+
+    #[tokio::test]
+    async fn boundaries() {
+        assert_satisfied!(test_pattern(YEAR, json!("2023-01-01T00:00:00Z")).await);
+        assert_satisfied!(test_pattern(YEAR, json!("2023-12-31T23:59:59Z")).await);
+        // same instant, different offset
+        assert_satisfied!(test_pattern(YEAR, json!("2023-01-01T01:00:00+01:00")).await);
+        assert_satisfied!(test_pattern(YEAR, json!("2023-06-15T12:00:00Z")).await);
+
+        assert_not_satisfied!(test_pattern(YEAR, json!("2022-12-31T23:59:59Z")).await);
+        assert_not_satisfied!(test_pattern(YEAR, json!("2024-01-01T00:00:00Z")).await);
+        assert_not_satisfied!(test_pattern(YEAR, json!("yesterday")).await);
+    }
+
+    #[tokio::test]
+    async fn outside() {
+        let result = test_pattern(YEAR, json!("2024-01-01T00:00:00Z")).await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "2024-01-01T00:00:00+00:00 is not between 2023-01-01T00:00:00+00:00 and 2023-12-31T23:59:59+00:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn inverted_interval() {
+        let result = test_pattern(
+            r#"timestamp::between<"2023-12-31T00:00:00Z", "2023-01-01T00:00:00Z">"#,
+            json!("2023-06-15T12:00:00Z"),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: start 2023-12-31T00:00:00+00:00 is after end 2023-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_bound() {
+        let result = test_pattern(
+            r#"timestamp::between<"today", "2023-01-01T00:00:00Z">"#,
+            json!("2023-06-15T12:00:00Z"),
+        )
+        .await;
+
+        assert_not_satisfied!(&result);
+        assert_eq!(
+            result.rationale().reason(),
+            "invalid argument: start must be a timestamp, but is today"
+        );
+    }
+
+    #[tokio::test]
+    async fn bounds_from_config() {
+        let src = Ephemeral::new(
+            "test",
+            r#"pattern window = timestamp::between<config::of<"window.start">, config::of<"window.end">>"#,
+        );
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+        builder.config("window.start", "2023-03-01T00:00:00Z".to_string());
+        builder.config("window.end", "2023-03-02T00:00:00Z".to_string());
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate(
+                "test::window",
+                json!("2023-03-01T10:00:00Z"),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+
+        let result = runtime
+            .evaluate(
+                "test::window",
+                json!("2023-03-02T10:00:00Z"),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_not_satisfied!(result);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::package::Package;
 use crate::runtime::PackagePath;
 
+mod between;
 mod rfc2822;
 mod rfc3339;
 
@@ -9,5 +10,6 @@ pub fn package() -> Package {
     pkg.register_function("rfc3339".into(), rfc3339::Rfc3339);
     pkg.register_function("iso8601".into(), rfc3339::Rfc3339);
     pkg.register_function("rfc2822".into(), rfc2822::Rfc2822);
+    pkg.register_function("between".into(), between::Between);
     pkg
 }
@@ -1,8 +1,7 @@
 use crate::core::uri::url::Url;
-use crate::core::{BlockingFunction, Example, FunctionEvaluationResult};
+use crate::core::{invalid_arg, BlockingFunction, Example, FunctionEvaluationResult};
 use crate::lang::lir::Bindings;
-use crate::lang::{PatternMeta, Severity};
-use crate::runtime::rationale::Rationale;
+use crate::lang::PatternMeta;
 use crate::runtime::{ExecutionContext, Output, RuntimeError, World};
 use crate::value::{Object, RuntimeValue};
 use serde_json::json;
@@ -56,7 +55,7 @@ impl BlockingFunction for Purl {
             },
 
             RuntimeValue::Object(url) => self.validate(url),
-            _ => Self::invalid_arg("input is neither a String nor an Object"),
+            _ => Ok(invalid_arg("input is neither a String nor an Object")),
         }
     }
 }
@@ -64,15 +63,15 @@ impl BlockingFunction for Purl {
 impl Purl {
     fn validate(&self, url: &Object) -> Result<FunctionEvaluationResult, RuntimeError> {
         if !url.has_str("scheme", "pkg") {
-            return Self::invalid_arg(format!(
+            return Ok(invalid_arg(format!(
                 "Purl invalid scheme value, must be 'pkg', has: {:?}",
                 url.get("scheme")
-            ));
+            )));
         }
 
         let path = match url["path"].try_get_str() {
             Some(path) => path,
-            None => return Self::invalid_arg("Purl has no path"),
+            None => return Ok(invalid_arg("Purl has no path")),
         };
 
         let mut result = Object::new();
@@ -88,7 +87,7 @@ impl Purl {
                 *name
             }
             _ => {
-                return Self::invalid_arg("Invalid purl path");
+                return Ok(invalid_arg("Invalid purl path"));
             }
         };
 
@@ -103,7 +102,7 @@ impl Purl {
                 result.set("version", *version);
             }
             _ => {
-                return Self::invalid_arg(format!("Invalid name syntax: {name}"));
+                return Ok(invalid_arg(format!("Invalid name syntax: {name}")));
             }
         }
 
@@ -123,10 +122,6 @@ impl Purl {
 
         Ok(Output::Transform(Arc::new(result.into())).into())
     }
-
-    fn invalid_arg(msg: impl Into<Arc<str>>) -> Result<FunctionEvaluationResult, RuntimeError> {
-        Ok((Severity::Error, Rationale::InvalidArgument(msg.into())).into())
-    }
 }
 
 #[cfg(test)]
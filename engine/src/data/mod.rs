@@ -4,6 +4,7 @@
 use crate::runtime::RuntimeError;
 use crate::value::RuntimeValue;
 use std::collections::HashMap;
+use std::env;
 
 use std::fmt::Debug;
 use std::fs::File;
@@ -14,6 +15,14 @@ use std::path::PathBuf;
 pub trait DataSource: Send + Sync + Debug {
     /// Retrieve the data at the provided path, if found.
     fn get(&self, path: &str) -> Result<Option<RuntimeValue>, RuntimeError>;
+
+    /// Whether the data is not expected to change once the world is built.
+    ///
+    /// Data of static sources may be folded into the patterns when building, see
+    /// [`crate::lang::builder::Builder::fold_data`].
+    fn is_static(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug)]
@@ -45,6 +54,10 @@ impl DataSource for MemDataSource {
             None => Err(RuntimeError::NoSuchPath(path.to_string())),
         }
     }
+
+    fn is_static(&self) -> bool {
+        true
+    }
 }
 
 /// A source of data read from a directory.
@@ -112,4 +125,137 @@ impl DataSource for DirectoryDataSource {
             Ok(None)
         }
     }
+
+    /// Files are expected to be deployed along with the policies, they are still read again on
+    /// every lookup unless folded.
+    fn is_static(&self) -> bool {
+        true
+    }
+}
+
+/// A source of data read from environment variables.
+///
+/// Only variables starting with one of the allowed prefixes are exposed, so that unrelated
+/// variables, like credentials, can't be read by policies. Exposed variables are named without
+/// their prefix: with the prefix `SEEDWING_`, the variable `SEEDWING_ALLOWED_REGISTRY` is
+/// available as `ALLOWED_REGISTRY`. The path `env` provides an object of all exposed variables,
+/// and `env/NAME` the value of a single variable.
+#[derive(Debug)]
+pub struct EnvDataSource {
+    prefixes: Vec<String>,
+}
+
+impl EnvDataSource {
+    /// The path of the data provided by the source.
+    pub const PATH: &'static str = "env";
+
+    /// Create an environment data source exposing the variables with the given prefixes.
+    pub fn new<I, S>(prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            prefixes: prefixes.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The exposed name of a variable, if it starts with one of the allowed prefixes.
+    fn exposed<'n>(&self, name: &'n str) -> Option<&'n str> {
+        self.prefixes
+            .iter()
+            .filter(|prefix| !prefix.is_empty())
+            .find_map(|prefix| name.strip_prefix(prefix.as_str()))
+            .filter(|name| !name.is_empty())
+    }
+}
+
+impl DataSource for EnvDataSource {
+    fn get(&self, path: &str) -> Result<Option<RuntimeValue>, RuntimeError> {
+        if path == Self::PATH {
+            // variables which are not valid unicode are skipped
+            let vars: serde_json::Map<_, _> = env::vars_os()
+                .filter_map(|(name, value)| {
+                    Some((name.into_string().ok()?, value.into_string().ok()?))
+                })
+                .filter_map(|(name, value)| Some((self.exposed(&name)?.to_string(), value.into())))
+                .collect();
+            return Ok(Some(serde_json::Value::Object(vars).into()));
+        }
+
+        match path
+            .strip_prefix(Self::PATH)
+            .and_then(|p| p.strip_prefix('/'))
+        {
+            Some(name) if !name.is_empty() => Ok(self
+                .prefixes
+                .iter()
+                .filter(|prefix| !prefix.is_empty())
+                .find_map(|prefix| env::var(format!("{prefix}{name}")).ok())
+                .map(Into::into)),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::EvalContext;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn env_data_source() {
+        env::set_var("SWIO_ENV_DATA_SOURCE_TEST_ALLOWED_REGISTRY", "quay.io");
+        env::set_var("SWIO_ENV_DATA_SOURCE_SECRET", "hunter2");
+
+        let mut builder = Builder::new();
+        builder.data(EnvDataSource::new(["SWIO_ENV_DATA_SOURCE_TEST_"]));
+        builder
+            .build(
+                Ephemeral::new(
+                    "test",
+                    r#"
+pattern registry = data::from<"env/ALLOWED_REGISTRY">
+pattern lookup = data::lookup<"env", ["ALLOWED_REGISTRY"]>
+pattern prefixed = data::from<"env/SWIO_ENV_DATA_SOURCE_TEST_ALLOWED_REGISTRY">
+pattern secret = data::from<"env/SWIO_ENV_DATA_SOURCE_SECRET">
+pattern all = data::from<"env">
+"#,
+                )
+                .iter(),
+            )
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        for name in ["test::registry", "test::lookup"] {
+            let result = world
+                .evaluate(name, json!({}), EvalContext::default())
+                .await
+                .unwrap();
+            assert_satisfied!(&result);
+            assert_eq!(result.output().as_json(), json!("quay.io"));
+        }
+
+        for name in ["test::prefixed", "test::secret"] {
+            let result = world
+                .evaluate(name, json!({}), EvalContext::default())
+                .await
+                .unwrap();
+            assert_not_satisfied!(result);
+        }
+
+        let result = world
+            .evaluate("test::all", json!({}), EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(&result);
+        let all = result.output().as_json();
+        assert_eq!(all["ALLOWED_REGISTRY"], json!("quay.io"));
+        assert!(all.get("SWIO_ENV_DATA_SOURCE_SECRET").is_none());
+        assert!(all.get("SECRET").is_none());
+    }
 }
@@ -1,13 +1,16 @@
 //! Builder for creating a policy engine from a set of policies and data sources.
 //!
 //! A builder creates a World - a representation of all policies and patterns known by an engine.
+pub use crate::core::CorePackages;
 use crate::data::DataSource;
 use crate::lang::hir;
-use crate::lang::parser::SourceLocation;
+use crate::lang::parser::{Located, SourceLocation};
 use crate::runtime;
 use crate::runtime::cache::SourceCache;
 use crate::runtime::config::{ConfigContext, ConfigValue};
-use crate::runtime::BuildError;
+use crate::runtime::snapshot::SnapshotError;
+use crate::runtime::{BuildError, PatternName};
+use std::sync::Arc;
 
 /// Builder representing the entire world of policies.
 #[derive(Clone)]
@@ -35,6 +38,15 @@ impl Builder {
         }
     }
 
+    /// Create a new builder, only adding the selected core packages.
+    ///
+    /// See [`hir::World::new_with_packages`].
+    pub fn new_with_packages(config: ConfigContext, packages: CorePackages) -> Self {
+        Self {
+            hir: hir::World::new_with_packages(config, packages),
+        }
+    }
+
     /// Build policies found in the provided sources.
     pub fn build<S, SrcIter>(&mut self, sources: SrcIter) -> Result<(), Vec<BuildError>>
     where
@@ -45,6 +57,15 @@ impl Builder {
         self.hir.build(sources)
     }
 
+    /// Register a pattern directly, without providing source text for it.
+    ///
+    /// References to other patterns must be fully qualified. If the name collides with any other
+    /// pattern, this is reported as an error by [`Builder::finish`].
+    pub fn add_pattern<N: Into<PatternName>>(&mut self, name: N, pattern: Arc<hir::Pattern>) {
+        self.hir
+            .add_pattern(name.into(), Located::new((*pattern).clone(), 0..0))
+    }
+
     /// Compile all policies into a runtime World that can be used for policy evaluation.
     pub async fn finish(&mut self) -> Result<runtime::World, Vec<BuildError>> {
         let mir = self.hir.lower()?;
@@ -52,6 +73,15 @@ impl Builder {
         Ok(runtime)
     }
 
+    /// Restore a runtime World from a snapshot created by [`runtime::World::snapshot`], skipping
+    /// the compilation of the policies.
+    ///
+    /// Sources added to this builder are ignored, but its packages must provide all functions the
+    /// snapshot references. Data sources and configuration are taken from this builder.
+    pub fn restore(&self, snapshot: &[u8]) -> Result<runtime::World, SnapshotError> {
+        self.hir.restore(snapshot)
+    }
+
     /// The source cache with all known sources for this builder.
     pub fn source_cache(&self) -> &SourceCache {
         self.hir.source_cache()
@@ -62,6 +92,19 @@ impl Builder {
         self.hir.data(src)
     }
 
+    /// Fold lookups of static data into the patterns, instead of looking up the data when
+    /// evaluating. Disabled by default.
+    ///
+    /// Lookups using `data::from` and `data::lookup` with constant arguments are resolved when
+    /// finishing the build, if the data is provided by a static source (see
+    /// [`DataSource::is_static`]), and can be expressed as a literal value. This saves reading,
+    /// and parsing, the data on every evaluation. However, changes to the data will only be
+    /// picked up by building the world again, and data which was missing when building is still
+    /// looked up when evaluating.
+    pub fn fold_data(&mut self, enabled: bool) {
+        self.hir.fold_data(enabled)
+    }
+
     pub fn config<S: Into<String>, V: Into<ConfigValue>>(&mut self, key: S, val: V) {
         self.hir.config(key.into(), val.into())
     }
@@ -73,7 +116,8 @@ mod test {
     use crate::runtime::sources::Ephemeral;
     use crate::runtime::EvalContext;
 
-    use crate::assert_satisfied;
+    use crate::lang::ValuePattern;
+    use crate::{assert_not_satisfied, assert_satisfied};
     use serde_json::json;
 
     #[tokio::test]
@@ -112,4 +156,329 @@ mod test {
 
         assert_satisfied!(result.unwrap());
     }
+
+    #[tokio::test]
+    async fn without_core_packages() {
+        let mut builder = Builder::new_with_packages(
+            ConfigContext::default(),
+            CorePackages::except(["sigstore", "external", "guac"]),
+        );
+        builder
+            .build(Ephemeral::new("foo", "pattern bar = list::any<42>").iter())
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        assert!(runtime.get_package_meta("sigstore").is_none());
+        assert!(runtime.get_pattern_meta("sigstore::sha256").is_none());
+        assert!(runtime.get_pattern_meta("external::remote").is_none());
+        assert!(runtime.get_pattern_meta("list::any").is_some());
+        assert!(runtime.get_pattern_meta("string::regexp").is_some());
+
+        let result = runtime
+            .evaluate("foo::bar", json!([1, 42]), EvalContext::default())
+            .await;
+        assert_satisfied!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn only_core_packages() {
+        let mut builder =
+            Builder::new_with_packages(ConfigContext::default(), CorePackages::only(["list"]));
+        let runtime = builder.finish().await.unwrap();
+
+        assert!(runtime.get_pattern_meta("list::any").is_some());
+        // always available
+        assert!(runtime.get_pattern_meta("lang::not").is_some());
+        assert!(runtime.get_pattern_meta("string::regexp").is_none());
+    }
+
+    fn const_pattern(value: &str) -> Arc<hir::Pattern> {
+        Arc::new(hir::Pattern::Const(Located::new(
+            ValuePattern::String(value.into()),
+            0..0,
+        )))
+    }
+
+    #[tokio::test]
+    async fn add_pattern() {
+        let src = Ephemeral::new(
+            "foo::bar",
+            r#"
+        pattern named = {
+            name: synthetic::bob
+        }
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder.add_pattern("synthetic::bob", const_pattern("Bob"));
+        builder.build(src.iter()).unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate("synthetic::bob", json!("Bob"), EvalContext::default())
+            .await;
+        assert_satisfied!(result.unwrap());
+
+        let result = runtime
+            .evaluate(
+                "foo::bar::named",
+                json!({ "name": "Bob" }),
+                EvalContext::default(),
+            )
+            .await;
+        assert_satisfied!(result.unwrap());
+
+        let result = runtime
+            .evaluate(
+                "foo::bar::named",
+                json!({ "name": "Jim" }),
+                EvalContext::default(),
+            )
+            .await;
+        assert_not_satisfied!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn add_pattern_collision() {
+        let src = Ephemeral::new("foo::bar", "pattern bob = \"Bob\"");
+
+        let mut builder = Builder::new();
+        builder.add_pattern("foo::bar::bob", const_pattern("Bob"));
+        builder.add_pattern("lang::not", const_pattern("Bob"));
+        builder.build(src.iter()).unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        let names = errors
+            .iter()
+            .map(|e| match e {
+                BuildError::DuplicatePattern(_, _, name) => name.clone(),
+                _ => panic!("unexpected error: {e}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["foo::bar::bob", "lang::not"]);
+
+        // the collision with a source is located at its definition
+        assert_eq!(errors[0].source_location().name(), "foo/bar");
+        assert_eq!("pattern bob = \"Bob\""[errors[0].span()].trim(), "bob");
+    }
+
+    #[tokio::test]
+    async fn import_file() {
+        let shared = Ephemeral::new(
+            "policies::shared",
+            r#"
+        pattern named<name> = {
+            name: name
+        }
+        "#,
+        );
+        let common = Ephemeral::new("common", r#"pattern bob = "Bob""#);
+        let main = Ephemeral::new(
+            "policies::main",
+            r#"
+        import "./shared.dog"
+        import "../common.dog"
+
+        pattern folks = named<bob>
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder
+            .build(shared.iter().chain(common.iter()).chain(main.iter()))
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate(
+                "policies::main::folks",
+                json!({ "name": "Bob" }),
+                EvalContext::default(),
+            )
+            .await;
+        assert_satisfied!(result.unwrap());
+
+        let result = runtime
+            .evaluate(
+                "policies::main::folks",
+                json!({ "name": "Jim" }),
+                EvalContext::default(),
+            )
+            .await;
+        assert_not_satisfied!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn import_not_transitive() {
+        let first = Ephemeral::new(
+            "test::first",
+            r#"
+        import "./second"
+        pattern bob = "Bob"
+        "#,
+        );
+        let second = Ephemeral::new("test::second", r#"pattern jim = "Jim""#);
+        let main = Ephemeral::new(
+            "test::main",
+            r#"
+        import "./first.dog"
+        pattern folks = bob || jim
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder
+            .build(first.iter().chain(second.iter()).chain(main.iter()))
+            .unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        assert!(
+            matches!(
+                &errors[..],
+                [BuildError::PatternNotFound(source, _, name)]
+                    if source.name() == "test/main" && name == "jim"
+            ),
+            "{errors:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn conflicting_imports() {
+        let first = Ephemeral::new("test::first", r#"pattern name = "Bob""#);
+        let second = Ephemeral::new("test::second", r#"pattern name = "Jim""#);
+        let main = Ephemeral::new(
+            "test::main",
+            r#"
+        import "./first"
+        import "./second"
+        pattern folks = name
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder
+            .build(first.iter().chain(second.iter()).chain(main.iter()))
+            .unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        assert!(
+            matches!(
+                &errors[..],
+                [BuildError::ConflictingImport(source, _, name, first, second)]
+                    if source.name() == "test/main"
+                        && name == "name"
+                        && first == "test/first"
+                        && second == "test/second"
+            ),
+            "{errors:?}"
+        );
+
+        // unless the importing file defines the name itself
+        let main = Ephemeral::new(
+            "test::main",
+            r#"
+        import "./first"
+        import "./second"
+        pattern name = "Alice"
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder
+            .build(first.iter().chain(second.iter()).chain(main.iter()))
+            .unwrap();
+        assert!(builder.finish().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_not_found() {
+        let src = Ephemeral::new("test", r#"import "./missing.dog""#);
+
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        assert!(matches!(
+            &errors[..],
+            [BuildError::ImportNotFound(_, _, path)] if path == "./missing.dog"
+        ));
+    }
+
+    #[tokio::test]
+    async fn circular_import() {
+        let first = Ephemeral::new(
+            "test::first",
+            r#"
+        import "./second.dog"
+        pattern bob = "Bob"
+        "#,
+        );
+        let second = Ephemeral::new(
+            "test::second",
+            r#"
+        import "./first.dog"
+        pattern jim = "Jim"
+        "#,
+        );
+
+        let mut builder = Builder::new();
+        builder.build(first.iter().chain(second.iter())).unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        assert!(
+            matches!(
+                &errors[..],
+                [BuildError::CircularImport(_, _, cycle)]
+                    if cycle == "test/first -> test/second -> test/first"
+            ),
+            "{errors:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn config_from_environment() {
+        std::env::set_var("SEEDWING_TEST_CONFIG_GREETING", "Hello");
+
+        let src = Ephemeral::new("test", r#"pattern greeting = config::of<"greeting">"#);
+        let mut builder = Builder::new();
+        builder.config(
+            "greeting",
+            "${SEEDWING_TEST_CONFIG_GREETING} world".to_string(),
+        );
+        builder.build(src.iter()).unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate("test::greeting", json!(null), EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(&result);
+        assert_eq!(result.output().try_get_str(), Some("Hello world"));
+    }
+
+    #[tokio::test]
+    async fn config_from_unset_environment() {
+        std::env::remove_var("SEEDWING_TEST_CONFIG_UNSET");
+
+        let mut builder = Builder::new();
+        builder.config("token", "${SEEDWING_TEST_CONFIG_UNSET}".to_string());
+
+        let errors = builder.finish().await.err().unwrap();
+        assert!(matches!(
+            &errors[..],
+            [BuildError::UnsetEnvironmentVariable(_, _, key, variable)]
+                if key == "token" && variable == "SEEDWING_TEST_CONFIG_UNSET"
+        ));
+
+        // located at the reference of the config value, if any
+        let policy = r#"pattern token = config::of<"token">"#;
+        builder
+            .build(Ephemeral::new("test", policy).iter())
+            .unwrap();
+
+        let errors = builder.finish().await.err().unwrap();
+        assert_eq!(errors[0].source_location().name(), "test");
+        assert_eq!(policy[errors[0].span()].trim(), "config::of");
+    }
 }
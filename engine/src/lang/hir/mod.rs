@@ -1,14 +1,17 @@
-use crate::core::Example;
+use crate::core::{CorePackages, Example};
 use crate::data::DataSource;
 use crate::lang::lir::ValuePattern;
-use crate::lang::parser::{CompilationUnit, Located, Location, PolicyParser, SourceLocation};
+use crate::lang::parser::{
+    CompilationUnit, Located, Location, PolicyParser, SourceLocation, SourceSpan,
+};
 use crate::lang::{lir, mir, SyntacticSugar};
 use crate::package::Package;
 use crate::runtime::cache::SourceCache;
 use crate::runtime::config::{ConfigContext, ConfigValue};
-use crate::runtime::{BuildError, PackagePath, PatternName};
+use crate::runtime::snapshot::SnapshotError;
+use crate::runtime::{self, BuildError, PackagePath, PatternName};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::iter::once;
 use std::sync::Arc;
@@ -182,6 +185,45 @@ impl Pattern {
         }
     }
 
+    /// The keys of the configuration values referenced using `config::of`, located at the
+    /// reference.
+    pub(crate) fn config_references(&self) -> Vec<Located<Arc<str>>> {
+        match self {
+            Pattern::Ref(_, name, arguments) => {
+                let mut references = arguments
+                    .iter()
+                    .flat_map(|e| e.config_references())
+                    .collect::<Vec<_>>();
+                if name.as_type_str() == "config::of" {
+                    if let Some(Pattern::Const(key)) = arguments.first().map(|e| &**e) {
+                        if let ValuePattern::String(key) = &**key {
+                            references.push(Located::new(key.clone(), name.location()));
+                        }
+                    }
+                }
+                references
+            }
+            Pattern::Object(inner) => inner
+                .fields()
+                .iter()
+                .flat_map(|e| e.ty().config_references())
+                .collect(),
+            Pattern::Join(terms)
+            | Pattern::Meet(terms)
+            | Pattern::List(terms)
+            | Pattern::Chain(terms) => terms.iter().flat_map(|e| e.config_references()).collect(),
+            Pattern::Refinement(inner) | Pattern::Not(inner) | Pattern::Deref(inner) => {
+                inner.config_references()
+            }
+            Pattern::Anything
+            | Pattern::Const(_)
+            | Pattern::Expr(_)
+            | Pattern::Traverse(_)
+            | Pattern::Nothing
+            | Pattern::Parameter(_) => Vec::default(),
+        }
+    }
+
     pub(crate) fn qualify_types(&mut self, types: &HashMap<String, Option<Located<PatternName>>>) {
         match self {
             Pattern::Anything => {}
@@ -337,8 +379,10 @@ impl Field {
 pub struct World {
     units: Vec<CompilationUnit>,
     packages: Vec<Package>,
+    patterns: Vec<(PatternName, Located<Pattern>)>,
     source_cache: SourceCache,
     data_sources: Vec<Arc<dyn DataSource>>,
+    fold_data: bool,
     config: ConfigContext,
 }
 
@@ -352,7 +396,9 @@ impl Clone for World {
     fn clone(&self) -> Self {
         let mut h = World::new();
         h.packages = self.packages.clone();
+        h.patterns = self.patterns.clone();
         h.data_sources = self.data_sources.clone();
+        h.fold_data = self.fold_data;
         h.config = self.config.clone();
         h
     }
@@ -364,58 +410,78 @@ impl World {
     }
 
     pub fn new_with_config(config: ConfigContext) -> Self {
+        Self::new_with_packages(config, CorePackages::All)
+    }
+
+    /// Create a new world, only adding the selected core packages.
+    ///
+    /// The `lang` package is always added, as the policy syntax relies on it.
+    pub fn new_with_packages(config: ConfigContext, packages: CorePackages) -> Self {
         let mut world = Self {
             units: Default::default(),
             packages: Default::default(),
+            patterns: Default::default(),
             source_cache: Default::default(),
             data_sources: Vec::default(),
+            fold_data: false,
             config,
         };
         world.add_package(crate::core::lang::package());
-        world.add_package(crate::core::config::package());
-        world.add_package(crate::core::list::package());
-        world.add_package(crate::core::string::package());
-        world.add_package(crate::core::base64::package());
-        world.add_package(crate::core::json::package());
+        world.add_core_package(&packages, crate::core::config::package());
+        world.add_core_package(&packages, crate::core::list::package());
+        world.add_core_package(&packages, crate::core::string::package());
+        world.add_core_package(&packages, crate::core::base64::package());
+        world.add_core_package(&packages, crate::core::json::package());
         #[cfg(feature = "sigstore")]
-        world.add_package(crate::core::sigstore::package());
-        world.add_package(crate::core::x509::package());
-        world.add_package(crate::core::cyclonedx::package());
-        world.add_package(crate::core::jsf::package());
-        world.add_package(crate::core::spdx::package());
-        world.add_package(crate::core::iso::package());
-        world.add_package(crate::core::kafka::package());
-        world.add_package(crate::core::pem::package());
-        world.add_package(crate::core::net::package());
+        world.add_core_package(&packages, crate::core::sigstore::package());
+        world.add_core_package(&packages, crate::core::x509::package());
+        world.add_core_package(&packages, crate::core::cyclonedx::package());
+        world.add_core_package(&packages, crate::core::jsf::package());
+        world.add_core_package(&packages, crate::core::spdx::package());
+        world.add_core_package(&packages, crate::core::sbom::package());
+        world.add_core_package(&packages, crate::core::iso::package());
+        world.add_core_package(&packages, crate::core::kafka::package());
+        world.add_core_package(&packages, crate::core::pem::package());
+        world.add_core_package(&packages, crate::core::net::package());
+        world.add_core_package(&packages, crate::core::email::package());
+        world.add_core_package(&packages, crate::core::octets::package());
         #[cfg(not(target_arch = "wasm32"))]
-        world.add_package(crate::core::openvex::package());
+        world.add_core_package(&packages, crate::core::openvex::package());
         #[cfg(not(target_arch = "wasm32"))]
-        world.add_package(crate::core::osv::package());
-        world.add_package(crate::core::uri::package());
-        world.add_package(crate::core::timestamp::package());
-        world.add_package(crate::core::csaf::package());
+        world.add_core_package(&packages, crate::core::osv::package());
+        world.add_core_package(&packages, crate::core::uri::package());
+        world.add_core_package(&packages, crate::core::purl::package());
+        world.add_core_package(&packages, crate::core::timestamp::package());
+        world.add_core_package(&packages, crate::core::csaf::package());
         #[cfg(not(target_arch = "wasm32"))]
-        world.add_package(crate::core::rhsa::package());
-        world.add_package(crate::core::slsa::package());
+        world.add_core_package(&packages, crate::core::rhsa::package());
+        world.add_core_package(&packages, crate::core::slsa::package());
         #[cfg(feature = "intoto")]
-        world.add_package(crate::core::intoto::package());
+        world.add_core_package(&packages, crate::core::intoto::package());
+        world.add_core_package(&packages, crate::core::dsse::package());
 
         #[cfg(feature = "debug")]
-        world.add_package(crate::core::debug::package());
+        world.add_core_package(&packages, crate::core::debug::package());
 
-        world.add_package(crate::core::maven::package());
+        world.add_core_package(&packages, crate::core::maven::package());
         #[cfg(not(target_arch = "wasm32"))]
-        world.add_package(crate::core::external::package());
+        world.add_core_package(&packages, crate::core::external::package());
         #[cfg(not(target_arch = "wasm32"))]
-        world.add_package(crate::core::guac::package());
-        world.add_package(crate::core::semver::package());
+        world.add_core_package(&packages, crate::core::guac::package());
+        world.add_core_package(&packages, crate::core::semver::package());
 
         #[cfg(feature = "showcase")]
-        world.add_package(crate::core::showcase::package());
+        world.add_core_package(&packages, crate::core::showcase::package());
 
         world
     }
 
+    fn add_core_package(&mut self, packages: &CorePackages, package: Package) {
+        if packages.includes(&package.path().as_package_str()) {
+            self.add_package(package);
+        }
+    }
+
     pub fn source_cache(&self) -> &SourceCache {
         &self.source_cache
     }
@@ -455,6 +521,13 @@ impl World {
         self.data_sources.push(Arc::new(src))
     }
 
+    /// Fold lookups of data provided by static data sources into the patterns when lowering.
+    ///
+    /// See [`crate::lang::builder::Builder::fold_data`].
+    pub fn fold_data(&mut self, enabled: bool) {
+        self.fold_data = enabled;
+    }
+
     pub fn config(&mut self, key: String, val: ConfigValue) {
         self.config.insert(key.into(), val);
     }
@@ -467,8 +540,43 @@ impl World {
         self.packages.push(package);
     }
 
+    pub fn add_pattern(&mut self, name: PatternName, pattern: Located<Pattern>) {
+        self.patterns.push((name, pattern));
+    }
+
+    /// Restore a world from a snapshot, instead of lowering the sources.
+    ///
+    /// The functions of the snapshot are looked up in the packages of this world.
+    pub fn restore(&self, snapshot: &[u8]) -> Result<runtime::World, SnapshotError> {
+        let data = crate::core::data::package(self.data_sources.clone());
+        let mut lang = Package::new(PackagePath::from_parts(vec!["lang"]));
+        crate::core::data::register_lang_functions(&mut lang, self.data_sources.clone());
+
+        let mut functions = HashMap::new();
+        for package in self.packages.iter().chain([&data, &lang]) {
+            let path = package.path();
+            for (fn_name, func) in package.functions() {
+                functions.insert(path.type_name(fn_name), func);
+            }
+        }
+
+        let config =
+            self.config
+                .resolve_env()
+                .map_err(|unset| SnapshotError::UnsetEnvironmentVariable {
+                    key: unset.key,
+                    variable: unset.variable,
+                })?;
+
+        runtime::World::restore(snapshot, &functions, config)
+    }
+
     pub fn lower(&mut self) -> Result<mir::World, Vec<BuildError>> {
         self.add_package(crate::core::data::package(self.data_sources.clone()));
+        let lang = PackagePath::from_parts(vec!["lang"]);
+        if let Some(pkg) = self.packages.iter_mut().find(|pkg| pkg.path() == lang) {
+            crate::core::data::register_lang_functions(pkg, self.data_sources.clone());
+        }
 
         let mut core_units = Vec::new();
 
@@ -500,41 +608,175 @@ impl World {
             return Err(errors);
         }
 
-        Lowerer::new(&mut self.units, &mut self.packages, self.config.clone()).lower()
+        let config = self.config.resolve_env().map_err(|unset| {
+            // point to a reference of the config value, if any
+            let (source, span) = self
+                .units
+                .iter()
+                .find_map(|unit| {
+                    unit.types()
+                        .iter()
+                        .flat_map(|defn| defn.ty().config_references())
+                        .find(|reference| *reference.inner() == *unset.key)
+                        .map(|reference| (unit.source(), reference.span()))
+                })
+                .unwrap_or_else(|| (String::from("config").into(), 0..0));
+            vec![BuildError::UnsetEnvironmentVariable(
+                source,
+                span,
+                unset.key,
+                unset.variable,
+            )]
+        })?;
+
+        let fold_data = self.fold_data.then(|| self.data_sources.clone());
+        Lowerer::new(
+            &mut self.units,
+            &mut self.packages,
+            &self.patterns,
+            config,
+            fold_data,
+        )
+        .lower()
     }
 }
 
 struct Lowerer<'b> {
     units: &'b mut Vec<CompilationUnit>,
     packages: &'b mut Vec<Package>,
+    patterns: &'b [(PatternName, Located<Pattern>)],
     config: ConfigContext,
+    fold_data: Option<Vec<Arc<dyn DataSource>>>,
 }
 
 impl<'b> Lowerer<'b> {
     pub fn new(
         units: &'b mut Vec<CompilationUnit>,
         packages: &'b mut Vec<Package>,
+        patterns: &'b [(PatternName, Located<Pattern>)],
         config: ConfigContext,
+        fold_data: Option<Vec<Arc<dyn DataSource>>>,
     ) -> Self {
         Self {
             units,
             packages,
+            patterns,
             config,
+            fold_data,
         }
     }
 
     pub fn lower(self) -> Result<mir::World, Vec<BuildError>> {
         // First, perform internal per-unit linkage and type qualification
         let mut world = mir::World::new(self.config);
+        if let Some(data_sources) = self.fold_data {
+            world.fold_data(data_sources);
+        }
         let mut errors = Vec::new();
 
-        for unit in self.units.iter_mut() {
-            let unit_path = PackagePath::from(unit.source());
+        // resolve imported files, which must not import each other in circles
+
+        let sources = self
+            .units
+            .iter()
+            .enumerate()
+            .map(|(index, unit)| (unit.source(), index))
+            .collect::<HashMap<_, _>>();
+
+        let mut imports = Vec::with_capacity(self.units.len());
+        for unit in self.units.iter() {
+            let mut resolved = Vec::new();
+            for import in unit.imports() {
+                match resolve_import(&unit.source(), import).and_then(|src| sources.get(&src)) {
+                    Some(index) => resolved.push((*index, import.span())),
+                    None => errors.push(BuildError::ImportNotFound(
+                        unit.source(),
+                        import.span(),
+                        import.inner(),
+                    )),
+                }
+            }
+            imports.push(resolved);
+        }
+
+        for (cycle, span) in import_cycles(&imports) {
+            let importing = cycle[cycle.len() - 2];
+            errors.push(BuildError::CircularImport(
+                self.units[importing].source(),
+                span,
+                cycle
+                    .iter()
+                    .map(|index| self.units[*index].source().name())
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // imports are not transitive, and must not bring the same name into scope twice, unless
+        // the importing file defines it itself
 
-            let mut visible_types = unit
+        let mut imported_types = Vec::with_capacity(self.units.len());
+        for (index, unit) in self.units.iter().enumerate() {
+            let local = unit
                 .uses()
                 .iter()
-                .map(|e| (e.as_name().inner(), Some(e.type_name())))
+                .map(|e| e.as_name().inner())
+                .chain(unit.types().iter().map(|e| e.name().inner()))
+                .collect::<HashSet<_>>();
+
+            let mut visible = HashMap::<String, (usize, Located<PatternName>)>::new();
+            for (imported, span) in &imports[index] {
+                let imported_unit = &self.units[*imported];
+                let unit_path = PackagePath::from(imported_unit.source());
+                for e in imported_unit.types() {
+                    let name = e.name().inner();
+                    if local.contains(&name) {
+                        continue;
+                    }
+                    match visible.get(&name) {
+                        Some((other, _)) if other != imported => {
+                            errors.push(BuildError::ConflictingImport(
+                                unit.source(),
+                                span.clone(),
+                                name,
+                                self.units[*other].source().name(),
+                                imported_unit.source().name(),
+                            ));
+                        }
+                        Some(_) => {}
+                        None => {
+                            let ty = Located::new(unit_path.type_name(name.clone()), e.location());
+                            visible.insert(name, (*imported, ty));
+                        }
+                    }
+                }
+            }
+            imported_types.push(
+                visible
+                    .into_iter()
+                    .map(|(name, (_, ty))| (name, Some(ty)))
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        for (unit, imported_types) in self.units.iter_mut().zip(imported_types) {
+            let unit_path = PackagePath::from(unit.source());
+
+            let mut visible_types = imported_types
+                .into_iter()
+                .chain(
+                    unit.uses()
+                        .iter()
+                        .map(|e| (e.as_name().inner(), Some(e.type_name()))),
+                )
                 .chain(unit.types().iter().map(|e| {
                     (
                         e.name().inner(),
@@ -598,6 +840,38 @@ impl<'b> Lowerer<'b> {
             known_world.extend_from_slice(&unit_types);
         }
 
+        // patterns registered programmatically must not shadow any other pattern
+
+        for (name, pattern) in self.patterns.iter() {
+            if known_world.contains(name) {
+                // point to the colliding definition, if it has a source
+                let (source, span) = self
+                    .units
+                    .iter()
+                    .find_map(|unit| {
+                        let unit_path = PackagePath::from(unit.source());
+                        unit.types()
+                            .iter()
+                            .find(|defn| unit_path.type_name(defn.name().inner()) == *name)
+                            .map(|defn| (unit.source(), defn.name().span()))
+                    })
+                    .unwrap_or_else(|| {
+                        let source = match name.package() {
+                            Some(package) => package.into(),
+                            None => String::new().into(),
+                        };
+                        (source, pattern.span())
+                    });
+                errors.push(BuildError::DuplicatePattern(
+                    source,
+                    span,
+                    name.as_type_str(),
+                ));
+            } else {
+                known_world.push(name.clone());
+            }
+        }
+
         if !errors.is_empty() {
             return Err(errors);
         }
@@ -619,6 +893,28 @@ impl<'b> Lowerer<'b> {
             }
         }
 
+        for (_, ty) in self.patterns.iter() {
+            for each in ty.referenced_types() {
+                if !known_world.contains(&each.clone().inner()) {
+                    errors.push(BuildError::PatternNotFound(
+                        String::new().into(),
+                        each.location().span(),
+                        each.clone().as_type_str(),
+                    ))
+                }
+            }
+        }
+
+        // memoized patterns must always produce the same result for the same input
+        let memoized = self.units.iter().any(|unit| {
+            unit.types()
+                .iter()
+                .any(|defn| defn.metadata.attributes.contains_key("memoize"))
+        });
+        if memoized {
+            self.check_memoized(&mut errors);
+        }
+
         for unit in self.units.iter() {
             let unit_path = PackagePath::from(unit.source());
 
@@ -635,6 +931,10 @@ impl<'b> Lowerer<'b> {
             }
         }
 
+        for (name, _) in self.patterns.iter() {
+            world.declare(name.clone(), Default::default(), vec![], vec![]);
+        }
+
         for package in self.packages.iter() {
             let path = package.path();
             for (fn_name, func) in package.functions() {
@@ -677,6 +977,10 @@ impl<'b> Lowerer<'b> {
             }
         }
 
+        for (name, ty) in self.patterns.iter() {
+            world.define(name.clone(), ty).map_err(|e| vec![e])?;
+        }
+
         // define packages in the mir world
 
         let mut packages = HashMap::with_capacity(self.packages.len());
@@ -705,4 +1009,131 @@ impl<'b> Lowerer<'b> {
             Err(errors)
         }
     }
+
+    /// Report the memoized patterns which may not produce the same result for the same input.
+    fn check_memoized(&self, errors: &mut Vec<BuildError>) {
+        let mut non_deterministic = HashSet::new();
+        for package in self.packages.iter() {
+            let path = package.path();
+            for (fn_name, func) in package.functions() {
+                if !func.deterministic() {
+                    non_deterministic.insert(path.type_name(fn_name));
+                }
+            }
+        }
+
+        let mut references = Vec::new();
+        for unit in self.units.iter() {
+            let unit_path = PackagePath::from(unit.source());
+            for defn in unit.types() {
+                references.push((
+                    unit_path.type_name(defn.name().inner()),
+                    defn.referenced_types(),
+                ));
+            }
+        }
+        for (name, ty) in self.patterns.iter() {
+            references.push((name.clone(), ty.referenced_types()));
+        }
+
+        // anything referencing a non-deterministic pattern is non-deterministic too
+        loop {
+            let mut changed = false;
+            for (name, referenced) in &references {
+                if !non_deterministic.contains(name)
+                    && referenced
+                        .iter()
+                        .any(|each| non_deterministic.contains(&**each))
+                {
+                    non_deterministic.insert(name.clone());
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        for unit in self.units.iter() {
+            let unit_path = PackagePath::from(unit.source());
+
+            for defn in unit.types() {
+                if !defn.metadata.attributes.contains_key("memoize") {
+                    continue;
+                }
+                let name = unit_path.type_name(defn.name().inner());
+                let reason = if !defn.parameters().is_empty() {
+                    "it has parameters"
+                } else if non_deterministic.contains(&name) {
+                    "it depends on a non-deterministic function"
+                } else {
+                    continue;
+                };
+                errors.push(BuildError::Memoize(
+                    unit.source().clone(),
+                    defn.name().location().span(),
+                    name.as_type_str(),
+                    reason.into(),
+                ));
+            }
+        }
+    }
+}
+
+/// Resolve the path of an imported file, relative to the importing source.
+///
+/// Sources are named after their path without the `.dog` extension, like `foo/bar` for the file
+/// `foo/bar.dog`.
+fn resolve_import(source: &SourceLocation, path: &str) -> Option<SourceLocation> {
+    let name = source.name();
+    let mut parts = name.split('/').collect::<Vec<_>>();
+    // imports are relative to the directory of the importing file
+    parts.pop();
+
+    for part in path.strip_suffix(".dog").unwrap_or(path).split('/') {
+        match part {
+            "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            "" => return None,
+            part => parts.push(part),
+        }
+    }
+
+    Some(parts.join("/").into())
+}
+
+/// Find the cycles in the imports, each one as the list of units from the start back to the
+/// start, along with the span of the import closing it.
+fn import_cycles(imports: &[Vec<(usize, SourceSpan)>]) -> Vec<(Vec<usize>, SourceSpan)> {
+    fn visit(
+        unit: usize,
+        imports: &[Vec<(usize, SourceSpan)>],
+        done: &mut [bool],
+        stack: &mut Vec<usize>,
+        cycles: &mut Vec<(Vec<usize>, SourceSpan)>,
+    ) {
+        stack.push(unit);
+        for (imported, span) in &imports[unit] {
+            if let Some(start) = stack.iter().position(|e| e == imported) {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(*imported);
+                cycles.push((cycle, span.clone()));
+            } else if !done[*imported] {
+                visit(*imported, imports, done, stack, cycles);
+            }
+        }
+        stack.pop();
+        done[unit] = true;
+    }
+
+    let mut done = vec![false; imports.len()];
+    let mut cycles = Vec::new();
+    for unit in 0..imports.len() {
+        if !done[unit] {
+            visit(unit, imports, &mut done, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
 }
@@ -0,0 +1,382 @@
+//! Constant folding of data lookups, used by [`crate::lang::builder::Builder::fold_data`].
+//!
+//! References to `data::from` and `data::lookup` with constant arguments are replaced by
+//! references to `data::literal`, carrying the data they resolve to. Only data provided by
+//! static sources is folded, and only data which can be expressed as a [`ValuePattern`], so
+//! objects, and lists containing objects, are still looked up when evaluating.
+
+use crate::data::DataSource;
+use crate::lang::lir::{Bindings, Field, InnerPattern, ObjectPattern, Pattern, ValuePattern};
+use crate::runtime::{PackagePath, PatternName};
+use crate::value::RuntimeValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub(crate) struct DataFolder<'a> {
+    data_sources: &'a [Arc<dyn DataSource>],
+    from: Option<usize>,
+    lookup: Option<usize>,
+    literal: usize,
+}
+
+impl<'a> DataFolder<'a> {
+    /// Create a folder for the types of a world, `None` if the world lacks the `data` package.
+    pub(crate) fn new(
+        data_sources: &'a [Arc<dyn DataSource>],
+        types: &HashMap<PatternName, usize>,
+    ) -> Option<Self> {
+        let slot = |name: &str| {
+            types
+                .get(&PatternName::new(
+                    Some(PackagePath::from_parts(vec!["data"])),
+                    name.into(),
+                ))
+                .copied()
+        };
+
+        Some(Self {
+            data_sources,
+            from: slot("from"),
+            lookup: slot("lookup"),
+            literal: slot("literal")?,
+        })
+    }
+
+    /// Fold all data lookups of the pattern.
+    pub(crate) fn fold(&self, pattern: &Arc<Pattern>) -> Arc<Pattern> {
+        self.fold_inner(pattern).unwrap_or_else(|| pattern.clone())
+    }
+
+    /// Fold the pattern, returning `None` if there was nothing to fold.
+    fn fold_inner(&self, pattern: &Arc<Pattern>) -> Option<Arc<Pattern>> {
+        let inner = match &pattern.inner {
+            InnerPattern::Ref(sugar, slot, arguments) => match self.resolve(*slot, arguments) {
+                Some(value) => InnerPattern::Ref(
+                    sugar.clone(),
+                    self.literal,
+                    vec![Arc::new(Pattern::new(
+                        None,
+                        Default::default(),
+                        Vec::default(),
+                        Vec::default(),
+                        InnerPattern::Const(value),
+                    ))],
+                ),
+                None => InnerPattern::Ref(sugar.clone(), *slot, self.fold_all(arguments)?),
+            },
+            InnerPattern::Bound(primary, bindings) => {
+                let folded = self.fold_inner(primary);
+                let mut changed = folded.is_some();
+                let mut folded_bindings = Bindings::default();
+                for (name, binding) in bindings.iter() {
+                    let binding = match self.fold_inner(binding) {
+                        Some(binding) => {
+                            changed = true;
+                            binding
+                        }
+                        None => binding.clone(),
+                    };
+                    folded_bindings.bind(name.clone(), binding);
+                }
+                if !changed {
+                    return None;
+                }
+                InnerPattern::Bound(folded.unwrap_or_else(|| primary.clone()), folded_bindings)
+            }
+            InnerPattern::Deref(inner) => InnerPattern::Deref(self.fold_inner(inner)?),
+            InnerPattern::Object(object) => {
+                let types: Vec<_> = object.fields().iter().map(|f| f.ty.clone()).collect();
+                let types = self.fold_all(&types)?;
+                InnerPattern::Object(ObjectPattern::new(
+                    object
+                        .fields()
+                        .iter()
+                        .zip(types)
+                        .map(|(field, ty)| {
+                            Arc::new(Field::new(field.name.clone(), ty, field.optional))
+                        })
+                        .collect(),
+                ))
+            }
+            InnerPattern::List(terms) => InnerPattern::List(self.fold_all(terms)?),
+            _ => return None,
+        };
+
+        Some(Arc::new(Pattern::new(
+            pattern.name.clone(),
+            pattern.metadata.clone(),
+            pattern.examples.clone(),
+            pattern.parameters.clone(),
+            inner,
+        )))
+    }
+
+    /// Fold all patterns, returning `None` if there was nothing to fold in any of them.
+    fn fold_all(&self, patterns: &[Arc<Pattern>]) -> Option<Vec<Arc<Pattern>>> {
+        let folded: Vec<_> = patterns.iter().map(|each| self.fold_inner(each)).collect();
+        if folded.iter().all(Option::is_none) {
+            return None;
+        }
+
+        Some(
+            folded
+                .into_iter()
+                .zip(patterns)
+                .map(|(folded, original)| folded.unwrap_or_else(|| original.clone()))
+                .collect(),
+        )
+    }
+
+    /// The value a reference to a data function resolves to, if it can be folded.
+    ///
+    /// This mirrors the lookup performed by the functions when evaluating.
+    fn resolve(&self, slot: usize, arguments: &[Arc<Pattern>]) -> Option<ValuePattern> {
+        let (path, steps) = if Some(slot) == self.from {
+            let [path] = arguments else { return None };
+            (path, None)
+        } else if Some(slot) == self.lookup {
+            let [path, steps] = arguments else { return None };
+            (path, Some(steps))
+        } else {
+            return None;
+        };
+
+        let Some(ValuePattern::String(path)) = path.try_get_resolved_value() else {
+            return None;
+        };
+        let mut value = Arc::new(self.get(&path)?);
+
+        if let Some(steps) = steps {
+            let InnerPattern::List(steps) = &steps.inner else {
+                return None;
+            };
+            for step in steps {
+                let Some(ValuePattern::String(step)) = step.try_get_resolved_value() else {
+                    return None;
+                };
+                value = value.try_get_object()?.get(step)?;
+            }
+        }
+
+        to_value_pattern(&value)
+    }
+
+    /// The data of the first source providing it, as long as no source which isn't static would
+    /// have been asked before.
+    fn get(&self, path: &str) -> Option<RuntimeValue> {
+        for ds in self.data_sources {
+            if !ds.is_static() {
+                return None;
+            }
+            match ds.get(path) {
+                Ok(Some(value)) => return Some(value),
+                Ok(None) => {}
+                // not folding it, evaluating looks it up again
+                Err(_) => return None,
+            }
+        }
+        None
+    }
+}
+
+fn to_value_pattern(value: &RuntimeValue) -> Option<ValuePattern> {
+    Some(match value {
+        RuntimeValue::Null => ValuePattern::Null,
+        RuntimeValue::String(val) => ValuePattern::String(val.clone()),
+        RuntimeValue::Integer(val) => ValuePattern::Integer(*val),
+        RuntimeValue::Decimal(val) => ValuePattern::Decimal(*val),
+        RuntimeValue::Boolean(val) => ValuePattern::Boolean(*val),
+        RuntimeValue::List(items) => ValuePattern::List(
+            items
+                .iter()
+                .map(|each| to_value_pattern(each).map(Arc::new))
+                .collect::<Option<_>>()?,
+        ),
+        RuntimeValue::Octets(val) => ValuePattern::Octets(val.clone()),
+        // there are no literals for those
+        RuntimeValue::Object(_) | RuntimeValue::Timestamp(_) => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data::{DataSource, DirectoryDataSource};
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::testutil::test_data_dir;
+    use crate::runtime::{EvalContext, RuntimeError, World};
+    use crate::value::RuntimeValue;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::{json, Value};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const POLICY: &str = r#"
+pattern licenses = data::from<"allowed-licenses.json">
+pattern owner = data::lookup<"allow-lists.json", ["owner"]>
+pattern registries = data::lookup<"allow-lists.json", ["registries"]>
+pattern unknown-step = data::lookup<"allow-lists.json", ["unknown"]>
+pattern inventory = data::from<"inventory.json">
+pattern missing = data::from<"missing.json">
+pattern mit = data::from<"allowed-licenses.json"> | list::any<"MIT">
+pattern gpl = data::from<"allowed-licenses.json"> | list::any<"GPL-3.0-only">
+pattern component = {
+    name: string,
+    allowed: data::lookup<"allow-lists.json", ["registries"]>,
+}
+"#;
+
+    /// Counts the lookups of the data, which can't happen once folded.
+    #[derive(Debug)]
+    struct Counting {
+        inner: DirectoryDataSource,
+        is_static: bool,
+        lookups: Arc<AtomicUsize>,
+        /// Fail all lookups while set.
+        failing: Arc<AtomicBool>,
+    }
+
+    impl DataSource for Counting {
+        fn get(&self, path: &str) -> Result<Option<RuntimeValue>, RuntimeError> {
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+            if self.failing.load(Ordering::Relaxed) {
+                return Err(RuntimeError::FileUnreadable(path.into()));
+            }
+            self.inner.get(path)
+        }
+
+        fn is_static(&self) -> bool {
+            self.is_static
+        }
+    }
+
+    async fn world(fold: bool, data: impl DataSource + 'static) -> World {
+        let mut builder = Builder::new();
+        builder.data(data);
+        builder.fold_data(fold);
+        builder
+            .build(Ephemeral::new("test", POLICY).iter())
+            .unwrap();
+        builder.finish().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn folded_evaluates_identically() {
+        let unfolded = world(false, DirectoryDataSource::new(test_data_dir())).await;
+        let folded = world(true, DirectoryDataSource::new(test_data_dir())).await;
+
+        let inputs = [
+            json!(null),
+            json!("MIT"),
+            json!(["quay.io"]),
+            json!({"name": "widgets", "allowed": true}),
+            json!({"allowed": true}),
+        ];
+
+        for pattern in [
+            "licenses",
+            "owner",
+            "registries",
+            "unknown-step",
+            "inventory",
+            "missing",
+            "mit",
+            "gpl",
+            "component",
+        ] {
+            let name = format!("test::{pattern}");
+            for input in &inputs {
+                let expected = unfolded
+                    .evaluate(name.as_str(), input.clone(), EvalContext::default())
+                    .await
+                    .unwrap();
+                let actual = folded
+                    .evaluate(name.as_str(), input.clone(), EvalContext::default())
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    expected.severity(),
+                    actual.severity(),
+                    "{pattern} with {input}"
+                );
+                assert_eq!(
+                    expected.output().as_json(),
+                    actual.output().as_json(),
+                    "{pattern} with {input}"
+                );
+            }
+        }
+
+        let result = folded
+            .evaluate("test::mit", Value::Null, EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+        let result = folded
+            .evaluate("test::gpl", Value::Null, EvalContext::default())
+            .await
+            .unwrap();
+        assert_not_satisfied!(result);
+    }
+
+    async fn lookups(is_static: bool, pattern: &str) -> usize {
+        let lookups = Arc::new(AtomicUsize::default());
+        let world = world(
+            true,
+            Counting {
+                inner: DirectoryDataSource::new(test_data_dir()),
+                is_static,
+                lookups: lookups.clone(),
+                failing: Default::default(),
+            },
+        )
+        .await;
+
+        let before = lookups.load(Ordering::Relaxed);
+        world
+            .evaluate(pattern, Value::Null, EvalContext::default())
+            .await
+            .unwrap();
+        lookups.load(Ordering::Relaxed) - before
+    }
+
+    #[tokio::test]
+    async fn static_data_is_folded() {
+        assert_eq!(lookups(true, "test::licenses").await, 0);
+        assert_eq!(lookups(true, "test::registries").await, 0);
+        // objects can't be folded
+        assert_eq!(lookups(true, "test::inventory").await, 1);
+    }
+
+    #[tokio::test]
+    async fn dynamic_data_is_not_folded() {
+        assert_eq!(lookups(false, "test::licenses").await, 1);
+        assert_eq!(lookups(false, "test::registries").await, 1);
+    }
+
+    #[tokio::test]
+    async fn failing_data_is_not_folded() {
+        let lookups = Arc::new(AtomicUsize::default());
+        let failing = Arc::new(AtomicBool::new(true));
+        let world = world(
+            true,
+            Counting {
+                inner: DirectoryDataSource::new(test_data_dir()),
+                is_static: true,
+                lookups: lookups.clone(),
+                failing: failing.clone(),
+            },
+        )
+        .await;
+
+        failing.store(false, Ordering::Relaxed);
+        let before = lookups.load(Ordering::Relaxed);
+        let result = world
+            .evaluate("test::mit", Value::Null, EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+        assert_eq!(lookups.load(Ordering::Relaxed) - before, 1);
+    }
+}
@@ -7,7 +7,32 @@ use serde_json::{json, Value};
 use std::sync::Arc;
 
 impl Pattern {
+    /// Create a JSON schema of the pattern.
+    ///
+    /// The name and documentation of the pattern are used as title and description of the
+    /// schema. As the documentation of object fields is part of the field's pattern, it becomes
+    /// the description of the property.
     pub fn as_json_schema(&self, world: &World, bindings: &Vec<Arc<Pattern>>) -> SchemaObject {
+        let mut schema = self.as_undocumented_json_schema(world, bindings);
+
+        if let Some(name) = &self.name {
+            schema.metadata().title = Some(name.as_type_str());
+        }
+        if let Some(documentation) = self.metadata.documentation.as_ref() {
+            let documentation = documentation.trim();
+            if !documentation.is_empty() {
+                schema.metadata().description = Some(documentation.to_string());
+            }
+        }
+
+        schema
+    }
+
+    fn as_undocumented_json_schema(
+        &self,
+        world: &World,
+        bindings: &Vec<Arc<Pattern>>,
+    ) -> SchemaObject {
         match &self.inner {
             InnerPattern::Anything => Schema::Bool(true).into_object(),
             InnerPattern::Primordial(inner) => match inner {
@@ -147,3 +172,58 @@ impl Pattern {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn documentation() {
+        let src = Ephemeral::new(
+            "test",
+            r#"
+/// A software component.
+pattern component = {
+    /// The name of the component.
+    name: string,
+    version: string,
+    /// The supplier of the component.
+    supplier?: supplier,
+}
+
+pattern supplier = {
+    name: string,
+}
+"#,
+        );
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let (_, pattern) = world
+            .patterns_matching("test::component")
+            .into_iter()
+            .next()
+            .unwrap();
+        let schema = serde_json::to_value(pattern.as_json_schema(&world, &vec![])).unwrap();
+
+        assert_eq!(schema["title"], json!("test::component"));
+        assert_eq!(schema["description"], json!("A software component."));
+
+        let properties = &schema["properties"];
+        assert_eq!(
+            properties["name"]["description"],
+            json!("The name of the component.")
+        );
+        assert!(properties["version"].get("description").is_none());
+        assert_eq!(
+            properties["supplier"],
+            json!({
+                "$ref": "test::supplier",
+                "description": "The supplier of the component.",
+            })
+        );
+    }
+}
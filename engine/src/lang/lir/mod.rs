@@ -1,4 +1,6 @@
+pub(crate) mod fold;
 pub mod json_schema;
+pub(crate) mod snapshot;
 
 use crate::{
     core::Example,
@@ -19,6 +21,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Instant;
 
 /// Represents an expression of patterns.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -217,12 +220,25 @@ impl Pattern {
         bindings: &'v Bindings,
         world: &'v World,
     ) -> Pin<Box<dyn Future<Output = Result<EvaluationResult, RuntimeError>> + 'v>> {
-        ctx.trace.clone().run(
+        let memo = match (self.metadata.memoize, self.name()) {
+            (true, Some(name)) => {
+                let key = (name, value.as_typed_key());
+                if let Some(result) = ctx.memoized(&key) {
+                    return Box::pin(std::future::ready(Ok(result)));
+                }
+                Some((ctx.memo(), key))
+            }
+            _ => None,
+        };
+
+        let profiler = ctx.profiler().cloned().zip(self.name());
+
+        let result = ctx.trace.clone().run(
             value.clone(),
             self.clone(),
             Box::pin(async move {
                 // increment recursions
-                let ctx = ctx.push()?;
+                let ctx = ctx.push()?.enter(self.name());
 
                 match &self.inner {
                     InnerPattern::Anything => Ok(EvaluationResult::new(
@@ -291,10 +307,12 @@ impl Pattern {
                         PrimordialPattern::Boolean => {
                             self.eval_primordial(value, RuntimeValue::is_boolean)
                         }
-                        PrimordialPattern::String => {
-                            self.eval_primordial(value, RuntimeValue::is_string)
-                        }
+                        PrimordialPattern::String => self.eval_primordial(value, |value| {
+                            // detected timestamps are still strings of the input
+                            value.is_string() || value.is_timestamp()
+                        }),
                         PrimordialPattern::Function(_sugar, _name, func) => {
+                            ctx.count_function_call()?;
                             let result = func.call(value.clone(), ctx, bindings, world).await?;
                             Ok(EvaluationResult::new(
                                 value,
@@ -353,11 +371,27 @@ impl Pattern {
                                 }
                             }
 
+                            // transformed fields are replaced in the output, keeping the others as-is
+                            let mut output = None;
+                            for field in &inner.fields {
+                                if let Some(Some(field_result)) = result.get(field.name()) {
+                                    if let Output::Transform(transformed) = &field_result.output {
+                                        output
+                                            .get_or_insert_with(|| obj.clone())
+                                            .set(field.name(), (**transformed).clone());
+                                    }
+                                }
+                            }
+                            let output = match output {
+                                Some(output) => Output::Transform(Arc::new(output.into())),
+                                None => Output::Identity,
+                            };
+
                             Ok(EvaluationResult::new(
                                 value,
                                 self.clone(),
                                 Arc::new(Rationale::Object(result)),
-                                Output::Identity,
+                                output,
                             ))
                         } else {
                             Ok(EvaluationResult::new(
@@ -424,7 +458,26 @@ impl Pattern {
                     )),
                 }
             }),
-        )
+        );
+
+        let result = match profiler {
+            None => result,
+            Some((profiler, name)) => Box::pin(async move {
+                let start = Instant::now();
+                let result = result.await;
+                profiler.record(name, start.elapsed());
+                result
+            }),
+        };
+
+        match memo {
+            None => result,
+            Some((memo, key)) => Box::pin(async move {
+                let result = result.await?;
+                memo.lock().unwrap().insert(key, result.clone());
+                Ok(result)
+            }),
+        }
     }
 
     fn eval_primordial<'ctx, 'v, F>(
@@ -611,6 +664,12 @@ impl From<&ValuePattern> for RuntimeValue {
 }
 
 impl ValuePattern {
+    /// Check if the value is equal to a runtime value, following the same rules as comparing two
+    /// runtime values.
+    ///
+    /// Besides values of the same type, a string is equal to octets consisting of its UTF-8
+    /// encoded bytes (in both directions), and a string is equal to a timestamp it can be
+    /// parsed as.
     pub fn is_equal(&self, other: &RuntimeValue) -> bool {
         match (self, &other) {
             (ValuePattern::Null, RuntimeValue::Null) => true,
@@ -631,6 +690,11 @@ impl ValuePattern {
                 }
             }
             (ValuePattern::Octets(lhs), RuntimeValue::Octets(rhs)) => lhs.eq(rhs),
+            (ValuePattern::String(lhs), RuntimeValue::Octets(rhs)) => lhs.as_bytes() == rhs,
+            (ValuePattern::Octets(lhs), RuntimeValue::String(rhs)) => lhs == rhs.as_bytes(),
+            (ValuePattern::String(_), RuntimeValue::Timestamp(_)) => {
+                RuntimeValue::from(self).eq(other)
+            }
             _ => false,
         }
     }
@@ -663,6 +727,9 @@ impl From<Arc<RuntimeValue>> for Pattern {
                 RuntimeValue::Octets(inner) => {
                     InnerPattern::Const(ValuePattern::Octets(inner.clone()))
                 }
+                RuntimeValue::Timestamp(inner) => {
+                    InnerPattern::Const(ValuePattern::String(inner.to_rfc3339().into()))
+                }
             },
         )
     }
@@ -918,6 +985,9 @@ mod test {
         hir::{self, AttributeValues},
         meta::Deprecation,
     };
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::testutil::Instrumented;
+    use crate::runtime::{BuildError, EvalContext};
     use std::collections::HashMap;
 
     #[test]
@@ -986,4 +1056,112 @@ mod test {
             })
         );
     }
+
+    #[test]
+    fn string_equals_octets() {
+        let string = ValuePattern::String("bob".into());
+        assert!(string.is_equal(&RuntimeValue::Octets(b"bob".to_vec())));
+        assert!(!string.is_equal(&RuntimeValue::Octets(b"jim".to_vec())));
+        assert_eq!(
+            string.is_equal(&RuntimeValue::Octets(b"bob".to_vec())),
+            RuntimeValue::from(&string) == RuntimeValue::Octets(b"bob".to_vec())
+        );
+    }
+
+    #[test]
+    fn octets_equal_string() {
+        let octets = ValuePattern::Octets(b"bob".to_vec());
+        assert!(octets.is_equal(&RuntimeValue::String("bob".into())));
+        assert!(!octets.is_equal(&RuntimeValue::String("jim".into())));
+        assert_eq!(
+            octets.is_equal(&RuntimeValue::String("bob".into())),
+            RuntimeValue::from(&octets) == RuntimeValue::String("bob".into())
+        );
+    }
+
+    fn world(src: &str, instrumented: Instrumented) -> Result<mir::World, Vec<BuildError>> {
+        let mut world = hir::World::new();
+        world.add_package(instrumented.package());
+        world.build(Ephemeral::new("test", src).iter()).unwrap();
+        world.lower()
+    }
+
+    #[tokio::test]
+    async fn memoized_evaluated_once() {
+        let instrumented = Instrumented::default().memoizable();
+        let runtime = world(
+            r#"
+#[memoize]
+pattern element = instrumented::term
+
+pattern test = list::all<element>
+"#,
+            instrumented.clone(),
+        )
+        .unwrap()
+        .lower()
+        .unwrap();
+
+        let result = runtime
+            .evaluate(
+                "test::test",
+                serde_json::json!([42; 10]),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.severity(), Severity::None);
+        assert_eq!(instrumented.calls(), 1);
+    }
+
+    #[test]
+    fn memoize_non_deterministic() {
+        let result = world(
+            r#"
+pattern element = instrumented::term
+
+#[memoize]
+pattern test = list::all<element>
+"#,
+            Instrumented::default(),
+        );
+
+        assert!(matches!(
+            result.err().as_deref(),
+            Some([BuildError::Memoize(_, _, name, _)]) if name == "test::test"
+        ));
+    }
+
+    #[test]
+    fn memoize_parameterized() {
+        let result = world(
+            r#"
+#[memoize]
+pattern test<p> = list::all<p>
+"#,
+            Instrumented::default(),
+        );
+
+        assert!(matches!(
+            result.err().as_deref(),
+            Some([BuildError::Memoize(_, _, name, _)]) if name == "test::test"
+        ));
+    }
+
+    #[test]
+    fn memoize_timeout() {
+        let result = world(
+            r#"
+#[memoize]
+pattern test = lang::timeout<integer, 100>
+"#,
+            Instrumented::default(),
+        );
+
+        assert!(matches!(
+            result.err().as_deref(),
+            Some([BuildError::Memoize(_, _, name, _)]) if name == "test::test"
+        ));
+    }
 }
@@ -0,0 +1,186 @@
+//! Serializable form of compiled patterns, used by [`crate::runtime::snapshot`].
+//!
+//! Patterns form a graph, sharing common sub-patterns. They are stored in a flat table, each
+//! pattern referencing the patterns it consists of by their index in the table. Patterns are
+//! added after the patterns they reference, so they can be restored in a single pass.
+
+use crate::core::{Example, Function};
+use crate::lang::lir::{Bindings, Expr, Field, InnerPattern, ObjectPattern, Pattern, ValuePattern};
+use crate::lang::{PatternMeta, PrimordialPattern, SyntacticSugar};
+use crate::runtime::snapshot::SnapshotError;
+use crate::runtime::PatternName;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct PatternTable {
+    entries: Vec<Entry>,
+    #[serde(skip)]
+    indices: HashMap<*const Pattern, usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    name: Option<PatternName>,
+    metadata: PatternMeta,
+    examples: Vec<Example>,
+    parameters: Vec<Arc<str>>,
+    inner: InnerEntry,
+}
+
+#[derive(Serialize, Deserialize)]
+enum InnerEntry {
+    Anything,
+    Integer,
+    Decimal,
+    Boolean,
+    String,
+    /// A function, restored by its name.
+    Function(SyntacticSugar, PatternName),
+    Bound(usize, Vec<(Arc<str>, usize)>),
+    Ref(SyntacticSugar, usize, Vec<usize>),
+    Deref(usize),
+    Argument(Arc<str>),
+    Const(ValuePattern),
+    Object(Vec<(Arc<str>, usize, bool)>),
+    Expr(Expr),
+    List(Vec<usize>),
+    Nothing,
+}
+
+impl PatternTable {
+    /// Add a pattern, and all patterns it consists of, returning its index.
+    pub(crate) fn add(&mut self, pattern: &Arc<Pattern>) -> usize {
+        if let Some(index) = self.indices.get(&Arc::as_ptr(pattern)) {
+            return *index;
+        }
+
+        let inner = match &pattern.inner {
+            InnerPattern::Anything => InnerEntry::Anything,
+            InnerPattern::Primordial(PrimordialPattern::Integer) => InnerEntry::Integer,
+            InnerPattern::Primordial(PrimordialPattern::Decimal) => InnerEntry::Decimal,
+            InnerPattern::Primordial(PrimordialPattern::Boolean) => InnerEntry::Boolean,
+            InnerPattern::Primordial(PrimordialPattern::String) => InnerEntry::String,
+            InnerPattern::Primordial(PrimordialPattern::Function(sugar, name, _)) => {
+                InnerEntry::Function(sugar.clone(), name.clone())
+            }
+            InnerPattern::Bound(primary, bindings) => {
+                let primary = self.add(primary);
+                let mut bindings = bindings
+                    .iter()
+                    .map(|(name, binding)| (name.clone(), self.add(binding)))
+                    .collect::<Vec<_>>();
+                // keep the snapshot stable
+                bindings.sort_unstable_by(|(l, _), (r, _)| l.cmp(r));
+                InnerEntry::Bound(primary, bindings)
+            }
+            InnerPattern::Ref(sugar, slot, arguments) => InnerEntry::Ref(
+                sugar.clone(),
+                *slot,
+                arguments.iter().map(|each| self.add(each)).collect(),
+            ),
+            InnerPattern::Deref(inner) => InnerEntry::Deref(self.add(inner)),
+            InnerPattern::Argument(name) => InnerEntry::Argument(name.clone()),
+            InnerPattern::Const(value) => InnerEntry::Const(value.clone()),
+            InnerPattern::Object(object) => InnerEntry::Object(
+                object
+                    .fields()
+                    .iter()
+                    .map(|field| (field.name.clone(), self.add(&field.ty), field.optional))
+                    .collect(),
+            ),
+            InnerPattern::Expr(expr) => InnerEntry::Expr((**expr).clone()),
+            InnerPattern::List(terms) => {
+                InnerEntry::List(terms.iter().map(|each| self.add(each)).collect())
+            }
+            InnerPattern::Nothing => InnerEntry::Nothing,
+        };
+
+        let index = self.entries.len();
+        self.entries.push(Entry {
+            name: pattern.name.clone(),
+            metadata: (*pattern.metadata).clone(),
+            examples: pattern.examples.clone(),
+            parameters: pattern.parameters.clone(),
+            inner,
+        });
+        self.indices.insert(Arc::as_ptr(pattern), index);
+        index
+    }
+
+    /// Restore all patterns of the table, in the order they were added.
+    ///
+    /// Functions are looked up by their name.
+    pub(crate) fn restore(
+        self,
+        functions: &HashMap<PatternName, Arc<dyn Function>>,
+    ) -> Result<Vec<Arc<Pattern>>, SnapshotError> {
+        let mut patterns: Vec<Arc<Pattern>> = Vec::with_capacity(self.entries.len());
+
+        for entry in self.entries {
+            // only patterns which were added before can be referenced
+            let get = |index: usize| {
+                patterns
+                    .get(index)
+                    .cloned()
+                    .ok_or(SnapshotError::InvalidReference(index))
+            };
+
+            let inner = match entry.inner {
+                InnerEntry::Anything => InnerPattern::Anything,
+                InnerEntry::Integer => InnerPattern::Primordial(PrimordialPattern::Integer),
+                InnerEntry::Decimal => InnerPattern::Primordial(PrimordialPattern::Decimal),
+                InnerEntry::Boolean => InnerPattern::Primordial(PrimordialPattern::Boolean),
+                InnerEntry::String => InnerPattern::Primordial(PrimordialPattern::String),
+                InnerEntry::Function(sugar, name) => {
+                    let Some(func) = functions.get(&name) else {
+                        return Err(SnapshotError::UnknownFunction(name.as_type_str()));
+                    };
+                    InnerPattern::Primordial(PrimordialPattern::Function(sugar, name, func.clone()))
+                }
+                InnerEntry::Bound(primary, entries) => {
+                    let mut bindings = Bindings::default();
+                    for (name, binding) in entries {
+                        bindings.bind(name, get(binding)?);
+                    }
+                    InnerPattern::Bound(get(primary)?, bindings)
+                }
+                InnerEntry::Ref(sugar, slot, arguments) => InnerPattern::Ref(
+                    sugar,
+                    slot,
+                    arguments
+                        .into_iter()
+                        .map(get)
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+                InnerEntry::Deref(inner) => InnerPattern::Deref(get(inner)?),
+                InnerEntry::Argument(name) => InnerPattern::Argument(name),
+                InnerEntry::Const(value) => InnerPattern::Const(value),
+                InnerEntry::Object(fields) => InnerPattern::Object(ObjectPattern::new(
+                    fields
+                        .into_iter()
+                        .map(|(name, ty, optional)| {
+                            Ok(Arc::new(Field::new(name, get(ty)?, optional)))
+                        })
+                        .collect::<Result<Vec<_>, SnapshotError>>()?,
+                )),
+                InnerEntry::Expr(expr) => InnerPattern::Expr(Arc::new(expr)),
+                InnerEntry::List(terms) => {
+                    InnerPattern::List(terms.into_iter().map(get).collect::<Result<Vec<_>, _>>()?)
+                }
+                InnerEntry::Nothing => InnerPattern::Nothing,
+            };
+
+            patterns.push(Arc::new(Pattern::new(
+                entry.name,
+                Arc::new(entry.metadata),
+                entry.examples,
+                entry.parameters,
+                inner,
+            )));
+        }
+
+        Ok(patterns)
+    }
+}
@@ -13,6 +13,9 @@ pub struct PatternMeta {
     pub deprecation: Option<Deprecation>,
     #[serde(default, skip_serializing_if = "is_default")]
     pub reporting: Reporting,
+    /// Results of the pattern can be cached by input
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub memoize: bool,
 }
 
 impl PatternMeta {
@@ -31,6 +34,7 @@ impl TryFrom<hir::Metadata> for PatternMeta {
             documentation: Documentation(value.documentation),
             unstable: value.attributes.contains_key("unstable"),
             deprecation: value.attributes.remove("deprecated").map(Into::into),
+            memoize: value.attributes.contains_key("memoize"),
         })
     }
 }
@@ -1,5 +1,7 @@
 use crate::core::{Example, Function};
+use crate::data::DataSource;
 use crate::lang::hir::Expr;
+use crate::lang::lir::fold::DataFolder;
 use crate::lang::parser::Located;
 use crate::lang::{hir, mir};
 use crate::lang::{lir, SyntacticSugar};
@@ -176,6 +178,9 @@ pub struct World {
     type_slots: Vec<Arc<PatternHandle>>,
     types: HashMap<PatternName, usize>,
     packages: HashMap<PackagePath, PackageMeta>,
+    fold_data: Option<Vec<Arc<dyn DataSource>>>,
+    /// Non-fatal findings of the build, like undocumented patterns.
+    warnings: Vec<String>,
 }
 
 impl World {
@@ -185,6 +190,8 @@ impl World {
             type_slots: vec![],
             types: Default::default(),
             packages: Default::default(),
+            fold_data: None,
+            warnings: vec![],
         };
 
         this.define_primordial("integer", PrimordialPattern::Integer);
@@ -195,6 +202,11 @@ impl World {
         this
     }
 
+    /// Fold lookups of data provided by static data sources when lowering.
+    pub(crate) fn fold_data(&mut self, data_sources: Vec<Arc<dyn DataSource>>) {
+        self.fold_data = Some(data_sources);
+    }
+
     fn define_primordial(&mut self, name: &str, ty: PrimordialPattern) {
         let name = PatternName::new(None, name.into());
 
@@ -220,7 +232,9 @@ impl World {
     ) {
         log::debug!("declare {}", path);
         if metadata.documentation.is_none() {
-            log::info!("{} is not documented", path.as_type_str());
+            let warning = format!("{} is not documented", path.as_type_str());
+            log::info!("{warning}");
+            self.warnings.push(warning);
         }
 
         let runtime_type = Arc::new(
@@ -482,6 +496,9 @@ impl Lowerer {
         log::info!("Compiling {} patterns", self.world.types.len());
 
         self.add_types();
+        if let Some(data_sources) = self.world.fold_data.take() {
+            self.fold_data(&data_sources);
+        }
         self.build_packages();
         self.apply_packages();
         self.sort_world();
@@ -493,7 +510,8 @@ impl Lowerer {
             self.types,
             self.type_slots,
             self.packages,
-        ))
+        )
+        .with_warnings(self.world.warnings))
     }
 
     fn add_types(&mut self) {
@@ -514,6 +532,15 @@ impl Lowerer {
         }
     }
 
+    /// Replace lookups of static data with the data itself.
+    fn fold_data(&mut self, data_sources: &[Arc<dyn DataSource>]) {
+        if let Some(folder) = DataFolder::new(data_sources, &self.types) {
+            for pattern in self.type_slots.iter_mut() {
+                *pattern = folder.fold(pattern);
+            }
+        }
+    }
+
     /// Build the package hierarchy from the known types
     fn build_packages(&mut self) {
         // insert the root
@@ -1,5 +1,6 @@
 //use crate::lang::expr::expr;
 use crate::lang::hir::PatternDefn;
+use crate::lang::parser::literal::raw_string_literal;
 use crate::lang::parser::ty::{pkg_doc_comment, simple_type_name, type_definition, type_name};
 use crate::runtime::PackagePath;
 use crate::runtime::PatternName;
@@ -22,18 +23,22 @@ pub type SourceSpan = std::ops::Range<usize>;
 #[derive(Debug)]
 pub struct CompilationUnit {
     source: SourceLocation,
+    imports: Vec<Located<String>>,
     uses: Vec<Located<Use>>,
     types: Vec<Located<PatternDefn>>,
     documentation: Option<String>,
+    comments: Vec<Located<String>>,
 }
 
 impl CompilationUnit {
     pub fn new(source: SourceLocation) -> Self {
         Self {
             source,
+            imports: Default::default(),
             uses: Default::default(),
             types: Default::default(),
             documentation: None,
+            comments: Default::default(),
         }
     }
 
@@ -41,6 +46,10 @@ impl CompilationUnit {
         self.source.clone()
     }
 
+    pub fn add_import(&mut self, path: Located<String>) {
+        self.imports.push(path)
+    }
+
     pub fn add_use(&mut self, ty: Located<Use>) {
         self.uses.push(ty)
     }
@@ -49,6 +58,18 @@ impl CompilationUnit {
         self.types.push(ty)
     }
 
+    /// The regular (non-doc) comments of the source, with the text following the `//`.
+    ///
+    /// Those don't contribute to the patterns, but can be used by tools which must retain them.
+    pub fn comments(&self) -> &[Located<String>] {
+        &self.comments
+    }
+
+    /// The paths of the files imported by the source, relative to the source.
+    pub(crate) fn imports(&self) -> &Vec<Located<String>> {
+        &self.imports
+    }
+
     pub(crate) fn uses(&self) -> &Vec<Located<Use>> {
         &self.uses
     }
@@ -274,7 +295,7 @@ impl PolicyParser {
         S: Into<Stream<'a, ParserInput, <ParserError as Error<ParserInput>>::Span, Iter>>,
     {
         let tokens = lexer().parse(stream)?;
-        let tokens = remove_comments(&tokens);
+        let (tokens, comments) = remove_comments(&tokens);
 
         //let bytes = tokens.iter().map(|e|e.0).collect::<String>();
         //println!("{:?}", bytes);
@@ -285,7 +306,8 @@ impl PolicyParser {
 
         if !errors.is_empty() {
             Err(errors)
-        } else if let Some(compilation_unit) = compilation_unit {
+        } else if let Some(mut compilation_unit) = compilation_unit {
+            compilation_unit.comments = comments;
             Ok(compilation_unit)
         } else {
             Err(vec![ParserError::custom(
@@ -296,8 +318,13 @@ impl PolicyParser {
     }
 }
 
-fn remove_comments(tokens: &Vec<(ParserInput, SourceSpan)>) -> Vec<(ParserInput, SourceSpan)> {
+/// Remove regular comments from the tokens, returning them separately.
+#[allow(clippy::type_complexity)]
+fn remove_comments(
+    tokens: &Vec<(ParserInput, SourceSpan)>,
+) -> (Vec<(ParserInput, SourceSpan)>, Vec<Located<String>>) {
     let mut filtered_tokens = Vec::new();
+    let mut comments = Vec::new();
     let len = tokens.len();
 
     let mut i = 0;
@@ -319,11 +346,16 @@ fn remove_comments(tokens: &Vec<(ParserInput, SourceSpan)>) -> Vec<(ParserInput,
                         i += 2;
                     }
                     _ => {
+                        let start = tokens[i].1.start;
                         i += 2;
                         // consume until newline
-                        while tokens[i].0 != '\n' {
+                        let mut comment = String::new();
+                        while i < len && tokens[i].0 != '\n' {
+                            comment.push(tokens[i].0);
                             i += 1;
                         }
+                        let end = tokens[i - 1].1.end;
+                        comments.push(Located::new(comment, start..end));
                     }
                 }
             } else {
@@ -343,7 +375,7 @@ fn remove_comments(tokens: &Vec<(ParserInput, SourceSpan)>) -> Vec<(ParserInput,
     println!("{}", debug);
      */
 
-    filtered_tokens
+    (filtered_tokens, comments)
 }
 
 pub fn lexer(
@@ -355,6 +387,15 @@ fn op(op: &str) -> impl Parser<ParserInput, &str, Error = ParserError> + Clone {
     just(op).padded()
 }
 
+pub fn import_statement() -> impl Parser<ParserInput, Located<String>, Error = ParserError> + Clone
+{
+    just("import")
+        .padded()
+        .ignored()
+        .then(raw_string_literal())
+        .map(|(_, path)| path)
+}
+
 pub fn use_statement() -> impl Parser<ParserInput, Located<Use>, Error = ParserError> + Clone {
     just("use")
         .padded()
@@ -383,10 +424,17 @@ where
 {
     pkg_doc_comment(0)
         .padded()
+        .then(import_statement().padded().repeated())
         .then(use_statement().padded().repeated())
-        .then(type_definition().padded().repeated())
+        .then(
+            type_definition()
+                .padded()
+                // skip ahead to the next definition on errors, reporting all errors at once
+                .recover_with(skip_then_retry_until([]))
+                .repeated(),
+        )
         .then_ignore(end())
-        .map(move |((pkg_doc, use_statements), types)| {
+        .map(move |(((pkg_doc, imports), use_statements), types)| {
             let mut unit = CompilationUnit::new(source.clone().into());
 
             unit.documentation = if !pkg_doc.is_empty() {
@@ -395,6 +443,10 @@ where
                 None
             };
 
+            for e in imports {
+                unit.add_import(e)
+            }
+
             for e in use_statements {
                 unit.add_use(e)
             }
@@ -545,10 +597,88 @@ mod test {
 
 #[cfg(test)]
 mod test {
-    use crate::lang::parser::Located;
+    use super::*;
 
     /// created a located instance suitable for testing only (as it has a range of 0..0)
     pub(crate) fn located<T>(inner: impl Into<T>) -> Located<T> {
         Located::new(inner.into(), 0..0usize)
     }
+
+    #[test]
+    fn retain_comments() {
+        let src = r#"// leading comment
+/// documentation
+pattern foo = {
+    // inside an object
+    name: "bob", // trailing
+    url: "http://example.com",
+}
+// last line without a newline"#;
+
+        let unit = PolicyParser::default()
+            .parse("test".to_string(), src)
+            .unwrap();
+        let comments: Vec<_> = unit
+            .comments()
+            .iter()
+            .map(|comment| (comment.inner.as_str(), &src[comment.span()]))
+            .collect();
+
+        assert_eq!(
+            comments,
+            vec![
+                (" leading comment", "// leading comment"),
+                (" inside an object", "// inside an object"),
+                (" trailing", "// trailing"),
+                (
+                    " last line without a newline",
+                    "// last line without a newline"
+                ),
+            ]
+        );
+        assert_eq!(unit.types().len(), 1);
+    }
+
+    #[test]
+    fn parse_imports() {
+        let src = r#"
+import "./shared.dog"
+import "../common/types.dog"
+
+use list::all
+
+pattern foo = all<shared-pattern>
+"#;
+
+        let unit = PolicyParser::default()
+            .parse("test".to_string(), src)
+            .unwrap();
+        let imports: Vec<_> = unit.imports().iter().map(|i| i.inner()).collect();
+
+        assert_eq!(imports, vec!["./shared.dog", "../common/types.dog"]);
+        assert_eq!(unit.uses().len(), 1);
+        assert_eq!(unit.types().len(), 1);
+    }
+
+    #[test]
+    fn report_multiple_errors() {
+        let first = "pattern first = { name: }\n";
+        let valid = "\npattern valid = string\n\n";
+        let second = "pattern second = list::all<\n";
+        let src = format!("{first}{valid}{second}");
+
+        let errors = PolicyParser::default()
+            .parse("test".to_string(), src.as_str())
+            .unwrap_err();
+
+        let second_start = first.len() + valid.len();
+        assert!(
+            errors.iter().any(|e| e.span().start < first.len()),
+            "{errors:?}"
+        );
+        assert!(
+            errors.iter().any(|e| e.span().start >= second_start),
+            "{errors:?}"
+        );
+    }
 }
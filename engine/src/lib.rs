@@ -13,6 +13,11 @@ mod package;
 pub mod runtime;
 pub mod value;
 
+/// DSSE (Dead Simple Signing Envelope) envelopes, as processed by the `dsse` package.
+pub mod dsse {
+    pub use crate::core::dsse::{pae, Envelope, Signature};
+}
+
 /// Common test functionality
 #[doc(hidden)]
 pub mod test;
@@ -22,6 +22,11 @@ impl SourceCache {
     pub fn add(&mut self, id: SourceLocation, source: Source) {
         self.cache.insert(id, source);
     }
+
+    /// Get the source of a location, if cached.
+    pub fn get(&self, id: &SourceLocation) -> Option<&Source> {
+        self.cache.get(id)
+    }
 }
 
 impl Cache<SourceLocation> for &SourceCache {
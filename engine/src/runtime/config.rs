@@ -21,6 +21,66 @@ impl ConfigContext {
             }
         }
     }
+
+    /// Resolve `${ENV_VAR}` references in string values from the process environment.
+    pub(crate) fn resolve_env(&self) -> Result<Self, UnsetVariable> {
+        self.resolve_with(|name| std::env::var(name).ok())
+    }
+
+    /// Resolve `${NAME}` references in string values, using the provided lookup function.
+    ///
+    /// Fails on the first reference which cannot be resolved.
+    pub(crate) fn resolve_with<F>(&self, lookup: F) -> Result<Self, UnsetVariable>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut resolved = HashMap::with_capacity(self.0.len());
+        for (key, value) in self.0.iter() {
+            let value = match value {
+                ConfigValue::String(value) => {
+                    ConfigValue::String(interpolate(value, &lookup).map_err(|variable| {
+                        UnsetVariable {
+                            key: key.to_string(),
+                            variable,
+                        }
+                    })?)
+                }
+                value => value.clone(),
+            };
+            resolved.insert(key.clone(), value);
+        }
+        Ok(Self(resolved))
+    }
+}
+
+/// A config value referencing a variable which is not set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsetVariable {
+    /// The config key
+    pub key: String,
+    /// The name of the variable
+    pub variable: String,
+}
+
+/// Replace all `${NAME}` occurrences in the value, returning the name of the first unset variable
+/// on failure.
+fn interpolate<F>(value: &str, lookup: &F) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut result = String::with_capacity(value.len());
+    let mut remainder = value;
+    while let Some(start) = remainder.find("${") {
+        let Some(end) = remainder[start + 2..].find('}') else {
+            break;
+        };
+        let name = &remainder[start + 2..start + 2 + end];
+        result.push_str(&remainder[..start]);
+        result.push_str(&lookup(name).ok_or_else(|| name.to_string())?);
+        remainder = &remainder[start + 2 + end + 1..];
+    }
+    result.push_str(remainder);
+    Ok(result)
 }
 
 impl ConfigContext {
@@ -178,3 +238,69 @@ impl From<&ConfigValue> for RuntimeValue {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOST" => Some("example.com".to_string()),
+            "PORT" => Some("8443".to_string()),
+            _ => None,
+        }
+    }
+
+    fn get_string(config: &ConfigContext, key: &str) -> String {
+        match config.get(key) {
+            Some(ConfigValue::String(value)) => value.clone(),
+            other => panic!("unexpected value: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_set_variables() {
+        let config: ConfigContext = serde_json::json!({
+            "server": {
+                "host": "${HOST}",
+                "url": "https://${HOST}:${PORT}/api",
+                "plain": "no variables",
+                "unterminated": "${HOST",
+            },
+            "retries": 3,
+        })
+        .into();
+
+        let config = config.resolve_with(lookup).unwrap();
+
+        assert_eq!(get_string(&config, "server.host"), "example.com");
+        assert_eq!(
+            get_string(&config, "server.url"),
+            "https://example.com:8443/api"
+        );
+        assert_eq!(get_string(&config, "server.plain"), "no variables");
+        assert_eq!(get_string(&config, "server.unterminated"), "${HOST");
+        assert!(matches!(
+            config.get("retries"),
+            Some(ConfigValue::Integer(3))
+        ));
+    }
+
+    #[test]
+    fn resolve_unset_variable() {
+        let config: ConfigContext = serde_json::json!({
+            "server": {
+                "token": "${TOKEN}",
+            },
+        })
+        .into();
+
+        assert_eq!(
+            config.resolve_with(lookup).unwrap_err(),
+            UnsetVariable {
+                key: "server.token".to_string(),
+                variable: "TOKEN".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Structured reports of build errors, for tools like editors.
+
+use crate::runtime::cache::SourceCache;
+use crate::runtime::BuildError;
+use serde::{Deserialize, Serialize};
+
+/// A build error, located in its source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// The name of the source, like the path of a policy file.
+    pub source: String,
+    /// The start and end of the offending part of the source, as character offsets.
+    pub span: (usize, usize),
+    /// The line of the start of the span, starting at 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column of the start of the span, starting at 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic for an error, resolving its position using the sources of the build.
+    pub fn new(error: &BuildError, cache: &SourceCache) -> Self {
+        let location = error.source_location();
+        let span = error.span();
+
+        let position = cache
+            .get(&location)
+            .and_then(|source| source.get_offset_line(span.start))
+            .map(|(_, line, column)| (line + 1, column + 1));
+
+        Self {
+            source: location.name(),
+            span: (span.start, span.end),
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            message: error.message(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+
+    #[tokio::test]
+    async fn pattern_not_found() {
+        let mut builder = Builder::new();
+        let src = Ephemeral::new("test", "pattern a = string\npattern b = { name: c }\n");
+        builder.build(src.iter()).unwrap();
+        let errors = builder.finish().await.unwrap_err();
+
+        let diagnostics: Vec<_> = errors
+            .iter()
+            .map(|error| Diagnostic::new(error, builder.source_cache()))
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic {
+                source: "test".into(),
+                span: (39, 40),
+                line: Some(2),
+                column: Some(21),
+                message: "pattern not found: c".into(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Reporting evaluation results in the JUnit XML format, understood by most CI systems.
+
+use crate::lang::Severity;
+use crate::runtime::EvaluationResult;
+use std::fmt::Write;
+
+/// The outcome of a single test case.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// Not passed, with the reason.
+    Failure(String),
+    /// Not run, or only flagged, with an optional reason.
+    Skipped(Option<String>),
+    /// The test case couldn't be run.
+    Error(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct TestCase {
+    pub classname: String,
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+impl TestCase {
+    pub fn new<C: Into<String>, N: Into<String>>(classname: C, name: N, outcome: Outcome) -> Self {
+        Self {
+            classname: classname.into(),
+            name: name.into(),
+            outcome,
+        }
+    }
+
+    /// Create the test case of evaluating a pattern.
+    ///
+    /// An error is reported as failure, and a warning as skipped, so that it gets flagged without
+    /// failing the build.
+    pub fn from_result(name: &str, result: &EvaluationResult) -> Self {
+        let (severity, reason) = result.outcome();
+        let outcome = match severity {
+            Severity::None | Severity::Advice => Outcome::Passed,
+            Severity::Warning => Outcome::Skipped(Some(reason)),
+            Severity::Error => Outcome::Failure(reason),
+        };
+
+        let classname = match name.rsplit_once("::") {
+            Some((package, _)) => package,
+            None => name,
+        };
+
+        Self::new(classname, name, outcome)
+    }
+}
+
+/// A report, consisting of a single test suite.
+#[derive(Clone, Debug)]
+pub struct Report {
+    name: String,
+    cases: Vec<TestCase>,
+}
+
+impl Report {
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into(),
+            cases: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, case: TestCase) {
+        self.cases.push(case);
+    }
+
+    pub fn cases(&self) -> &[TestCase] {
+        &self.cases
+    }
+
+    fn count(&self, f: impl Fn(&Outcome) -> bool) -> usize {
+        self.cases.iter().filter(|case| f(&case.outcome)).count()
+    }
+
+    /// Render the report as XML document.
+    pub fn to_xml(&self) -> String {
+        let attributes = format!(
+            r#"tests="{}" failures="{}" errors="{}" skipped="{}""#,
+            self.cases.len(),
+            self.count(|o| matches!(o, Outcome::Failure(_))),
+            self.count(|o| matches!(o, Outcome::Error(_))),
+            self.count(|o| matches!(o, Outcome::Skipped(_))),
+        );
+
+        let mut xml = String::new();
+        // writing to a string never fails
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(xml, "<testsuites {attributes}>");
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" {attributes}>"#,
+            escape(&self.name)
+        );
+
+        for case in &self.cases {
+            let _ = write!(
+                xml,
+                r#"    <testcase name="{}" classname="{}""#,
+                escape(&case.name),
+                escape(&case.classname)
+            );
+            let _ = match &case.outcome {
+                Outcome::Passed => writeln!(xml, "/>"),
+                Outcome::Failure(reason) => {
+                    writeln!(xml, "><failure message=\"{}\"/></testcase>", escape(reason))
+                }
+                Outcome::Skipped(None) => writeln!(xml, "><skipped/></testcase>"),
+                Outcome::Skipped(Some(reason)) => {
+                    writeln!(xml, "><skipped message=\"{}\"/></testcase>", escape(reason))
+                }
+                Outcome::Error(reason) => {
+                    writeln!(xml, "><error message=\"{}\"/></testcase>", escape(reason))
+                }
+            };
+        }
+
+        let _ = writeln!(xml, "  </testsuite>");
+        let _ = writeln!(xml, "</testsuites>");
+        xml
+    }
+}
+
+/// Render the results of evaluating patterns, by their names, as JUnit XML report.
+pub fn to_junit<'r, N, I>(name: &str, results: I) -> String
+where
+    N: AsRef<str>,
+    I: IntoIterator<Item = (N, &'r EvaluationResult)>,
+{
+    let mut report = Report::new(name);
+    for (name, result) in results {
+        report.add(TestCase::from_result(name.as_ref(), result));
+    }
+    report.to_xml()
+}
+
+/// Escape text for use in XML attributes.
+///
+/// Characters which XML 1.0 doesn't allow, even as references, are left out.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // attribute values normalize whitespace, unless referenced
+            '\t' => escaped.push_str("&#9;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::EvalContext;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn failures_and_warnings() {
+        let mut builder = Builder::new();
+        builder
+            .build(
+                Ephemeral::new(
+                    "test",
+                    r#"
+pattern answer = 42
+pattern legacy = lang::deprecated-field<"image", "use <images> instead">
+"#,
+                )
+                .iter(),
+            )
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let mut results = Vec::new();
+        for (name, input) in [
+            ("test::answer", json!(42)),
+            ("test::answer", json!(43)),
+            ("test::legacy", json!({"image": "a"})),
+        ] {
+            let result = world
+                .evaluate(name, input, EvalContext::default())
+                .await
+                .unwrap();
+            results.push((name, result));
+        }
+
+        let xml = to_junit("policies", results.iter().map(|(n, r)| (*n, r)));
+
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(
+            xml.contains(
+                r#"<testsuite name="policies" tests="3" failures="1" errors="0" skipped="1">"#
+            ),
+            "{xml}"
+        );
+        assert!(
+            xml.contains(r#"<testcase name="test::answer" classname="test"/>"#),
+            "{xml}"
+        );
+        assert!(
+            xml.contains(r#"<testcase name="test::answer" classname="test"><failure message=""#),
+            "{xml}"
+        );
+        assert!(
+            xml.contains(
+                r#"<testcase name="test::legacy" classname="test"><skipped message="field &apos;image&apos; is deprecated: use &lt;images&gt; instead"/></testcase>"#
+            ),
+            "{xml}"
+        );
+        assert_eq!(xml.matches("<testcase ").count(), 3);
+        assert_eq!(xml.matches("</testsuites>").count(), 1);
+    }
+
+    #[test]
+    fn escape_invalid_characters() {
+        assert_eq!(
+            escape("a\tb\r\nc\u{0}d\u{1B}[0m\u{FFFF}é"),
+            "a&#9;b&#13;&#10;cd[0mé"
+        );
+    }
+}
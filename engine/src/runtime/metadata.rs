@@ -1,6 +1,6 @@
 use crate::lang;
 use crate::lang::{lir, Expr, PackageMeta, PatternMeta, SyntacticSugar, ValuePattern};
-use crate::runtime::{Example, PackagePath, Pattern, PatternName};
+use crate::runtime::{is_default, Example, PackagePath, Pattern, PatternName};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -238,6 +238,78 @@ pub enum PatternOrReference {
     Ref(PatternRef),
 }
 
+/// A catalog of all packages known to a world, and the patterns they contain.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Catalog {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<CatalogPackage>,
+}
+
+impl Catalog {
+    /// Find a pattern by its full path, like `list::all`.
+    pub fn get_pattern(&self, path: &str) -> Option<&CatalogPattern> {
+        self.packages
+            .iter()
+            .flat_map(|package| package.patterns.iter())
+            .find(|pattern| pattern.path == path)
+    }
+}
+
+/// A package entry of the [`Catalog`].
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct CatalogPackage {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Documentation,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<CatalogPattern>,
+}
+
+/// A pattern entry of a [`CatalogPackage`].
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct CatalogPattern {
+    pub name: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Arc<str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Documentation,
+    /// If the pattern is implemented natively, rather than in the policy language.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub function: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub unstable: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub deprecated: bool,
+}
+
+impl From<&PackageMetadata> for CatalogPackage {
+    fn from(package: &PackageMetadata) -> Self {
+        Self {
+            name: package.name.clone(),
+            documentation: package.documentation.clone(),
+            patterns: package
+                .patterns
+                .iter()
+                .filter_map(|pattern| {
+                    Some(CatalogPattern {
+                        name: pattern.name.clone()?,
+                        path: pattern.path.clone()?,
+                        parameters: pattern.parameters.clone(),
+                        documentation: pattern.metadata.documentation.clone(),
+                        function: matches!(
+                            pattern.inner,
+                            InnerPatternMetadata::Primordial(PrimordialPattern::Function(..))
+                        ),
+                        unstable: pattern.metadata.unstable,
+                        deprecated: pattern.metadata.is_deprecated(),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Errors when generating information.
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum Error {
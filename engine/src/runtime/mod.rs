@@ -1,10 +1,12 @@
 //! Policy evaluation runtime.
 //!
 //! All policies are parsed and compiled into a `World` used to evaluate policy decisions for different inputs.
-use crate::lang::lir::Bindings;
+use crate::lang::lir::{Bindings, InnerPattern};
 use crate::lang::parser::{Located, ParserError, SourceLocation, SourceSpan};
-use crate::lang::Severity;
-use crate::runtime::metadata::{PackageMetadata, PatternMetadata, ToMetadata, WorldLike};
+use crate::lang::{PrimordialPattern, Severity};
+use crate::runtime::metadata::{
+    Catalog, CatalogPackage, PackageMetadata, PatternMetadata, ToMetadata, WorldLike,
+};
 use crate::runtime::{cache::SourceCache, rationale::Rationale};
 use crate::value::RuntimeValue;
 use ariadne::{Label, Report, ReportKind};
@@ -15,7 +17,8 @@ use std::fmt::{Debug, Display, Formatter};
 use std::io;
 use std::ops::Deref;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 pub use crate::core::Example;
 pub use crate::lang::lir::Pattern;
@@ -23,10 +26,15 @@ pub use response::Response;
 
 pub mod cache;
 pub mod config;
+pub mod diagnostic;
+pub mod junit;
 pub mod metadata;
 pub mod monitor;
+pub mod profile;
 pub mod rationale;
+pub mod report;
 pub mod response;
+pub mod snapshot;
 pub mod sources;
 pub mod statistics;
 
@@ -35,6 +43,8 @@ pub use utils::is_default;
 
 mod trace;
 use crate::runtime::config::ConfigContext;
+use crate::runtime::profile::{Profile, Profiler};
+use crate::runtime::report::BuildReport;
 pub use trace::*;
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -45,6 +55,18 @@ pub enum BuildError {
     Parser(SourceLocation, ParserError),
     #[error("argument mismatch (@ {0}:{1:?})")]
     ArgumentMismatch(SourceLocation, SourceSpan),
+    #[error("pattern ({2}) already defined (@ {0}:{1:?})")]
+    DuplicatePattern(SourceLocation, SourceSpan, String),
+    #[error("environment variable ({3}) referenced by config ({2}) is not set")]
+    UnsetEnvironmentVariable(SourceLocation, SourceSpan, String, String),
+    #[error("pattern ({2}) can't be memoized: {3} (@ {0}:{1:?})")]
+    Memoize(SourceLocation, SourceSpan, String, String),
+    #[error("imported file ({2}) not found (@ {0}:{1:?})")]
+    ImportNotFound(SourceLocation, SourceSpan, String),
+    #[error("circular import ({2}) (@ {0}:{1:?})")]
+    CircularImport(SourceLocation, SourceSpan, String),
+    #[error("pattern ({2}) imported from both {3} and {4} (@ {0}:{1:?})")]
+    ConflictingImport(SourceLocation, SourceSpan, String, String, String),
 }
 
 impl BuildError {
@@ -53,6 +75,12 @@ impl BuildError {
             BuildError::PatternNotFound(loc, _, _) => loc.clone(),
             BuildError::Parser(loc, _) => loc.clone(),
             BuildError::ArgumentMismatch(loc, _) => loc.clone(),
+            BuildError::DuplicatePattern(loc, _, _) => loc.clone(),
+            BuildError::UnsetEnvironmentVariable(loc, _, _, _) => loc.clone(),
+            BuildError::Memoize(loc, _, _, _) => loc.clone(),
+            BuildError::ImportNotFound(loc, _, _) => loc.clone(),
+            BuildError::CircularImport(loc, _, _) => loc.clone(),
+            BuildError::ConflictingImport(loc, _, _, _, _) => loc.clone(),
         }
     }
 
@@ -61,6 +89,50 @@ impl BuildError {
             BuildError::PatternNotFound(_, span, _) => span.clone(),
             BuildError::Parser(_, err) => err.span(),
             BuildError::ArgumentMismatch(_, span) => span.clone(),
+            BuildError::DuplicatePattern(_, span, _) => span.clone(),
+            BuildError::UnsetEnvironmentVariable(_, span, _, _) => span.clone(),
+            BuildError::Memoize(_, span, _, _) => span.clone(),
+            BuildError::ImportNotFound(_, span, _) => span.clone(),
+            BuildError::CircularImport(_, span, _) => span.clone(),
+            BuildError::ConflictingImport(_, span, _, _, _) => span.clone(),
+        }
+    }
+
+    /// A description of the error, without its location.
+    pub fn message(&self) -> String {
+        match self {
+            BuildError::ArgumentMismatch(_, _) => "argument mismatch".to_string(),
+            BuildError::PatternNotFound(_, _, name) => {
+                format!("pattern not found: {name}")
+            }
+            BuildError::DuplicatePattern(_, _, name) => {
+                format!("pattern already defined: {name}")
+            }
+            BuildError::UnsetEnvironmentVariable(_, _, key, variable) => {
+                format!("environment variable {variable} (referenced by config {key}) is not set")
+            }
+            BuildError::Memoize(_, _, name, reason) => {
+                format!("pattern {name} can't be memoized: {reason}")
+            }
+            BuildError::ImportNotFound(_, _, path) => {
+                format!("imported file not found: {path}")
+            }
+            BuildError::CircularImport(_, _, cycle) => {
+                format!("circular import: {cycle}")
+            }
+            BuildError::ConflictingImport(_, _, name, first, second) => {
+                format!("pattern {name} is imported from both {first} and {second}")
+            }
+            BuildError::Parser(_, inner) => match inner.reason() {
+                SimpleReason::Unexpected => match inner.found() {
+                    Some(found) => format!("unexpected character found {found}"),
+                    None => "unexpected end of input".to_string(),
+                },
+                SimpleReason::Unclosed { span: _, delimiter } => {
+                    format!("unclosed delimiter {delimiter}")
+                }
+                SimpleReason::Custom(inner) => inner.clone(),
+            },
         }
     }
 }
@@ -93,21 +165,7 @@ impl<'c> ErrorPrinter<'c> {
                 source_id.clone(),
                 span.start,
             )
-            .with_label(Label::new(full_span).with_message(match error {
-                BuildError::ArgumentMismatch(_, _) => "argument mismatch".to_string(),
-                BuildError::PatternNotFound(_, _, name) => {
-                    format!("pattern not found: {name}")
-                }
-                BuildError::Parser(_, inner) => match inner.reason() {
-                    SimpleReason::Unexpected => {
-                        format!("unexpected character found {}", inner.found().unwrap())
-                    }
-                    SimpleReason::Unclosed { span: _, delimiter } => {
-                        format!("unclosed delimiter {delimiter}")
-                    }
-                    SimpleReason::Custom(inner) => inner.clone(),
-                },
-            }))
+            .with_label(Label::new(full_span).with_message(error.message()))
             .finish();
 
             let _ = report.write(self.cache, &mut w);
@@ -135,6 +193,9 @@ pub struct EvaluationResult {
     pub(crate) rationale: Arc<Rationale>,
     pub(crate) output: Output,
     pub(crate) trace: Option<TraceResult>,
+    pub(crate) profile: Option<Arc<Profile>>,
+    /// The severity reported for a failed match, unless the pattern overrides it.
+    pub(crate) unmatched_severity: Severity,
 }
 
 impl EvaluationResult {
@@ -150,28 +211,20 @@ impl EvaluationResult {
             rationale,
             output,
             trace: None,
+            profile: None,
+            unmatched_severity: Severity::Error,
         }
     }
 
     /// Get both the severity and the reason.
     pub fn outcome(&self) -> (Severity, String) {
-        // the evaluated severity
-        let mut severity = self.rationale.severity();
+        let severity = self.severity();
 
-        let reason;
-
-        if severity > Severity::None {
-            // a possible override severity
-            let override_severity = self.ty.metadata().reporting.severity;
-
-            if override_severity > Severity::None {
-                severity = override_severity;
-            }
-
-            reason = self.ty.metadata().reporting.explanation.clone();
+        let reason = if severity > Severity::None {
+            self.ty.metadata().reporting.explanation.clone()
         } else {
-            reason = None;
-        }
+            None
+        };
 
         let reason = reason.unwrap_or_else(|| self.rationale.reason());
 
@@ -189,6 +242,8 @@ impl EvaluationResult {
 
             if override_severity > Severity::None {
                 severity = override_severity;
+            } else if severity == Severity::Error {
+                severity = self.unmatched_severity;
             }
         }
 
@@ -222,6 +277,13 @@ impl EvaluationResult {
         self.trace
     }
 
+    /// The time spent per pattern, if the evaluation was profiled.
+    ///
+    /// See [`EvalContext::with_profiler`].
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_deref()
+    }
+
     #[allow(dead_code)]
     pub(crate) fn with_trace_result(&mut self, trace: TraceResult) {
         self.trace.replace(trace);
@@ -245,8 +307,10 @@ pub enum RuntimeError {
     #[error("remote client failed: {0}")]
     #[cfg(not(target_arch = "wasm32"))]
     RemoteClient(#[from] crate::client::Error),
-    #[error("recursion limit reached: {0}")]
-    RecursionLimit(usize),
+    #[error("recursion limit reached: {0}{}", display_cycle(.1))]
+    RecursionLimit(usize, Vec<PatternName>),
+    #[error("function call budget exceeded: {0}")]
+    BudgetExceeded(usize),
     #[error("no such path: {0}")]
     NoSuchPath(String),
 }
@@ -258,6 +322,7 @@ pub struct World {
     type_slots: Vec<Arc<Pattern>>,
 
     packages: HashMap<PackagePath, PackageMetadata>,
+    warnings: Vec<String>,
 }
 
 impl WorldLike for World {
@@ -278,9 +343,16 @@ impl World {
             types,
             type_slots,
             packages,
+            warnings: Vec::new(),
         }
     }
 
+    /// Attach the warnings gathered while building the world.
+    pub(crate) fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
     pub fn all(&self) -> Vec<(PatternName, Arc<Pattern>)> {
         let mut all = Vec::new();
         for (k, slot) in &self.types {
@@ -300,13 +372,25 @@ impl World {
         mut ctx: EvalContext,
     ) -> Result<EvaluationResult, RuntimeError> {
         ctx.merge_config(&self.config);
+        let value = if ctx.options.detect_timestamps {
+            Arc::new((*value).clone().detect_timestamps())
+        } else {
+            value
+        };
         let path = PatternName::from(path.into());
         let slot = self.types.get(&path);
+        let unmatched_severity = ctx.options.unmatched_severity;
         let ctx = ExecutionContext::new(&ctx);
         if let Some(slot) = slot {
             let ty = self.type_slots[*slot].clone();
             let bindings = Bindings::default();
-            ty.evaluate(value.clone(), ctx, &bindings, self).await
+            let profiler = ctx.profiler().cloned();
+            let mut result = ty.evaluate(value.clone(), ctx, &bindings, self).await?;
+            if let Some(profiler) = profiler {
+                result.profile = Some(Arc::new(profiler.profile()));
+            }
+            result.unmatched_severity = unmatched_severity;
+            Ok(result)
         } else {
             Err(RuntimeError::NoSuchPattern(path))
         }
@@ -322,10 +406,90 @@ impl World {
         self.evaluate_nocopy(path, value, ctx).await
     }
 
+    /// All patterns with a name matching the glob expression, sorted by name.
+    ///
+    /// See [`PatternName::matches_glob`] for the glob syntax.
+    pub fn patterns_matching(&self, glob: &str) -> Vec<(PatternName, Arc<Pattern>)> {
+        let mut result: Vec<_> = self
+            .all()
+            .into_iter()
+            .filter(|(name, _)| name.matches_glob(glob))
+            .collect();
+        result.sort_unstable_by(|(l, _), (r, _)| l.cmp(r));
+        result
+    }
+
+    /// All natively implemented functions with their parameter names, sorted by name.
+    ///
+    /// Patterns written in the policy language are not included, neither are internal functions.
+    pub fn functions(&self) -> Vec<(PatternName, Vec<String>)> {
+        let mut result: Vec<_> = self
+            .all()
+            .into_iter()
+            .filter(|(_, pattern)| {
+                matches!(
+                    pattern.inner(),
+                    InnerPattern::Primordial(PrimordialPattern::Function(_, _, function))
+                        if !function.internal()
+                )
+            })
+            .map(|(name, pattern)| {
+                let parameters = pattern.parameters().iter().map(|p| p.to_string()).collect();
+                (name, parameters)
+            })
+            .collect();
+        result.sort_unstable_by(|(l, _), (r, _)| l.cmp(r));
+        result
+    }
+
+    /// A summary of the world, for validating policies in CI for instance.
+    pub fn build_report(&self) -> BuildReport {
+        let mut report = BuildReport {
+            packages: self
+                .packages
+                .values()
+                .filter(|package| !package.name.is_empty())
+                .count(),
+            warnings: self.warnings.clone(),
+            ..Default::default()
+        };
+
+        for pattern in &self.type_slots {
+            match pattern.inner() {
+                InnerPattern::Primordial(PrimordialPattern::Function(..)) => report.functions += 1,
+                // the built-in primitive types, like `integer`
+                InnerPattern::Primordial(_) => continue,
+                _ => report.patterns += 1,
+            }
+            if pattern.metadata().documentation.is_none() {
+                report.undocumented += 1;
+            }
+            if pattern.metadata().is_deprecated() {
+                report.deprecated += 1;
+            }
+        }
+
+        report
+    }
+
     pub fn get_package_meta<S: Into<PackagePath>>(&self, name: S) -> Option<PackageMetadata> {
         self.packages.get(&name.into()).cloned()
     }
 
+    /// A catalog of all packages, including their patterns and documentation.
+    pub fn catalog(&self) -> Catalog {
+        let mut packages: Vec<_> = self
+            .packages
+            .values()
+            // skip the root, it doesn't have a name, nor patterns
+            .filter(|package| !package.name.is_empty())
+            .map(CatalogPackage::from)
+            .collect();
+        packages.sort_unstable_by(|l, r| l.name.cmp(&r.name));
+
+        Catalog { packages }
+    }
+
     pub fn get_pattern_meta<S: Into<PatternName>>(&self, name: S) -> Option<PatternMetadata> {
         let name = name.into();
         if let Some(slot) = self.types.get(&name) {
@@ -406,6 +570,35 @@ impl PatternName {
     pub fn package(&self) -> Option<PackagePath> {
         self.package.clone()
     }
+
+    /// Check if the fully qualified name matches a glob expression.
+    ///
+    /// A `*` matches any sequence of characters within a segment, while `**` also spans
+    /// package separators. So `foo::*` matches `foo::bar`, but not `foo::bar::baz`, which is
+    /// matched by `foo::**`.
+    pub fn matches_glob(&self, glob: &str) -> bool {
+        glob_match(glob.as_bytes(), self.as_type_str().as_bytes())
+    }
+}
+
+fn glob_match(glob: &[u8], name: &[u8]) -> bool {
+    match glob {
+        [] => name.is_empty(),
+        [b'*', b'*', rest @ ..] => (0..=name.len()).any(|i| glob_match(rest, &name[i..])),
+        [b'*', rest @ ..] => {
+            for i in 0..=name.len() {
+                if glob_match(rest, &name[i..]) {
+                    return true;
+                }
+                // a single star doesn't cross segments
+                if name.get(i) == Some(&b':') {
+                    break;
+                }
+            }
+            false
+        }
+        [c, rest @ ..] => name.first() == Some(c) && glob_match(rest, &name[1..]),
+    }
 }
 
 impl<T> From<T> for PatternName
@@ -630,14 +823,43 @@ impl Default for EvalContext {
     }
 }
 
+fn display_cycle(cycle: &[PatternName]) -> String {
+    if cycle.is_empty() {
+        String::new()
+    } else {
+        let cycle: Vec<_> = cycle.iter().map(PatternName::as_type_str).collect();
+        format!(", cycle: {}", cycle.join(" -> "))
+    }
+}
+
+/// The named patterns currently being evaluated, the most recent one first.
+struct PatternStack {
+    name: PatternName,
+    parent: Option<Arc<PatternStack>>,
+}
+
 /// A context when executing an evaluation step
 pub struct ExecutionContext<'c> {
     /// The context of the overall evaluation
     eval: &'c EvalContext,
     /// the recursion level
     remaining_recursions: usize,
+    /// the number of function calls so far, shared by the whole evaluation
+    function_calls: Arc<AtomicUsize>,
+    /// the named patterns being evaluated
+    patterns: Option<Arc<PatternStack>>,
+    /// results of memoized patterns, shared by the whole evaluation
+    memo: Arc<Mutex<HashMap<MemoKey, EvaluationResult>>>,
+    /// the time spent per pattern, collected for this evaluation only
+    profiler: Option<Profiler>,
 }
 
+/// A memoized result is identified by the pattern and the typed key of its input.
+///
+/// The canonical JSON of the input isn't enough, as values a pattern may tell apart, like a
+/// timestamp and its string, serialize the same.
+pub(crate) type MemoKey = (PatternName, String);
+
 impl Deref for ExecutionContext<'_> {
     type Target = EvalContext;
 
@@ -651,6 +873,10 @@ impl<'c> ExecutionContext<'c> {
         Self {
             eval,
             remaining_recursions: eval.options.max_recursions,
+            function_calls: Default::default(),
+            patterns: None,
+            memo: Default::default(),
+            profiler: eval.options.profile.then(Profiler::new),
         }
     }
 
@@ -664,18 +890,101 @@ impl<'c> ExecutionContext<'c> {
         match self.remaining_recursions == 0 {
             true => Err(RuntimeError::RecursionLimit(
                 self.eval.options.max_recursions,
+                self.cycle(),
             )),
             false => Ok(Self {
                 eval: self.eval,
                 remaining_recursions: self.remaining_recursions - 1,
+                function_calls: self.function_calls.clone(),
+                patterns: self.patterns.clone(),
+                memo: self.memo.clone(),
+                profiler: self.profiler.clone(),
             }),
         }
     }
+
+    /// Record entering the evaluation of a pattern, which is tracked if it has a name.
+    pub(crate) fn enter(mut self, name: Option<PatternName>) -> Self {
+        if let Some(name) = name {
+            self.patterns = Some(Arc::new(PatternStack {
+                name,
+                parent: self.patterns.take(),
+            }));
+        }
+        self
+    }
+
+    /// The most recent cycle of named patterns, starting and ending with the same pattern.
+    ///
+    /// Empty if no pattern was entered repeatedly.
+    fn cycle(&self) -> Vec<PatternName> {
+        let mut names = Vec::new();
+        let mut current = self.patterns.as_deref();
+        while let Some(frame) = current {
+            names.push(&frame.name);
+            if names.len() > 1 && names[0] == &frame.name {
+                return names.into_iter().rev().cloned().collect();
+            }
+            current = frame.parent.as_deref();
+        }
+        Vec::new()
+    }
+
+    /// Account for a function call, or fail if the evaluation exceeded its function call budget.
+    ///
+    /// **NOTE:** This must be called every time before a function is being called.
+    pub fn count_function_call(&self) -> Result<(), RuntimeError> {
+        let calls = self.function_calls.fetch_add(1, Ordering::Relaxed) + 1;
+        match self.eval.options.max_function_calls {
+            Some(max) if calls > max => Err(RuntimeError::BudgetExceeded(max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// The result of an earlier evaluation of a memoized pattern.
+    pub(crate) fn memoized(&self, key: &MemoKey) -> Option<EvaluationResult> {
+        self.memo.lock().unwrap().get(key).cloned()
+    }
+
+    /// A handle for storing results of memoized patterns, usable after the context was consumed.
+    pub(crate) fn memo(&self) -> Arc<Mutex<HashMap<MemoKey, EvaluationResult>>> {
+        self.memo.clone()
+    }
+
+    /// The profiler of the evaluation, if profiling is enabled.
+    pub(crate) fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EvalOptions {
     pub max_recursions: usize,
+    /// The maximum number of function calls a single evaluation may perform, unlimited if `None`.
+    pub max_function_calls: Option<usize>,
+    /// The severity reported when the evaluated pattern doesn't match, defaults to
+    /// [`Severity::Error`].
+    ///
+    /// A severity set through the pattern's metadata takes precedence.
+    pub unmatched_severity: Severity,
+    /// Stop evaluating the terms of `lang::and` and the items of `list::all` at the first failing
+    /// one, and the terms of `lang::or`, the items of `list::any` and the values of
+    /// `lang::any-value` at the first satisfied one, defaults to `false`.
+    ///
+    /// This saves evaluating terms which can't change the outcome anymore, at the cost of a less
+    /// complete rationale: skipped terms are missing from it.
+    pub short_circuit: bool,
+    /// Convert all strings of the input which are valid RFC 3339 timestamps into timestamp values
+    /// before evaluating it, defaults to `false`.
+    ///
+    /// The `string` pattern and string literals still match those values, but functions reading
+    /// the text of a string, like the ones of the `string` package, don't accept them anymore.
+    pub detect_timestamps: bool,
+    /// Collect the time spent per pattern, attaching it to the [`EvaluationResult`], defaults to
+    /// `false`.
+    ///
+    /// Every evaluation is profiled on its own, use [`Profile::merge`] for aggregating them.
+    pub profile: bool,
 }
 
 impl EvalOptions {
@@ -697,7 +1006,15 @@ impl EvalOptions {
             _ => Self::DEFAULT_MAX_RECURSIONS,
         };
 
-        Self { max_recursions }
+        let max_function_calls = std::env::var("SEEDWING_FUNCTION_CALL_BUDGET")
+            .ok()
+            .and_then(|v| v.parse().ok());
+
+        Self {
+            max_recursions,
+            max_function_calls,
+            ..Default::default()
+        }
     }
 
     /// Create a new instance.
@@ -713,6 +1030,11 @@ impl Default for EvalOptions {
     fn default() -> Self {
         Self {
             max_recursions: Self::DEFAULT_MAX_RECURSIONS,
+            max_function_calls: None,
+            unmatched_severity: Severity::Error,
+            short_circuit: false,
+            detect_timestamps: false,
+            profile: false,
         }
     }
 }
@@ -744,6 +1066,14 @@ impl EvalContext {
         }
     }
 
+    /// Enable profiling, attaching the time spent per pattern to the [`EvaluationResult`].
+    ///
+    /// See [`EvalOptions::profile`].
+    pub fn with_profiler(mut self) -> Self {
+        self.options.profile = true;
+        self
+    }
+
     pub fn config(&self) -> &ConfigContext {
         &self.config
     }
@@ -755,13 +1085,24 @@ impl EvalContext {
 
 #[cfg(test)]
 pub mod testutil {
+    use crate::core::{Function, FunctionEvaluationResult};
     use crate::data::DirectoryDataSource;
     use crate::lang::builder::Builder;
+    use crate::lang::lir::Bindings;
+    use crate::lang::{hir, Severity};
+    use crate::package::Package;
+    use crate::runtime::config::ConfigContext;
     use crate::runtime::sources::Ephemeral;
     use crate::runtime::EvalContext;
     use crate::runtime::EvaluationResult;
+    use crate::runtime::{EvalOptions, ExecutionContext, Output, PackagePath, RuntimeError, World};
     use crate::value::RuntimeValue;
+    use std::future::Future;
     use std::path::{Path, PathBuf};
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
 
     pub(crate) async fn test_pattern<V>(pattern: &str, value: V) -> EvaluationResult
     where
@@ -784,6 +1125,97 @@ pub mod testutil {
         evaluate(src, value).await
     }
 
+    /// Evaluate a pattern, with the functions of an additional package available to it.
+    pub(crate) async fn evaluate_with_package<V>(
+        package: Package,
+        pattern: &str,
+        value: V,
+        options: EvalOptions,
+    ) -> EvaluationResult
+    where
+        V: Into<RuntimeValue>,
+    {
+        init_logger();
+        let src = Ephemeral::new("test", format!("pattern test-pattern = {pattern}"));
+        let mut world = hir::World::new();
+        world.add_package(package);
+        world.build(src.iter()).unwrap();
+        let runtime = world.lower().unwrap().lower().unwrap();
+
+        let ctx = EvalContext::new_with_config(ConfigContext::default(), options);
+        runtime
+            .evaluate("test::test-pattern", value, ctx)
+            .await
+            .unwrap()
+    }
+
+    /// Function counting its invocations, satisfied by the integer `42` only.
+    ///
+    /// Being a function, it is evaluated after literal terms.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct Instrumented {
+        calls: Arc<AtomicUsize>,
+        memoizable: bool,
+        delay: Option<Duration>,
+    }
+
+    impl Instrumented {
+        /// Declare the function deterministic, so that patterns using it can be memoized.
+        pub(crate) fn memoizable(self) -> Self {
+            Self {
+                memoizable: true,
+                ..self
+            }
+        }
+
+        /// Delay each invocation by the given duration.
+        pub(crate) fn delayed(self, delay: Duration) -> Self {
+            Self {
+                delay: Some(delay),
+                ..self
+            }
+        }
+
+        /// The number of invocations so far.
+        pub(crate) fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+
+        /// A package `instrumented`, providing the function as `term`.
+        pub(crate) fn package(&self) -> Package {
+            let mut pkg = Package::new(PackagePath::from_parts(vec!["instrumented"]));
+            pkg.register_function("term".into(), self.clone());
+            pkg
+        }
+    }
+
+    impl Function for Instrumented {
+        fn deterministic(&self) -> bool {
+            self.memoizable
+        }
+
+        fn call<'v>(
+            &'v self,
+            input: Arc<RuntimeValue>,
+            _ctx: ExecutionContext<'v>,
+            _bindings: &'v Bindings,
+            _world: &'v World,
+        ) -> Pin<Box<dyn Future<Output = Result<FunctionEvaluationResult, RuntimeError>> + 'v>>
+        {
+            Box::pin(async move {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                if let Some(delay) = self.delay {
+                    tokio::time::sleep(delay).await;
+                }
+                if input.try_get_integer() == Some(42) {
+                    Ok(Output::Identity.into())
+                } else {
+                    Ok(Severity::Error.into())
+                }
+            })
+        }
+    }
+
     async fn evaluate<V>(src: Ephemeral, value: V) -> EvaluationResult
     where
         V: Into<RuntimeValue>,
@@ -889,6 +1321,53 @@ mod test {
         assert!(matches!(result, Ok(_)));
     }
 
+    #[tokio::test]
+    async fn git_sources() {
+        use crate::runtime::sources::Git;
+        use std::process::Command;
+
+        let base = env::temp_dir().join(format!("seedwing-git-source-{}", std::process::id()));
+        let fixture = base.join("fixture");
+        std::fs::create_dir_all(fixture.join("foo")).unwrap();
+        std::fs::write(fixture.join("foo/bar.dog"), "pattern bar = 42").unwrap();
+        std::fs::write(fixture.join("baz.dog"), "pattern baz = \"baz\"").unwrap();
+
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&fixture)
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        git(&["init", "--quiet"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "policies"]);
+
+        let src = Git::new(fixture.to_string_lossy())
+            .with_cache_dir(base.join("cache"))
+            .fetch()
+            .unwrap();
+
+        let mut builder = Builder::new();
+        let _result = builder.build(src.iter());
+        let world = builder.finish().await.unwrap();
+        std::fs::remove_dir_all(&base).unwrap();
+
+        let result = world
+            .evaluate("foo::bar::bar", 42, EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+        let result = world
+            .evaluate("baz::baz", "baz", EvalContext::default())
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+    }
+
     #[tokio::test]
     async fn evaluate_function() {
         let result = testutil::test_pattern(
@@ -1068,6 +1547,128 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn catalog() {
+        let mut builder = Builder::new();
+        let src = Ephemeral::new("foo::bar", "pattern bob<name> = { name: name }");
+        builder.build(src.iter()).unwrap();
+
+        let world = builder.finish().await.unwrap();
+        let catalog = world.catalog();
+
+        let all = catalog.get_pattern("list::all").unwrap();
+        assert_eq!(all.name, "all");
+        assert_eq!(all.parameters, vec![Arc::<str>::from("pattern")]);
+        assert!(all.function);
+        assert!(all.documentation.is_some());
+
+        let bob = catalog.get_pattern("foo::bar::bob").unwrap();
+        assert_eq!(bob.parameters, vec![Arc::<str>::from("name")]);
+        assert!(!bob.function);
+
+        assert!(catalog.packages.iter().any(|p| p.name == "list"));
+        assert!(catalog.packages.iter().all(|p| !p.name.is_empty()));
+    }
+
+    #[test]
+    fn pattern_name_glob() {
+        let name = PatternName::from("foo::bar::baz");
+
+        assert!(name.matches_glob("foo::bar::baz"));
+        assert!(name.matches_glob("foo::bar::*"));
+        assert!(name.matches_glob("foo::*::baz"));
+        assert!(name.matches_glob("foo::bar::b*"));
+        assert!(name.matches_glob("foo::**"));
+        assert!(name.matches_glob("**::baz"));
+        assert!(name.matches_glob("**"));
+
+        assert!(!name.matches_glob("foo::*"));
+        assert!(!name.matches_glob("*"));
+        assert!(!name.matches_glob("foo::bar"));
+        assert!(!name.matches_glob("foo::bar::baz::*"));
+        assert!(!name.matches_glob("bar::**"));
+    }
+
+    #[tokio::test]
+    async fn patterns_matching() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("foo", "pattern a\npattern b").iter())
+            .unwrap();
+        builder
+            .build(Ephemeral::new("foo::bar", "pattern c").iter())
+            .unwrap();
+        builder
+            .build(Ephemeral::new("baz", "pattern d").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let names = |glob| {
+            world
+                .patterns_matching(glob)
+                .into_iter()
+                .map(|(name, _)| name.as_type_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("foo::*"), vec!["foo::a", "foo::b"]);
+        assert_eq!(names("foo::**"), vec!["foo::a", "foo::b", "foo::bar::c"]);
+        assert_eq!(names("*::c"), Vec::<String>::new());
+        assert_eq!(names("**::c"), vec!["foo::bar::c"]);
+    }
+
+    #[tokio::test]
+    async fn functions() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("foo", "pattern bob<name> = { name: name }").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let functions = world.functions();
+        let parameters = |name: &str| {
+            functions
+                .iter()
+                .find(|(n, _)| n.as_type_str() == name)
+                .map(|(_, parameters)| parameters.clone())
+        };
+
+        assert_eq!(parameters("base64::base64"), Some(vec![]));
+        assert_eq!(parameters("list::all"), Some(vec!["pattern".to_string()]));
+        assert_eq!(parameters("foo::bob"), None);
+        assert_eq!(parameters("data::literal"), None);
+    }
+
+    #[tokio::test]
+    async fn build_report() {
+        let baseline = Builder::new().finish().await.unwrap().build_report();
+
+        let mut builder = Builder::new();
+        let src = Ephemeral::new(
+            "foo",
+            r#"
+/// A documented pattern.
+pattern documented = string
+
+pattern undocumented = integer
+"#,
+        );
+        builder.build(src.iter()).unwrap();
+        let report = builder.finish().await.unwrap().build_report();
+
+        assert_eq!(report.patterns, baseline.patterns + 2);
+        assert_eq!(report.functions, baseline.functions);
+        assert_eq!(report.packages, baseline.packages + 1);
+        assert_eq!(report.undocumented, baseline.undocumented + 1);
+        assert_eq!(report.deprecated, baseline.deprecated);
+        assert!(report
+            .warnings
+            .contains(&"foo::undocumented is not documented".to_string()));
+        assert!(!report
+            .warnings
+            .contains(&"foo::documented is not documented".to_string()));
+    }
+
     #[test]
     fn split_name() {
         assert_eq!(PackagePath::root().split_name(), None);
@@ -1082,6 +1683,65 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn profile_patterns() {
+        let mut builder = Builder::new();
+        builder
+            .build(
+                Ephemeral::new(
+                    "test",
+                    r#"
+pattern outer = list::all<inner>
+pattern inner = string::length($(self > 2))
+"#,
+                )
+                .iter(),
+            )
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let result = runtime
+            .evaluate(
+                "test::outer",
+                json!(["foo", "bar", "baz"]),
+                EvalContext::default().with_profiler(),
+            )
+            .await
+            .unwrap();
+        assert_satisfied!(&result);
+
+        let profile = result.profile().unwrap();
+        let outer = profile.get(&"test::outer".into()).unwrap();
+        let inner = profile.get(&"test::inner".into()).unwrap();
+        assert_eq!(outer.calls, 1);
+        assert_eq!(inner.calls, 3);
+        assert!(inner.total > std::time::Duration::ZERO);
+        assert!(outer.total >= inner.total);
+
+        // every evaluation is profiled on its own
+        let second = runtime
+            .evaluate(
+                "test::outer",
+                json!(["foo"]),
+                EvalContext::default().with_profiler(),
+            )
+            .await
+            .unwrap();
+        let second = second.profile().unwrap();
+        assert_eq!(second.get(&"test::inner".into()).unwrap().calls, 1);
+
+        let mut merged = profile.clone();
+        merged.merge(second);
+        assert_eq!(merged.get(&"test::outer".into()).unwrap().calls, 2);
+        assert_eq!(merged.get(&"test::inner".into()).unwrap().calls, 4);
+
+        let result = runtime
+            .evaluate("test::outer", json!(["foo"]), EvalContext::default())
+            .await
+            .unwrap();
+        assert!(result.profile().is_none());
+    }
+
     #[tokio::test]
     async fn fail_infinite_recursion() {
         let mut builder = Builder::new();
@@ -1096,11 +1756,120 @@ mod test {
         assert!(matches!(
             result,
             Err(RuntimeError::RecursionLimit(
-                EvalOptions::DEFAULT_MAX_RECURSIONS
+                EvalOptions::DEFAULT_MAX_RECURSIONS,
+                _
             ))
         ));
     }
 
+    #[tokio::test]
+    async fn fail_function_call_budget() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern foo = list::all<lang::not-empty>").iter())
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let eval = |max_function_calls| {
+            let ctx = EvalContext::new_with_config(
+                ConfigContext::default(),
+                EvalOptions {
+                    max_function_calls,
+                    ..Default::default()
+                },
+            );
+            runtime.evaluate("test::foo", json!(["a", "b", "c", "d", "e"]), ctx)
+        };
+
+        // one call for list::all, and one for each item
+        assert_satisfied!(eval(None).await.unwrap());
+        assert_satisfied!(eval(Some(6)).await.unwrap());
+        assert!(matches!(
+            eval(Some(5)).await,
+            Err(RuntimeError::BudgetExceeded(5))
+        ));
+    }
+
+    #[tokio::test]
+    async fn unmatched_severity() {
+        let mut builder = Builder::new();
+        builder
+            .build(
+                Ephemeral::new(
+                    "test",
+                    r#"
+pattern foo = 42
+
+#[reason("not 42")]
+pattern bar = 42
+"#,
+                )
+                .iter(),
+            )
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let eval = |path, unmatched_severity| {
+            let ctx = EvalContext::new_with_config(
+                ConfigContext::default(),
+                EvalOptions {
+                    unmatched_severity,
+                    ..Default::default()
+                },
+            );
+            runtime.evaluate(path, json!(41), ctx)
+        };
+
+        let result = eval("test::foo", Severity::Error).await.unwrap();
+        assert_eq!(result.severity(), Severity::Error);
+        assert_not_satisfied!(result);
+
+        let result = eval("test::foo", Severity::Warning).await.unwrap();
+        assert_eq!(result.severity(), Severity::Warning);
+        assert_eq!(result.outcome().0, Severity::Warning);
+        assert_satisfied!(result);
+
+        // the pattern's own severity wins
+        let result = eval("test::bar", Severity::Warning).await.unwrap();
+        assert_eq!(result.outcome(), (Severity::Error, "not 42".to_string()));
+        assert_not_satisfied!(result);
+
+        // a match stays a match
+        let result = runtime
+            .evaluate("test::foo", json!(42), EvalContext::default())
+            .await
+            .unwrap();
+        assert_eq!(result.severity(), Severity::None);
+    }
+
+    #[tokio::test]
+    async fn detect_timestamps() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern foo = string").iter())
+            .unwrap();
+        let runtime = builder.finish().await.unwrap();
+
+        let eval = |detect_timestamps| {
+            let ctx = EvalContext::new_with_config(
+                ConfigContext::default(),
+                EvalOptions {
+                    detect_timestamps,
+                    ..Default::default()
+                },
+            );
+            runtime.evaluate("test::foo", json!("2023-03-01T12:30:00Z"), ctx)
+        };
+
+        let result = eval(false).await.unwrap();
+        assert!(result.input().is_string());
+        assert_satisfied!(result);
+
+        let result = eval(true).await.unwrap();
+        assert!(result.input().is_timestamp());
+        assert_satisfied!(result);
+    }
+
     #[tokio::test]
     async fn fail_circular_dependency() {
         let mut builder = Builder::new();
@@ -1122,11 +1891,21 @@ pattern bar = foo
             .evaluate("test::foo", RuntimeValue::Null, EvalContext::default())
             .await;
 
+        let err = result.unwrap_err();
         assert!(matches!(
-            result,
-            Err(RuntimeError::RecursionLimit(
-                EvalOptions::DEFAULT_MAX_RECURSIONS
-            ))
+            err,
+            RuntimeError::RecursionLimit(EvalOptions::DEFAULT_MAX_RECURSIONS, _)
         ));
+
+        let msg = err.to_string();
+        assert!(msg.contains("test::foo"), "{msg}");
+        assert!(msg.contains("test::bar"), "{msg}");
+        match err {
+            RuntimeError::RecursionLimit(_, cycle) => {
+                assert_eq!(cycle.len(), 3, "{cycle:?}");
+                assert_eq!(cycle.first(), cycle.last());
+            }
+            _ => unreachable!(),
+        }
     }
 }
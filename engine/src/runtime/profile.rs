@@ -0,0 +1,77 @@
+//! Profiling of evaluations.
+use crate::runtime::PatternName;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Time spent evaluating a single pattern.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PatternProfile {
+    /// The number of times the pattern was evaluated.
+    pub calls: usize,
+    /// The overall time spent evaluating the pattern, including the patterns it references.
+    pub total: Duration,
+}
+
+/// The time spent per pattern during an evaluation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    patterns: HashMap<PatternName, PatternProfile>,
+}
+
+impl Profile {
+    pub fn get(&self, name: &PatternName) -> Option<&PatternProfile> {
+        self.patterns.get(name)
+    }
+
+    /// All profiled patterns, slowest first.
+    pub fn sorted(&self) -> Vec<(&PatternName, &PatternProfile)> {
+        let mut result: Vec<_> = self.patterns.iter().collect();
+        result.sort_by(|(ln, lp), (rn, rp)| rp.total.cmp(&lp.total).then_with(|| ln.cmp(rn)));
+        result
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Add the times of another profile, for aggregating the profiles of several evaluations.
+    pub fn merge(&mut self, other: &Profile) {
+        for (name, profile) in &other.patterns {
+            let entry = self.patterns.entry(name.clone()).or_default();
+            entry.calls += profile.calls;
+            entry.total += profile.total;
+        }
+    }
+}
+
+/// Collects the [`Profile`] of a single evaluation.
+///
+/// Time is attributed to named patterns only, and includes the time of all patterns evaluated
+/// as part of them. Note that measuring time is not supported on every `wasm32` target.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Profiler {
+    profile: Arc<Mutex<Profile>>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub(crate) fn record(&self, name: PatternName, elapsed: Duration) {
+        if let Ok(mut profile) = self.profile.lock() {
+            let entry = profile.patterns.entry(name).or_default();
+            entry.calls += 1;
+            entry.total += elapsed;
+        }
+    }
+
+    /// A snapshot of the profile collected so far.
+    pub fn profile(&self) -> Profile {
+        self.profile
+            .lock()
+            .map(|profile| profile.clone())
+            .unwrap_or_default()
+    }
+}
@@ -26,6 +26,10 @@ pub enum Rationale {
         supporting: Arc<Vec<EvaluationResult>>,
     },
     Bound(Arc<Rationale>, Bindings),
+    /// A user supplied label, tagging the inner rationale.
+    Labeled(Arc<str>, Arc<Rationale>),
+    /// A user or function supplied reason, replacing the one of the inner rationale.
+    Reason(Arc<str>, Arc<Rationale>),
 }
 
 impl Rationale {
@@ -55,6 +59,7 @@ impl Rationale {
             } => *severity,
             Rationale::Chain(terms) => terms.iter().collect(),
             Rationale::Bound(inner, _) => inner.severity(),
+            Rationale::Labeled(_, inner) | Rationale::Reason(_, inner) => inner.severity(),
         }
     }
 
@@ -120,6 +125,8 @@ impl Rationale {
                 .to_string(),
             },
             Rationale::Bound(inner, _) => inner.reason(),
+            Rationale::Labeled(label, inner) => format!("{label}: {}", inner.reason()),
+            Rationale::Reason(reason, _) => reason.to_string(),
         }
     }
 }
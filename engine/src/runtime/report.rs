@@ -0,0 +1,37 @@
+//! Summary of a compiled world.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Counts of the content of a world, and the warnings raised while building it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildReport {
+    /// Patterns written in the policy language.
+    pub patterns: usize,
+    /// Natively implemented functions.
+    pub functions: usize,
+    pub packages: usize,
+    /// Patterns and functions lacking documentation.
+    pub undocumented: usize,
+    /// Patterns and functions being deprecated.
+    pub deprecated: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl Display for BuildReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "patterns: {}", self.patterns)?;
+        writeln!(f, "functions: {}", self.functions)?;
+        writeln!(f, "packages: {}", self.packages)?;
+        writeln!(f, "undocumented: {}", self.undocumented)?;
+        writeln!(f, "deprecated: {}", self.deprecated)?;
+        if !self.warnings.is_empty() {
+            writeln!(f, "warnings:")?;
+            for warning in &self.warnings {
+                writeln!(f, "  {warning}")?;
+            }
+        }
+        Ok(())
+    }
+}
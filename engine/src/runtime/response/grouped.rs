@@ -0,0 +1,150 @@
+use crate::{
+    lang::Severity,
+    runtime::{response::Name, PatternName, Response},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The maximum number of example inputs recorded for a pattern.
+const MAX_EXAMPLES: usize = 3;
+
+/// The failures which originated from a single pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatternFailures {
+    /// The name of the pattern, missing for failures outside of any named pattern.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<PatternName>,
+    /// The highest severity of the failures.
+    #[serde(default)]
+    pub severity: Severity,
+    /// The number of failures.
+    pub count: usize,
+    /// The distinct reasons of the failures.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reasons: Vec<String>,
+    /// Some of the distinct inputs which failed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Value>,
+}
+
+impl Response {
+    /// Group the failed reasons by the pattern they originated from.
+    ///
+    /// This considers the same reasons as the [`Collector`](super::Collector) does. Each one is
+    /// attributed to its own pattern, or the closest named pattern above it. Groups are ordered
+    /// by their first occurrence in the tree.
+    pub fn group_by_pattern(&self, severity: Severity) -> Vec<PatternFailures> {
+        let mut groups = Vec::new();
+        self.group_into(None, severity, &mut groups);
+        groups
+    }
+
+    fn group_into<'r>(
+        &'r self,
+        pattern: Option<&'r PatternName>,
+        severity: Severity,
+        groups: &mut Vec<PatternFailures>,
+    ) {
+        if self.satisfied(severity) {
+            return;
+        }
+
+        let pattern = match &self.name {
+            Name::Pattern(Some(name)) => Some(name),
+            _ => pattern,
+        };
+
+        if self.authoritative || self.rationale.iter().all(|r| r.satisfied(severity)) {
+            let idx = match groups.iter().position(|g| g.pattern.as_ref() == pattern) {
+                Some(idx) => idx,
+                None => {
+                    groups.push(PatternFailures {
+                        pattern: pattern.cloned(),
+                        ..Default::default()
+                    });
+                    groups.len() - 1
+                }
+            };
+            let group = &mut groups[idx];
+
+            group.count += 1;
+            group.severity = group.severity.max(self.severity);
+            if !self.reason.is_empty() && !group.reasons.contains(&self.reason) {
+                group.reasons.push(self.reason.clone());
+            }
+            if group.examples.len() < MAX_EXAMPLES && !group.examples.contains(&self.input) {
+                group.examples.push(self.input.clone());
+            }
+            return;
+        }
+
+        for r in &self.rationale {
+            r.group_into(pattern, severity, groups);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_not_satisfied;
+    use crate::runtime::testutil::test_common;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn group_same_pattern() {
+        let result = test_common(
+            r#"
+pattern test = {
+    first: component,
+    second: component,
+    third: component,
+}
+
+pattern component = {
+    name: named,
+    version: versioned,
+}
+
+pattern named = string
+pattern versioned = integer
+"#,
+            json!({
+                "first": { "name": "foo", "version": 1 },
+                "second": { "name": 1, "version": "1.0" },
+                "third": { "name": 2, "version": 2 },
+            }),
+        )
+        .await;
+        assert_not_satisfied!(&result);
+
+        let groups = Response::new(&result).group_by_pattern(Severity::Error);
+        assert_eq!(
+            serde_json::to_value(groups).unwrap(),
+            json!([
+                {
+                    "pattern": "test::named",
+                    "severity": "error",
+                    "count": 2,
+                    "reasons": ["The primordial type defined in the pattern is not satisfied"],
+                    "examples": [1, 2],
+                },
+                {
+                    "pattern": "test::versioned",
+                    "severity": "error",
+                    "count": 1,
+                    "reasons": ["The primordial type defined in the pattern is not satisfied"],
+                    "examples": ["1.0"],
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn no_failures() {
+        let result = test_common("pattern test = integer", json!(42)).await;
+        assert!(Response::new(&result)
+            .group_by_pattern(Severity::Error)
+            .is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 //! Response handling a policy decision.
 
 mod collector;
+mod grouped;
 
 pub use collector::*;
+pub use grouped::*;
 
 use super::{rationale::Rationale, EvaluationResult, PatternName};
 use crate::{
@@ -51,6 +53,9 @@ impl Display for Name {
 }
 
 /// A response is used to transform a policy result into different formats.
+///
+/// The serialized form is stable, as it is consumed by clients of the API, and by policies
+/// asserting on the outcome of other policies using `lang::response`.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, View)]
 pub struct Response {
     #[serde(default, skip_serializing_if = "Name::is_empty")]
@@ -118,6 +123,74 @@ impl Response {
         self
     }
 
+    /// Remove the output values from the whole tree of reasons.
+    ///
+    /// Outputs can be large, and are only interesting when the caller wants to consume what a
+    /// pattern produced.
+    pub fn without_output(mut self) -> Self {
+        self.output = None;
+        self.rationale = self
+            .rationale
+            .into_iter()
+            .map(Self::without_output)
+            .collect();
+        self
+    }
+
+    /// Summarize the response in a single line, for logging for instance.
+    ///
+    /// The line starts with the verdict (`PASS`, `WARN`, or `FAIL`) and the name of the pattern.
+    /// Unless passed, it is followed by the most relevant reason, found by descending into the
+    /// terms with the same severity down to the deepest one, or an authoritative one, like:
+    /// `FAIL test::dog: field 'trained' did not satisfy boolean`. Line breaks and other control
+    /// characters of the reason are replaced by spaces, keeping the summary on a single line.
+    pub fn summary_line(&self) -> String {
+        let verdict = match self.severity {
+            Severity::None | Severity::Advice => "PASS",
+            Severity::Warning => "WARN",
+            Severity::Error => "FAIL",
+        };
+        let mut line = match &self.name {
+            Name::Pattern(Some(name)) => format!("{verdict} {name}"),
+            _ => verdict.to_string(),
+        };
+        if self.severity <= Severity::Advice {
+            return line;
+        }
+
+        let mut fields = Vec::new();
+        let mut leaf = self;
+        while !leaf.authoritative {
+            let Some(next) = leaf.rationale.iter().find(|r| r.severity == self.severity) else {
+                break;
+            };
+            if let Name::Field(field) = &next.name {
+                fields.push(field.as_str());
+            }
+            leaf = next;
+        }
+
+        let path = fields.join(".");
+        let detail = match &leaf.name {
+            Name::Pattern(Some(pattern))
+                if !path.is_empty() && leaf.severity == Severity::Error =>
+            {
+                format!("field '{path}' did not satisfy {pattern}")
+            }
+            _ if !path.is_empty() => format!("field '{path}': {}", leaf.reason),
+            Name::Pattern(Some(pattern)) if !std::ptr::eq(leaf, self) => {
+                format!("{pattern}: {}", leaf.reason)
+            }
+            _ => leaf.reason.clone(),
+        };
+        if !detail.is_empty() {
+            line.push_str(": ");
+            line.extend(detail.chars().map(|c| if c.is_control() { ' ' } else { c }));
+        }
+
+        line
+    }
+
     /// Walk the tree of reasons.
     ///
     /// The callback can return `true` if it want to keep descending into the children of
@@ -244,7 +317,9 @@ fn support(rationale: &Rationale) -> Vec<Response> {
         | Rationale::Function {
             supporting: terms, ..
         } => terms.iter().map(|r| Response::new(&r)).collect(),
-        Rationale::Bound(inner, _) => support(inner),
+        Rationale::Bound(inner, _) | Rationale::Labeled(_, inner) | Rationale::Reason(_, inner) => {
+            support(inner)
+        }
         Rationale::Anything
         | Rationale::Nothing
         | Rationale::NotAnObject
@@ -288,6 +363,22 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn transformed_output() {
+        let result = test_pattern("list::count", json!([1, 42, 99])).await;
+        assert_satisfied!(&result);
+
+        let response = Response::new(&result);
+        assert_eq!(response.output, Some(json!(3)));
+
+        let response = response.without_output();
+        assert_eq!(response.output, None);
+        response.walk_tree(|r| {
+            assert_eq!(r.output, None);
+            true
+        });
+    }
+
     #[tokio::test]
     async fn happy_any_literal() {
         let result = test_pattern("list::any<42>", json!([1, 42, 99])).await;
@@ -372,6 +463,103 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn summary_line() {
+        let result = test_pattern(r#"{ trained: boolean }"#, json!({"trained": "true"})).await;
+        assert_eq!(
+            Response::new(&result).summary_line(),
+            "FAIL test::test-pattern: field 'trained' did not satisfy boolean"
+        );
+
+        let result = test_pattern(r#"{ trained: boolean }"#, json!({"trained": true})).await;
+        assert_eq!(
+            Response::new(&result).summary_line(),
+            "PASS test::test-pattern"
+        );
+
+        let result = test_pattern("purl::parse", "https://google.com").await;
+        assert_eq!(
+            Response::new(&result).summary_line(),
+            "FAIL purl::parse: purl must start with 'pkg:': https://google.com"
+        );
+    }
+
+    #[test]
+    fn summary_line_single_line() {
+        let response: Response = serde_json::from_value(json!({
+            "name": {"pattern": "test::dog"},
+            "severity": "error",
+            "reason": "not a dog:\nlooks like a cat\r\n\u{1b}[31m",
+        }))
+        .unwrap();
+        assert_eq!(
+            response.summary_line(),
+            "FAIL test::dog: not a dog: looks like a cat   [31m"
+        );
+
+        let response: Response = serde_json::from_value(json!({
+            "name": {"pattern": "test::dog"},
+            "severity": "advice",
+            "reason": "consider a leash",
+        }))
+        .unwrap();
+        assert_eq!(response.summary_line(), "PASS test::dog");
+    }
+
+    #[test]
+    fn summary_line_deepest_failure() {
+        let response: Response = serde_json::from_value(json!({
+            "name": {"pattern": "test::dog"},
+            "severity": "error",
+            "reason": "Because not all fields were satisfied",
+            "rationale": [
+                {
+                    "name": {"field": "name"},
+                    "severity": "none",
+                    "rationale": [{"name": {"pattern": "string"}, "severity": "none"}],
+                },
+                {
+                    "name": {"field": "owner"},
+                    "severity": "error",
+                    "reason": "Because not all fields were satisfied",
+                    "rationale": [{
+                        "name": {"field": "email"},
+                        "severity": "error",
+                        "reason": "The input does not satisfy the function",
+                        "rationale": [{
+                            "name": {"pattern": "email::address"},
+                            "severity": "error",
+                            "reason": "not an email address",
+                        }],
+                    }],
+                },
+            ],
+        }))
+        .unwrap();
+        assert_eq!(
+            response.summary_line(),
+            "FAIL test::dog: field 'owner.email' did not satisfy email::address"
+        );
+
+        // authoritative reasons are not descended into
+        let response: Response = serde_json::from_value(json!({
+            "name": {"pattern": "test::dog"},
+            "severity": "error",
+            "rationale": [{
+                "name": {"pattern": "test::vaccinated"},
+                "severity": "error",
+                "reason": "the dog must be vaccinated",
+                "authoritative": true,
+                "rationale": [{"severity": "error", "reason": "not a list"}],
+            }],
+        }))
+        .unwrap();
+        assert_eq!(
+            response.summary_line(),
+            "FAIL test::dog: test::vaccinated: the dog must be vaccinated"
+        );
+    }
+
     #[tokio::test]
     async fn nested_any() {
         let result = test_pattern("list::any<list::none<98>>", json!([[1, 99]])).await;
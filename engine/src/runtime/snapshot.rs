@@ -0,0 +1,240 @@
+//! Snapshots of a compiled world, for skipping the compilation on startup.
+//!
+//! A snapshot contains the compiled patterns, but not the functions, nor the configuration.
+//! Those are provided by the builder restoring the snapshot, functions being looked up by name.
+//! Restoring a snapshot therefore requires the same packages being available, but data sources
+//! and configuration may differ.
+
+use crate::core::Function;
+use crate::lang::lir::snapshot::PatternTable;
+use crate::runtime::config::ConfigContext;
+use crate::runtime::metadata::PackageMetadata;
+use crate::runtime::{PackagePath, PatternName, World};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Version of the snapshot format, snapshots of other versions can't be restored.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Errors raised when creating or restoring a snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("failed to encode the snapshot: {0}")]
+    Encode(String),
+    #[error("failed to decode the snapshot: {0}")]
+    Decode(String),
+    #[error("snapshot version {found} is not supported, expected {SNAPSHOT_VERSION}")]
+    UnsupportedVersion { found: u32 },
+    #[error("snapshot of engine version {found} can't be restored by version {expected}")]
+    EngineVersion { found: String, expected: String },
+    #[error("function {0} of the snapshot is not available")]
+    UnknownFunction(String),
+    #[error("snapshot references unknown pattern {0}")]
+    InvalidReference(usize),
+    #[error("environment variable ({variable}) referenced by config ({key}) is not set")]
+    UnsetEnvironmentVariable { key: String, variable: String },
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u32,
+    engine: String,
+    patterns: PatternTable,
+    types: Vec<(PatternName, usize)>,
+    type_slots: Vec<usize>,
+    packages: Vec<(Vec<String>, PackageMetadata)>,
+}
+
+impl World {
+    /// Serialize the compiled patterns of the world to a binary snapshot.
+    ///
+    /// The snapshot can be restored using [`crate::lang::builder::Builder::restore`].
+    pub fn snapshot(&self) -> Result<Vec<u8>, SnapshotError> {
+        let mut patterns = PatternTable::default();
+        let type_slots = self
+            .type_slots
+            .iter()
+            .map(|pattern| patterns.add(pattern))
+            .collect();
+
+        let mut types: Vec<_> = self
+            .types
+            .iter()
+            .map(|(name, slot)| (name.clone(), *slot))
+            .collect();
+        types.sort_unstable();
+
+        let mut packages: Vec<_> = self
+            .packages
+            .iter()
+            .map(|(path, meta)| {
+                let path = path.path.iter().map(|name| name.0.clone()).collect();
+                (path, meta.clone())
+            })
+            .collect();
+        packages.sort_unstable_by(|(l, _), (r, _)| l.cmp(r));
+
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            engine: env!("CARGO_PKG_VERSION").into(),
+            patterns,
+            types,
+            type_slots,
+            packages,
+        };
+
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(&snapshot, &mut out)
+            .map_err(|err| SnapshotError::Encode(format!("{err:?}")))?;
+        Ok(out)
+    }
+
+    /// Restore a world from a snapshot, using the provided functions and configuration.
+    pub(crate) fn restore(
+        snapshot: &[u8],
+        functions: &HashMap<PatternName, Arc<dyn Function>>,
+        config: ConfigContext,
+    ) -> Result<Self, SnapshotError> {
+        let snapshot: Snapshot = ciborium::de::from_reader(snapshot)
+            .map_err(|err| SnapshotError::Decode(format!("{err:?}")))?;
+
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: snapshot.version,
+            });
+        }
+        // the core patterns, and the functions implementing them, must match
+        if snapshot.engine != env!("CARGO_PKG_VERSION") {
+            return Err(SnapshotError::EngineVersion {
+                found: snapshot.engine,
+                expected: env!("CARGO_PKG_VERSION").into(),
+            });
+        }
+
+        let patterns = snapshot.patterns.restore(functions)?;
+        let type_slots = snapshot
+            .type_slots
+            .into_iter()
+            .map(|index| {
+                patterns
+                    .get(index)
+                    .cloned()
+                    .ok_or(SnapshotError::InvalidReference(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let types = snapshot.types.into_iter().collect::<HashMap<_, _>>();
+        if let Some(slot) = types.values().find(|slot| **slot >= type_slots.len()) {
+            return Err(SnapshotError::InvalidReference(*slot));
+        }
+
+        let packages = snapshot
+            .packages
+            .into_iter()
+            .map(|(path, meta)| (PackagePath::from(path), meta))
+            .collect();
+
+        Ok(World::new(config, types, type_slots, packages))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CorePackages;
+    use crate::lang::builder::Builder;
+    use crate::runtime::sources::Ephemeral;
+    use crate::runtime::EvalContext;
+    use crate::{assert_not_satisfied, assert_satisfied};
+    use serde_json::json;
+
+    const POLICY: &str = r#"
+/// A named component
+pattern component<name> = {
+    name: name,
+    version?: string,
+    licenses: list::all<string>,
+}
+
+pattern components = list::all<component<"bob"> || component<"jim">>
+
+pattern decoded = base64::base64 | json::json
+"#;
+
+    async fn snapshot() -> Vec<u8> {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", POLICY).iter())
+            .unwrap();
+        builder.finish().await.unwrap().snapshot().unwrap()
+    }
+
+    #[tokio::test]
+    async fn restore() {
+        let snapshot = snapshot().await;
+        // no sources required
+        let world = Builder::new().restore(&snapshot).unwrap();
+
+        let result = world
+            .evaluate(
+                "test::components",
+                json!([{"name": "bob", "licenses": ["MIT"]}, {"name": "jim", "licenses": []}]),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_satisfied!(result);
+
+        let result = world
+            .evaluate(
+                "test::components",
+                json!([{"name": "alice", "licenses": ["MIT"]}]),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_not_satisfied!(result);
+
+        let result = world
+            .evaluate(
+                "test::decoded",
+                json!("eyJhbnN3ZXIiOiA0Mn0="),
+                EvalContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_satisfied!(&result);
+        assert_eq!(result.output().as_json(), json!({"answer": 42}));
+
+        let meta = world.get_pattern_meta("test::component").unwrap();
+        assert_eq!(meta.parameters, vec![Arc::<str>::from("name")]);
+        assert!(world.get_package_meta("test").is_some());
+    }
+
+    #[tokio::test]
+    async fn stable() {
+        assert_eq!(snapshot().await, snapshot().await);
+    }
+
+    #[tokio::test]
+    async fn missing_function() {
+        let snapshot = snapshot().await;
+        let builder =
+            Builder::new_with_packages(ConfigContext::default(), CorePackages::only(["list"]));
+
+        assert!(matches!(
+            builder.restore(&snapshot),
+            Err(SnapshotError::UnknownFunction(name)) if name.starts_with("base64::")
+                || name.starts_with("json::")
+        ));
+    }
+
+    #[test]
+    fn invalid_snapshot() {
+        assert!(matches!(
+            Builder::new().restore(b"not a snapshot"),
+            Err(SnapshotError::Decode(_))
+        ));
+    }
+}
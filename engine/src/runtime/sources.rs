@@ -7,6 +7,9 @@ use std::iter::once;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use git::*;
+
 /// An in-memory source.
 #[derive(Clone)]
 pub struct Ephemeral {
@@ -77,3 +80,168 @@ impl Directory {
             })
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+mod git {
+    use super::Directory;
+    use sha2::{Digest, Sha256};
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    /// Errors raised while loading a remote policy source.
+    #[derive(Debug, thiserror::Error)]
+    pub enum SourceError {
+        #[error("i/o error: {0}")]
+        Io(#[from] std::io::Error),
+        #[error("git {command} failed: {stderr}")]
+        Git { command: String, stderr: String },
+        #[error("invalid git {name} '{value}': must not start with '-'")]
+        InvalidArgument { name: &'static str, value: String },
+    }
+
+    /// A git repository with policy files.
+    ///
+    /// The repository is fetched with the `git` command line tool into a local cache directory,
+    /// and then loaded like a [`Directory`]. Authentication is left to git itself (credential
+    /// helpers, SSH agent), additional settings can be passed using [`Git::with_config`].
+    #[derive(Debug, Clone)]
+    pub struct Git {
+        url: String,
+        reference: String,
+        subdirectory: Option<PathBuf>,
+        cache_dir: PathBuf,
+        config: Vec<(String, String)>,
+    }
+
+    impl Git {
+        pub fn new<U: Into<String>>(url: U) -> Self {
+            let cache_dir = home::home_dir()
+                .map(|home| home.join(".cache"))
+                .unwrap_or_else(std::env::temp_dir)
+                .join("seedwing")
+                .join("git");
+
+            Self {
+                url: url.into(),
+                reference: "HEAD".into(),
+                subdirectory: None,
+                cache_dir,
+                config: Vec::new(),
+            }
+        }
+
+        /// The branch, tag or commit to load, defaults to `HEAD`.
+        pub fn with_reference<R: Into<String>>(mut self, reference: R) -> Self {
+            self.reference = reference.into();
+            self
+        }
+
+        /// Only load policies from a subdirectory of the repository.
+        pub fn with_subdirectory<P: AsRef<Path>>(mut self, subdirectory: P) -> Self {
+            self.subdirectory = Some(subdirectory.as_ref().to_path_buf());
+            self
+        }
+
+        /// The directory checkouts are cached in, defaults to `~/.cache/seedwing/git`.
+        pub fn with_cache_dir<P: AsRef<Path>>(mut self, cache_dir: P) -> Self {
+            self.cache_dir = cache_dir.as_ref().to_path_buf();
+            self
+        }
+
+        /// Pass a configuration option to git, like `http.extraHeader` for token based authentication.
+        pub fn with_config<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+            self.config.push((key.into(), value.into()));
+            self
+        }
+
+        /// Fetch the repository into the cache, returning the directory to load policies from.
+        ///
+        /// An existing checkout is updated in place.
+        pub fn fetch(&self) -> Result<Directory, SourceError> {
+            // don't let the url or reference be interpreted as an option
+            for (name, value) in [("url", &self.url), ("reference", &self.reference)] {
+                if value.starts_with('-') {
+                    return Err(SourceError::InvalidArgument {
+                        name,
+                        value: value.clone(),
+                    });
+                }
+            }
+
+            let checkout = self.checkout_dir();
+            if !checkout.join(".git").exists() {
+                std::fs::create_dir_all(&checkout)?;
+                self.git(&checkout, &["init", "--quiet"])?;
+            }
+
+            self.git(
+                &checkout,
+                &[
+                    "fetch",
+                    "--quiet",
+                    "--end-of-options",
+                    &self.url,
+                    &self.reference,
+                ],
+            )?;
+            self.git(&checkout, &["checkout", "--quiet", "--force", "FETCH_HEAD"])?;
+
+            Ok(match &self.subdirectory {
+                Some(subdirectory) => Directory::new(checkout.join(subdirectory)),
+                None => Directory::new(checkout),
+            })
+        }
+
+        fn checkout_dir(&self) -> PathBuf {
+            let mut hasher = Sha256::new();
+            hasher.update(self.url.as_bytes());
+            self.cache_dir.join(format!("{:x}", hasher.finalize()))
+        }
+
+        fn git(&self, dir: &Path, args: &[&str]) -> Result<(), SourceError> {
+            let mut command = Command::new("git");
+            command.arg("-C").arg(dir);
+            command.args(["-c", "advice.detachedHead=false"]);
+            for (key, value) in &self.config {
+                command.arg("-c").arg(format!("{key}={value}"));
+            }
+
+            let output = command.args(args).output()?;
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(SourceError::Git {
+                    command: args[0].into(),
+                    stderr: String::from_utf8_lossy(&output.stderr).trim().into(),
+                })
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn reject_options() {
+            let cache_dir = std::env::temp_dir().join("seedwing-git-test");
+
+            let git = Git::new("--upload-pack=touch /tmp/pwned").with_cache_dir(&cache_dir);
+            assert!(matches!(
+                git.fetch(),
+                Err(SourceError::InvalidArgument { name: "url", .. })
+            ));
+
+            let git = Git::new("https://github.com/seedwing-io/seedwing-policy")
+                .with_reference("--upload-pack=touch /tmp/pwned")
+                .with_cache_dir(&cache_dir);
+            assert!(matches!(
+                git.fetch(),
+                Err(SourceError::InvalidArgument {
+                    name: "reference",
+                    ..
+                })
+            ));
+        }
+    }
+}
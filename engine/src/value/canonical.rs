@@ -0,0 +1,237 @@
+//! Canonical JSON serialization, following RFC 8785 (JCS).
+use crate::value::RuntimeValue;
+use std::borrow::Borrow;
+
+impl RuntimeValue {
+    /// Serialize into canonical JSON, as defined by RFC 8785 (JSON Canonicalization Scheme).
+    ///
+    /// Object keys are sorted and numbers are normalized, so that the output is stable
+    /// regardless of the order keys were inserted in. This makes it suitable for hashing or
+    /// signing values.
+    ///
+    /// Integers are serialized exactly, even beyond the range an IEEE 754 double can represent
+    /// precisely. Values without a JSON representation (octets, timestamps) are serialized like
+    /// [`RuntimeValue::as_json`] does.
+    pub fn as_canonical_json(&self) -> String {
+        let mut out = String::new();
+        write_canonical(&mut out, self, false);
+        out
+    }
+
+    /// Serialize like [`RuntimeValue::as_canonical_json`], but prefixing decimals, octets and
+    /// timestamps with a `~` and their type, so that values of different types never serialize
+    /// the same. The output is not valid JSON, and only meant to identify the value.
+    pub(crate) fn as_typed_key(&self) -> String {
+        let mut out = String::new();
+        write_canonical(&mut out, self, true);
+        out
+    }
+}
+
+fn write_canonical(out: &mut String, value: &RuntimeValue, typed: bool) {
+    if typed {
+        match value {
+            RuntimeValue::Decimal(_) => out.push_str("~d"),
+            RuntimeValue::Octets(_) => out.push_str("~o"),
+            RuntimeValue::Timestamp(_) => out.push_str("~t"),
+            _ => {}
+        }
+    }
+    match value {
+        RuntimeValue::Null => out.push_str("null"),
+        RuntimeValue::Boolean(val) => out.push_str(if *val { "true" } else { "false" }),
+        RuntimeValue::Integer(val) => out.push_str(&val.to_string()),
+        RuntimeValue::Decimal(val) => write_number(out, *val),
+        RuntimeValue::String(val) => write_string(out, val),
+        RuntimeValue::List(val) => {
+            out.push('[');
+            for (idx, each) in val.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_canonical(out, (**each).borrow(), typed);
+            }
+            out.push(']');
+        }
+        RuntimeValue::Object(val) => {
+            let mut fields: Vec<_> = val.iter().collect();
+            // keys are sorted by their UTF-16 code units
+            fields.sort_by(|(l, _), (r, _)| l.encode_utf16().cmp(r.encode_utf16()));
+
+            out.push('{');
+            for (idx, (name, value)) in fields.into_iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_string(out, name);
+                out.push(':');
+                write_canonical(out, (**value).borrow(), typed);
+            }
+            out.push('}');
+        }
+        RuntimeValue::Octets(_) | RuntimeValue::Timestamp(_) => {
+            // both serialize to a JSON string
+            if let serde_json::Value::String(val) = value.as_json() {
+                write_string(out, &val);
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, val: &str) {
+    out.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Write a number the way ECMAScript's `Number.prototype.toString` does, as required by JCS.
+fn write_number(out: &mut String, val: f64) {
+    if !val.is_finite() {
+        // not representable in JSON
+        out.push_str("null");
+        return;
+    }
+    if val == 0.0 {
+        // also covers negative zero
+        out.push('0');
+        return;
+    }
+    if val < 0.0 {
+        out.push('-');
+    }
+
+    // the shortest digits that round trip, in scientific notation: "d.ddde-x"
+    let scientific = format!("{:e}", val.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    // the position of the decimal point, relative to the start of the digits
+    let n = exponent.parse::<i32>().unwrap_or(0) + 1;
+
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.extend(std::iter::repeat('0').take((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        out.push_str(int);
+        out.push('.');
+        out.push_str(frac);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat('0').take(-n as usize));
+        out.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        out.push_str(first);
+        if !rest.is_empty() {
+            out.push('.');
+            out.push_str(rest);
+        }
+        out.push('e');
+        if n - 1 > 0 {
+            out.push('+');
+        }
+        out.push_str(&(n - 1).to_string());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::value::Object;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn key_order_is_stable() {
+        let mut first = Object::new();
+        first.set("b", 2);
+        first.set("a", "x");
+        first.set("c", RuntimeValue::from(json!({"z": true, "y": null})));
+
+        let mut second = Object::new();
+        second.set("c", RuntimeValue::from(json!({"y": null, "z": true})));
+        second.set("a", "x");
+        second.set("b", 2);
+
+        let first = RuntimeValue::Object(first).as_canonical_json();
+        let second = RuntimeValue::Object(second).as_canonical_json();
+
+        assert_eq!(first.as_bytes(), second.as_bytes());
+        assert_eq!(first, r#"{"a":"x","b":2,"c":{"y":null,"z":true}}"#);
+    }
+
+    #[test]
+    fn keys_sorted_by_utf16() {
+        // U+1F600 sorts before U+E000 in UTF-16 (as surrogate pair 0xD83D 0xDE00), but after it by
+        // code point or in UTF-8
+        let value = RuntimeValue::from(json!({"\u{1F600}": 1, "\u{E000}": 2, "a": 3}));
+        assert_eq!(
+            value.as_canonical_json(),
+            "{\"a\":3,\"\u{1F600}\":1,\"\u{E000}\":2}"
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        let cases = [
+            (0.0, "0"),
+            (-0.0, "0"),
+            (1.0, "1"),
+            (-1.5, "-1.5"),
+            (100.0, "100"),
+            (0.000001, "0.000001"),
+            (0.0000001, "1e-7"),
+            (1e21, "1e+21"),
+            (1e20, "100000000000000000000"),
+            (123.456, "123.456"),
+            (5e-324, "5e-324"),
+            (1.7976931348623157e308, "1.7976931348623157e+308"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                RuntimeValue::Decimal(value).as_canonical_json(),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let value = RuntimeValue::from("\"\\/\n\u{1}é");
+        assert_eq!(value.as_canonical_json(), r#""\"\\/\n\u0001é""#);
+    }
+
+    #[test]
+    fn typed_keys() {
+        let timestamp = RuntimeValue::from("2023-03-01T12:30:00+00:00").detect_timestamps();
+        let octets = RuntimeValue::Octets(vec![0xca, 0xfe]);
+        let pairs = [
+            (RuntimeValue::Integer(1), RuntimeValue::Decimal(1.0)),
+            (RuntimeValue::Null, RuntimeValue::Decimal(f64::NAN)),
+            (RuntimeValue::from(timestamp.as_json()), timestamp),
+            (RuntimeValue::from(octets.as_json()), octets),
+        ];
+        for (lhs, rhs) in pairs {
+            assert_eq!(lhs.as_canonical_json(), rhs.as_canonical_json());
+            assert_ne!(lhs.as_typed_key(), rhs.as_typed_key(), "{lhs:?}");
+
+            let lhs = RuntimeValue::List(vec![Arc::new(lhs)]);
+            let rhs = RuntimeValue::List(vec![Arc::new(rhs)]);
+            assert_ne!(lhs.as_typed_key(), rhs.as_typed_key(), "{lhs:?}");
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Values are inputs or outputs from patterns, and can be serialized and deserialized from different types.
 
 use ::serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use serde_json::{json, Map, Number};
 
@@ -18,6 +19,7 @@ use std::sync::Arc;
 
 pub mod serde;
 
+mod canonical;
 mod json;
 mod yaml;
 
@@ -76,10 +78,13 @@ impl PartialEq<Self> for RuntimeValue {
             (Self::List(lhs), Self::List(rhs)) => lhs == rhs,
             (Self::Octets(lhs), Self::Octets(rhs)) => lhs == rhs,
             (Self::Object(lhs), Self::Object(rhs)) => lhs == rhs,
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => lhs == rhs,
             (Self::Null, Self::Null) => true,
             // more specialness
             (Self::Octets(lhs), Self::String(rhs)) => lhs == rhs.as_bytes(),
             (Self::String(lhs), Self::Octets(rhs)) => lhs.as_bytes() == rhs,
+            (Self::Timestamp(lhs), Self::String(rhs)) => parse_timestamp(rhs) == Some(*lhs),
+            (Self::String(lhs), Self::Timestamp(rhs)) => parse_timestamp(lhs) == Some(*rhs),
             _ => false,
         }
     }
@@ -96,12 +101,26 @@ impl PartialOrd for RuntimeValue {
             (Self::String(lhs), Self::String(rhs)) => lhs.partial_cmp(rhs),
             (Self::List(lhs), Self::List(rhs)) => lhs.partial_cmp(rhs),
             (Self::Octets(lhs), Self::Octets(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Timestamp(lhs), Self::String(rhs)) => {
+                parse_timestamp(rhs).and_then(|rhs| lhs.partial_cmp(&rhs))
+            }
+            (Self::String(lhs), Self::Timestamp(rhs)) => {
+                parse_timestamp(lhs).and_then(|lhs| lhs.partial_cmp(rhs))
+            }
             (Self::Null, Self::Null) => Some(Ordering::Equal),
             _ => None,
         }
     }
 }
 
+/// Parse an RFC 3339 timestamp, normalized to UTC.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|value| value.with_timezone(&Utc))
+}
+
 impl From<u8> for RuntimeValue {
     fn from(inner: u8) -> Self {
         Self::Integer(inner as _)
@@ -222,6 +241,12 @@ impl From<&[RuntimeValue]> for RuntimeValue {
     }
 }
 
+impl From<DateTime<Utc>> for RuntimeValue {
+    fn from(inner: DateTime<Utc>) -> Self {
+        Self::Timestamp(inner)
+    }
+}
+
 impl From<Object> for RuntimeValue {
     fn from(inner: Object) -> Self {
         Self::Object(inner)
@@ -252,6 +277,7 @@ pub enum RuntimeValue {
     Object(Object),
     List(Vec<Arc<RuntimeValue>>),
     Octets(#[serde(with = "RuntimeValueBase64")] Vec<u8>),
+    Timestamp(DateTime<Utc>),
 }
 
 impl Display for RuntimeValue {
@@ -265,6 +291,7 @@ impl Display for RuntimeValue {
             Self::Object(val) => Display::fmt(val, f),
             Self::List(_val) => write!(f, "[ <<things>> ]"),
             Self::Octets(_val) => write!(f, "[ <<octets>> ]"),
+            Self::Timestamp(val) => write!(f, "{}", val.to_rfc3339()),
         }
     }
 }
@@ -280,6 +307,7 @@ impl RuntimeValue {
             Self::Object(_) => "object",
             Self::List(_) => "list",
             Self::Octets(_) => "octets",
+            Self::Timestamp(_) => "timestamp",
         }
     }
 
@@ -308,6 +336,7 @@ impl RuntimeValue {
                 }
                 serde_json::Value::String(octets)
             }
+            Self::Timestamp(val) => serde_json::Value::String(val.to_rfc3339()),
         }
     }
 
@@ -408,6 +437,42 @@ impl RuntimeValue {
             None
         }
     }
+
+    pub fn is_timestamp(&self) -> bool {
+        matches!(self, Self::Timestamp(_))
+    }
+
+    pub fn try_get_timestamp(&self) -> Option<DateTime<Utc>> {
+        if let Self::Timestamp(inner) = self {
+            Some(*inner)
+        } else {
+            None
+        }
+    }
+
+    /// Convert all strings which are valid RFC 3339 timestamps into timestamp values.
+    ///
+    /// This walks lists and objects recursively, leaving all other values untouched.
+    pub fn detect_timestamps(self) -> Self {
+        match self {
+            Self::String(val) => match parse_timestamp(&val) {
+                Some(timestamp) => Self::Timestamp(timestamp),
+                None => Self::String(val),
+            },
+            Self::List(val) => Self::List(
+                val.into_iter()
+                    .map(|e| Arc::new((*e).clone().detect_timestamps()))
+                    .collect(),
+            ),
+            Self::Object(val) => Self::Object(Object(
+                val.0
+                    .into_iter()
+                    .map(|(k, v)| (k, Arc::new((*v).clone().detect_timestamps())))
+                    .collect(),
+            )),
+            _ => self,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
@@ -603,6 +668,58 @@ here:
         );
     }
 
+    #[test]
+    fn test_serde_rv_timestamp() {
+        assert_eq_and_back_again(
+            RuntimeValue::Timestamp(timestamp("2023-03-01T12:30:00Z")),
+            json!({"timestamp": "2023-03-01T12:30:00Z"}),
+        );
+    }
+
+    #[test]
+    fn test_timestamp_ordering() {
+        let earlier = RuntimeValue::from(timestamp("2023-03-01T12:30:00Z"));
+        let later = RuntimeValue::from(timestamp("2023-03-01T15:30:00+02:00"));
+
+        assert_eq!(Some(Ordering::Less), earlier.partial_cmp(&later));
+        assert_eq!(Some(Ordering::Greater), later.partial_cmp(&earlier));
+        assert_eq!(Some(Ordering::Equal), earlier.partial_cmp(&earlier));
+
+        // strings are compared as timestamps if they are valid RFC 3339
+        let string = RuntimeValue::from("2023-03-01T14:30:00+02:00");
+        assert_eq!(earlier, string);
+        assert_eq!(Some(Ordering::Equal), string.partial_cmp(&earlier));
+        assert_eq!(Some(Ordering::Greater), later.partial_cmp(&string));
+        assert_eq!(None, earlier.partial_cmp(&RuntimeValue::from("yesterday")));
+        assert_eq!(None, earlier.partial_cmp(&RuntimeValue::from(42)));
+    }
+
+    #[test]
+    fn test_detect_timestamps() {
+        let value = RuntimeValue::from(json!({
+            "created": "2023-03-01T12:30:00Z",
+            "name": "foo",
+            "history": ["2023-02-01T00:00:00Z", 42],
+        }))
+        .detect_timestamps();
+
+        let value = value.try_get_object().unwrap();
+        assert_eq!(
+            Some(timestamp("2023-03-01T12:30:00Z")),
+            value["created"].try_get_timestamp()
+        );
+        assert!(value["name"].is_string());
+        let history = value["history"].try_get_list().unwrap();
+        assert!(history[0].is_timestamp());
+        assert!(history[1].is_integer());
+    }
+
+    fn timestamp(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
     #[test]
     fn test_serde_rv_list() {
         assert_eq_and_back_again(
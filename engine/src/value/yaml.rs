@@ -1,5 +1,6 @@
 use crate::value::{Object, RuntimeValue};
-use serde_yaml::Value as YamlValue;
+use base64::engine::{general_purpose::STANDARD, Engine as _};
+use serde_yaml::{Mapping, Value as YamlValue};
 
 use std::sync::Arc;
 
@@ -58,13 +59,71 @@ impl From<YamlValue> for RuntimeValue {
     }
 }
 
+impl RuntimeValue {
+    /// Convert into a YAML value.
+    ///
+    /// Octets are encoded as base64, the same way serializing a [`RuntimeValue`] does. Timestamps
+    /// are encoded as RFC 3339 strings.
+    pub fn as_yaml(&self) -> YamlValue {
+        match self {
+            Self::Null => YamlValue::Null,
+            Self::String(val) => YamlValue::String(val.to_string()),
+            Self::Integer(val) => YamlValue::Number((*val).into()),
+            Self::Decimal(val) => YamlValue::Number((*val).into()),
+            Self::Boolean(val) => YamlValue::Bool(*val),
+            Self::Object(val) => {
+                let mut mapping = Mapping::new();
+                for (name, value) in val.iter() {
+                    mapping.insert(YamlValue::String(name.to_string()), value.as_yaml());
+                }
+                YamlValue::Mapping(mapping)
+            }
+            Self::List(val) => YamlValue::Sequence(val.iter().map(|e| e.as_yaml()).collect()),
+            Self::Octets(val) => YamlValue::String(STANDARD.encode(val)),
+            Self::Timestamp(val) => YamlValue::String(val.to_rfc3339()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-
     use crate::value::test::assert_yaml;
+    use crate::value::RuntimeValue;
+    use serde_json::json;
 
     #[test]
     fn test_yaml() {
         assert_yaml(|y| serde_yaml::from_str::<serde_yaml::Value>(y).map(|v| v.into()));
     }
+
+    #[test]
+    fn round_trip() {
+        let value: RuntimeValue = json!({
+            "name": "bob",
+            "age": 42,
+            "height": 1.85,
+            "admin": false,
+            "nickname": null,
+            "labels": ["a", "b"],
+            "address": { "city": "Prague" },
+        })
+        .into();
+
+        let yaml = serde_yaml::to_string(&value.as_yaml()).unwrap();
+        let parsed: RuntimeValue = serde_yaml::from_str::<serde_yaml::Value>(&yaml)
+            .unwrap()
+            .into();
+
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn octets() {
+        let value = RuntimeValue::Octets(b"hello".to_vec());
+
+        assert_eq!(
+            value.as_yaml(),
+            serde_yaml::Value::String("aGVsbG8=".into())
+        );
+    }
 }
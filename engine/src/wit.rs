@@ -50,40 +50,55 @@ impl Engine for Exports {
     }
 
     fn eval(
-        _policies: Vec<String>,
+        policies: Vec<String>,
         data: Vec<(String, wit_types::DataType)>,
         policy: String,
         name: String,
         input: wit_types::RuntimeValue,
     ) -> Result<wit_types::EvaluationResultOuter, String> {
+        Self::eval_batch(policies, data, policy, name, vec![input])
+            .map(|mut results| results.remove(0))
+    }
+
+    fn eval_batch(
+        _policies: Vec<String>,
+        data: Vec<(String, wit_types::DataType)>,
+        policy: String,
+        name: String,
+        inputs: Vec<wit_types::RuntimeValue>,
+    ) -> Result<Vec<wit_types::EvaluationResultOuter>, String> {
         let mut builder = Builder::new();
         builder.data(MemDataSource::from(data));
         let _res = builder.build(Ephemeral::new("wit", policy).iter()).unwrap();
-        let evaluation_result = futures::executor::block_on(async {
-            let runtime = builder.finish().await;
-            runtime
-                .unwrap()
-                .evaluate(format!("wit::{name}"), &input, EvalContext::default())
-                .await
-        });
-        match evaluation_result {
-            Ok(result) => {
-                let mut eval_context = WitContext::new();
-                let wit_evaluation_result =
-                    wit_types::EvaluationResult::from_with_context(&result, &mut eval_context);
-
-                let wit_result_outer = wit_types::EvaluationResultOuter {
-                    evaluation_result: wit_evaluation_result,
-                    pattern_map: Vec::from_iter(eval_context.pattern_map.into_iter()),
-                    evaluation_result_map: Vec::from_iter(
-                        eval_context.evaluation_result_map.into_iter(),
-                    ),
-                    rationale_map: Vec::from_iter(eval_context.rationale_map.into_iter()),
-                    expr_map: Vec::from_iter(eval_context.expr_map.into_iter()),
-                };
-                Ok(wit_result_outer)
+        futures::executor::block_on(async {
+            let runtime = builder.finish().await.unwrap();
+            let mut results = Vec::with_capacity(inputs.len());
+            for input in &inputs {
+                match runtime
+                    .evaluate(format!("wit::{name}"), input, EvalContext::default())
+                    .await
+                {
+                    Ok(result) => results.push(wit_types::EvaluationResultOuter::from(&result)),
+                    Err(e) => return Err(format!("Error processing rule: {e}")),
+                }
             }
-            Err(e) => Err(format!("Error processing rule: {e}")),
+            Ok(results)
+        })
+    }
+}
+
+impl From<&EvaluationResult> for wit_types::EvaluationResultOuter {
+    fn from(result: &EvaluationResult) -> Self {
+        let mut eval_context = WitContext::new();
+        let wit_evaluation_result =
+            wit_types::EvaluationResult::from_with_context(result, &mut eval_context);
+
+        wit_types::EvaluationResultOuter {
+            evaluation_result: wit_evaluation_result,
+            pattern_map: Vec::from_iter(eval_context.pattern_map.into_iter()),
+            evaluation_result_map: Vec::from_iter(eval_context.evaluation_result_map.into_iter()),
+            rationale_map: Vec::from_iter(eval_context.rationale_map.into_iter()),
+            expr_map: Vec::from_iter(eval_context.expr_map.into_iter()),
         }
     }
 }
@@ -189,6 +204,24 @@ impl wit_types::Rationale {
                 };
                 wit_types::Rationale::Bound((rationale_ref, wb))
             }
+            Rationale::Labeled(label, rationale) => {
+                let wit_rationale = Self::from_with_context(rationale, context);
+                let rationale_id = Uuid::new_v4().to_string();
+                context
+                    .rationale_map
+                    .insert(rationale_id.to_string(), wit_rationale);
+                let rationale_ref = wit_types::RationaleRef { rationale_id };
+                wit_types::Rationale::Labeled((label.to_string(), rationale_ref))
+            }
+            Rationale::Reason(reason, rationale) => {
+                let wit_rationale = Self::from_with_context(rationale, context);
+                let rationale_id = Uuid::new_v4().to_string();
+                context
+                    .rationale_map
+                    .insert(rationale_id.to_string(), wit_rationale);
+                let rationale_ref = wit_types::RationaleRef { rationale_id };
+                wit_types::Rationale::Reason((reason.to_string(), rationale_ref))
+            }
             Rationale::Function {
                 severity,
                 rationale,
@@ -319,6 +352,9 @@ impl From<&RuntimeValue> for wit_types::RuntimeValue {
                 wit_types::RuntimeValue::List(values)
             }
             RuntimeValue::Octets(value) => wit_types::RuntimeValue::Octets(value.to_vec()),
+            RuntimeValue::Timestamp(value) => {
+                wit_types::RuntimeValue::String(value.to_rfc3339().into())
+            }
             RuntimeValue::Object(object) => {
                 let mut list: Vec<wit_types::Object> = Vec::new();
                 for (key, value) in object.iter() {
@@ -353,6 +389,9 @@ impl From<&RuntimeValue> for wit_types::ObjectValue {
                 wit_types::ObjectValue::List(values)
             }
             RuntimeValue::Octets(value) => wit_types::ObjectValue::Octets(value.to_vec()),
+            RuntimeValue::Timestamp(value) => {
+                wit_types::ObjectValue::String(value.to_rfc3339().into())
+            }
             RuntimeValue::Object(_) => wit_types::ObjectValue::Null,
         }
     }
@@ -366,6 +405,9 @@ impl From<&RuntimeValue> for wit_types::BaseValue {
             RuntimeValue::Integer(value) => wit_types::BaseValue::Integer(*value),
             RuntimeValue::Decimal(value) => wit_types::BaseValue::Decimal(value.clone()),
             RuntimeValue::Boolean(value) => wit_types::BaseValue::Boolean(*value),
+            RuntimeValue::Timestamp(value) => {
+                wit_types::BaseValue::String(value.to_rfc3339().into())
+            }
             _ => wit_types::BaseValue::Null,
         }
     }
@@ -711,4 +753,72 @@ mod test {
     fn from_engine_runtime() {
         println!("from_engine_runtime...");
     }
+
+    #[test]
+    fn eval_batch() {
+        let dog = |trained| {
+            wit_types::RuntimeValue::Object(vec![
+                wit_types::Object {
+                    key: "name".to_string(),
+                    value: wit_types::ObjectValue::String("goodboy".to_string()),
+                },
+                wit_types::Object {
+                    key: "trained".to_string(),
+                    value: wit_types::ObjectValue::Boolean(trained),
+                },
+            ])
+        };
+        let inputs = vec![dog(true), dog(false), wit_types::RuntimeValue::Integer(42)];
+
+        let results = Exports::eval_batch(
+            vec![],
+            vec![],
+            "pattern dog = { name: string, trained: true }".to_string(),
+            "dog".to_string(),
+            inputs,
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(
+            results[0].evaluation_result.input,
+            wit_types::RuntimeValue::Object(_)
+        ));
+        assert!(matches!(
+            results[1].evaluation_result.input,
+            wit_types::RuntimeValue::Object(_)
+        ));
+        assert!(matches!(
+            results[2].evaluation_result.input,
+            wit_types::RuntimeValue::Integer(42)
+        ));
+
+        // whether the field of an object input is satisfied, looking up the result of its pattern
+        let field = |outer: &wit_types::EvaluationResultOuter, name: &str| {
+            let wit_types::Rationale::Object(fields) = &outer.evaluation_result.rationale else {
+                panic!("expected an object rationale");
+            };
+            let Some((_, Some(result))) = fields.iter().find(|(field, _)| field == name) else {
+                panic!("field {name} was not evaluated");
+            };
+            let (_, result) = outer
+                .evaluation_result_map
+                .iter()
+                .find(|(id, _)| *id == result.eval_id)
+                .unwrap();
+            matches!(
+                result.rationale,
+                wit_types::Rationale::Const(true) | wit_types::Rationale::Primordial(true)
+            )
+        };
+
+        assert!(field(&results[0], "name"));
+        assert!(field(&results[0], "trained"));
+        assert!(field(&results[1], "name"));
+        assert!(!field(&results[1], "trained"));
+        assert!(matches!(
+            results[2].evaluation_result.rationale,
+            wit_types::Rationale::NotAnObject
+        ));
+    }
 }
@@ -72,12 +72,36 @@ async fn main() -> wasmtime::Result<()> {
         key: "trained".to_string(),
         value: types::ObjectValue::Boolean(true),
     };
-    let input = types::RuntimeValue::Object(vec![obj_name, obj_trained]);
+    let input = types::RuntimeValue::Object(vec![obj_name.clone(), obj_trained.clone()]);
 
     let result = engine
         .call_eval(&mut store, &policies, &data, policy, policy_name, &input)
         .await?;
 
+    let untrained = types::Object {
+        key: "trained".to_string(),
+        value: types::ObjectValue::String("no".to_string()),
+    };
+    let inputs = [
+        input.clone(),
+        types::RuntimeValue::Object(vec![obj_name, untrained]),
+        types::RuntimeValue::Object(vec![obj_trained]),
+    ];
+
+    let batch = engine
+        .call_eval_batch(&mut store, &policies, &data, policy, policy_name, &inputs)
+        .await?;
+
+    match batch {
+        Ok(results) => {
+            println!("Batch evaluated {} inputs:", results.len());
+            for (i, outer) in results.into_iter().enumerate() {
+                println!("{i}: rationale: {:#?}\n", outer.evaluation_result.rationale);
+            }
+        }
+        Err(e) => return Err(anyhow::anyhow!("Policy Engine error {:?}", e)),
+    }
+
     match result {
         Ok(outer) => {
             let evaluation_result = outer.evaluation_result;
@@ -192,7 +192,7 @@ pub async fn eval(policy: String, name: String, value: Value) -> Result<Response
     };
 
     let response = gloo_net::http::Request::post("/api/playground/v1alpha1/evaluate")
-        .query([("format", "json"), ("no_error", "true")])
+        .query([("format", "json"), ("no_error", "true"), ("output", "true")])
         .json(&request)
         .map_err(|err| format!("Failed to encode request: {err}"))?
         .send()
@@ -220,7 +220,7 @@ pub async fn eval(policy: String, name: String, value: Value) -> Result<Response
 /// Validate a remote policy
 pub async fn validate(path: &str, value: Value) -> Result<Response, String> {
     let response = gloo_net::http::Request::post(&format!("/api/policy/v1alpha1/{path}"))
-        .query([("format", "json")])
+        .query([("format", "json"), ("output", "true")])
         .json(&value)
         .map_err(|err| format!("Failed to encode request: {err}"))?
         .send()
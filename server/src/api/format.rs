@@ -15,6 +15,8 @@ pub enum Format {
     #[serde(alias = "pretty")]
     JsonPretty,
     Yaml,
+    /// Failures grouped by the pattern they originated from, as JSON
+    Grouped,
 }
 
 pub enum FormatError {
@@ -44,18 +46,27 @@ impl Format {
         &self,
         result: &EvaluationResult,
         collapse: bool,
+        output: bool,
         fields: Option<String>,
     ) -> Result<String, FormatError> {
         let mut response = Response::new(result);
+        if let Self::Grouped = self {
+            return serde_json::to_string(&response.group_by_pattern(Severity::Error))
+                .map_err(FormatError::Json);
+        }
         if collapse {
             response = response.collapse(Severity::Error);
         }
+        if !output {
+            response = response.without_output();
+        }
         let formatter = match self {
             // FIXME: Rationalizer should use `response` too, currently it ignored the collapse flag
             Self::Html => return Ok(Rationalizer::new(result).rationale()),
             Self::Json => |r| serde_json::to_string(&r).map_err(FormatError::Json),
             Self::JsonPretty => |r| serde_json::to_string_pretty(&r).map_err(FormatError::Json),
             Self::Yaml => |r| serde_yaml::to_string(&r).map_err(FormatError::Yaml),
+            Self::Grouped => unreachable!("handled above"),
         };
         match fields {
             None => formatter(&response.as_view()),
@@ -66,7 +77,7 @@ impl Format {
     pub fn content_type(&self) -> &'static str {
         match self {
             Self::Html => "text/html; charset=utf-8",
-            Self::Json | Self::JsonPretty => "application/json",
+            Self::Json | Self::JsonPretty | Self::Grouped => "application/json",
             Self::Yaml => "application/yaml",
         }
     }
@@ -77,6 +88,7 @@ impl From<String> for Format {
         match name.as_str() {
             "json" | "application/json" => Self::Json,
             "pretty" => Self::JsonPretty,
+            "grouped" => Self::Grouped,
             "yaml" | "application/yaml" | "application/x-yaml" | "text/x-yaml" => Self::Yaml,
             _ => Self::Html,
         }
@@ -103,11 +115,11 @@ mod test {
             .await
             .unwrap();
         assert!(Format::Json
-            .format(&result, true, Some(String::from("name")))
+            .format(&result, true, false, Some(String::from("name")))
             .is_ok());
         assert!(Format::Json
-            .format(&result, true, Some(String::from("fart")))
+            .format(&result, true, false, Some(String::from("fart")))
             .is_err());
-        assert!(Format::Json.format(&result, true, None).is_ok());
+        assert!(Format::Json.format(&result, true, false, None).is_ok());
     }
 }
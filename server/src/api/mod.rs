@@ -1,4 +1,7 @@
 use crate::api::format::Format;
+use crate::attestation::Attestor;
+use crate::cache::ResultCache;
+use crate::logging::RequestId;
 use crate::playground::PlaygroundState;
 use actix_web::{
     get,
@@ -9,7 +12,7 @@ use actix_web::{
 };
 use seedwing_policy_engine::runtime::{
     monitor::dispatcher::Monitor, statistics::monitor::Statistics, EvalContext, EvalOptions,
-    EvaluationResult, RuntimeError, World,
+    EvaluationResult, Response, RuntimeError, World,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -23,6 +26,27 @@ pub use openapi::*;
 use seedwing_policy_engine::lang::Severity;
 use seedwing_policy_engine::runtime::config::ConfigContext;
 use seedwing_policy_engine::runtime::metadata::ComponentMetadata;
+use seedwing_policy_engine::value::RuntimeValue;
+
+/// Response header carrying the overall severity of an evaluation, like `none` or `error`.
+pub const SEVERITY_HEADER: &str = "x-seedwing-severity";
+
+/// Default maximum size of a request body (16 MiB).
+pub const DEFAULT_MAX_INPUT_SIZE: usize = 16 * 1024 * 1024;
+
+/// JSON extractor configuration, limiting the size of the payload.
+///
+/// Payloads exceeding the limit are rejected with `413 Payload Too Large` before being parsed.
+/// Compressed payloads (`Content-Encoding: gzip`) are decompressed transparently, with the limit
+/// applying to the decompressed size, so that decompression bombs are rejected too.
+pub fn json_config(max_input_size: usize) -> web::JsonConfig {
+    web::JsonConfig::default().limit(max_input_size)
+}
+
+/// Raw payload extractor configuration, limiting the size of the payload.
+pub fn payload_config(max_input_size: usize) -> web::PayloadConfig {
+    web::PayloadConfig::new(max_input_size)
+}
 
 #[get("/policy/v1alpha1/{path:.*}")]
 pub async fn get_policy(world: web::Data<World>, path: web::Path<String>) -> impl Responder {
@@ -41,12 +65,59 @@ pub async fn get_policy(world: web::Data<World>, path: web::Path<String>) -> imp
     }
 }
 
+#[get("/catalog")]
+pub async fn get_catalog(world: web::Data<World>) -> impl Responder {
+    HttpResponse::Ok().json(world.catalog())
+}
+
+#[derive(serde::Deserialize)]
+pub struct PatternsQuery {
+    /// glob expression selecting the patterns, all patterns if missing
+    glob: Option<String>,
+}
+
+#[get("/patterns")]
+pub async fn get_patterns(
+    world: web::Data<World>,
+    query: web::Query<PatternsQuery>,
+) -> impl Responder {
+    let glob = query.glob.as_deref().unwrap_or("**");
+    let names: Vec<String> = world
+        .patterns_matching(glob)
+        .into_iter()
+        .map(|(name, _)| name.as_type_str())
+        .collect();
+    HttpResponse::Ok().json(names)
+}
+
+#[derive(Serialize)]
+pub struct FunctionInfo {
+    name: String,
+    parameters: Vec<String>,
+}
+
+/// All natively implemented functions with their parameters, for tooling like autocompletion.
+#[get("/functions")]
+pub async fn get_functions(world: web::Data<World>) -> impl Responder {
+    let functions: Vec<FunctionInfo> = world
+        .functions()
+        .into_iter()
+        .map(|(name, parameters)| FunctionInfo {
+            name: name.as_type_str(),
+            parameters,
+        })
+        .collect();
+    HttpResponse::Ok().json(functions)
+}
+
 #[derive(serde::Deserialize)]
 pub struct PolicyQuery {
     opa: Option<bool>,
     collapse: Option<bool>,
     format: Option<Format>,
     select: Option<String>, // for minimal, pass 'select=output'
+    /// include the (possibly transformed) output values, implied when selecting 'output'
+    output: Option<bool>,
     /// don't respond with HTTP errors in case of a failed policy
     no_error: Option<bool>,
 }
@@ -56,6 +127,8 @@ pub enum OutputEncoding {
     Seedwing {
         format: Format,
         collapse: bool,
+        /// include output values in the response
+        output: bool,
         select: Option<String>,
         /// only return an HTTP error code when processing (not the policy itself) failed
         no_error: bool,
@@ -68,6 +141,7 @@ impl Default for OutputEncoding {
         Self::Seedwing {
             format: Format::Html,
             collapse: false,
+            output: false,
             select: None,
             no_error: false,
         }
@@ -82,29 +156,137 @@ impl OutputEncoding {
 
         let mime = accept.preference();
         let format = query.format.unwrap_or_else(|| mime.to_string().into());
+        let output = query.output.unwrap_or_else(|| {
+            query
+                .select
+                .as_deref()
+                .map(|select| select.split(',').any(|field| field == "output"))
+                .unwrap_or_default()
+        });
         Self::Seedwing {
             format,
             collapse: query.collapse.unwrap_or_default(),
+            output,
             select: query.select,
             no_error: query.no_error.unwrap_or_default(),
         }
     }
 }
 
+/// Evaluate a policy against a JSON request body.
+///
+/// The body may be compressed, declared by a `Content-Encoding` header like `gzip`.
 #[post("/policy/v1alpha1/{path:.*}")]
 pub async fn post_policy(
     world: web::Data<World>,
     monitor: web::Data<Mutex<Monitor>>,
+    cache: Option<web::Data<ResultCache>>,
     path: web::Path<String>,
     accept: web::Header<header::Accept>,
     query: web::Query<PolicyQuery>,
+    request_id: Option<web::ReqData<RequestId>>,
     value: web::Json<Value>,
 ) -> impl Responder {
     let path = path.into_inner().trim_matches('/').replace('/', "::");
 
     let encoding = OutputEncoding::from_request(accept.into_inner(), query.into_inner());
 
-    run_eval(monitor.into_inner(), &world, path, value.0, encoding).await
+    run_eval(
+        monitor.into_inner(),
+        cache.as_deref(),
+        &world,
+        path,
+        value.0.into(),
+        encoding,
+        request_id.as_deref(),
+    )
+    .await
+}
+
+/// Evaluate a policy against a raw request body.
+///
+/// JSON (`application/json`, `*/*+json`) and text (`text/*`) content is decoded, anything else
+/// is passed on as octets. Like for `post_policy`, the body may be compressed.
+#[post("/evaluate-bytes/{path:.*}")]
+pub async fn post_bytes(
+    world: web::Data<World>,
+    monitor: web::Data<Mutex<Monitor>>,
+    cache: Option<web::Data<ResultCache>>,
+    path: web::Path<String>,
+    accept: web::Header<header::Accept>,
+    content_type: Option<web::Header<header::ContentType>>,
+    query: web::Query<PolicyQuery>,
+    request_id: Option<web::ReqData<RequestId>>,
+    body: web::Bytes,
+) -> impl Responder {
+    let path = path.into_inner().trim_matches('/').replace('/', "::");
+
+    let value = match content_type.map(|content_type| content_type.into_inner().0) {
+        Some(mime)
+            if mime.subtype() == "json" || mime.suffix().map(|s| s.as_str()) == Some("json") =>
+        {
+            match serde_json::from_slice::<Value>(&body) {
+                Ok(value) => value.into(),
+                Err(err) => {
+                    return HttpResponse::BadRequest().json(json!({ "error": err.to_string() }))
+                }
+            }
+        }
+        Some(mime) if mime.type_() == "text" => match String::from_utf8(body.to_vec()) {
+            Ok(value) => value.into(),
+            Err(err) => {
+                return HttpResponse::BadRequest().json(json!({ "error": err.to_string() }))
+            }
+        },
+        _ => RuntimeValue::from(body.to_vec()),
+    };
+
+    let encoding = OutputEncoding::from_request(accept.into_inner(), query.into_inner());
+
+    run_eval(
+        monitor.into_inner(),
+        cache.as_deref(),
+        &world,
+        path,
+        value,
+        encoding,
+        request_id.as_deref(),
+    )
+    .await
+}
+
+/// Evaluate a policy, responding with the decision and an attestation of it.
+///
+/// The attestation is an in-toto statement wrapped in a DSSE envelope, signed if the server has
+/// been configured with a key.
+#[post("/attest/v1alpha1/{path:.*}")]
+pub async fn post_attest(
+    world: web::Data<World>,
+    attestor: web::Data<Attestor>,
+    path: web::Path<String>,
+    value: web::Json<Value>,
+) -> HttpResponse {
+    let path = path.into_inner().trim_matches('/').replace('/', "::");
+    let context = EvalContext::new_with_config(ConfigContext::default(), EvalOptions::new());
+
+    match world.evaluate(path.as_str(), value.0, context).await {
+        Ok(result) => {
+            let attestation = attestor.attest(&path, &result);
+            let decision = Response::new(&result).collapse(Severity::Error);
+            HttpResponse::Ok().json(json!({
+                "decision": decision,
+                "attestation": attestation,
+            }))
+        }
+        Err(RuntimeError::NoSuchPattern(name)) => HttpResponse::BadRequest().json(json!({
+            "reason": "NoSuchPattern",
+            "name": name.as_type_str(),
+        })),
+        Err(err) => {
+            log::warn!("failed to run: {err}");
+            HttpResponse::InternalServerError().finish()
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -121,6 +303,7 @@ pub async fn evaluate(
     body: web::Json<EvaluateRequest>,
     accept: web::Header<header::Accept>,
     query: web::Query<PolicyQuery>,
+    request_id: Option<web::ReqData<RequestId>>,
 ) -> HttpResponse {
     let encoding = OutputEncoding::from_request(accept.into_inner(), query.into_inner());
 
@@ -132,12 +315,15 @@ pub async fn evaluate(
                     value,
                     policy: _,
                 } = body.0;
+                // the playground world is rebuilt for every request, don't cache its results
                 run_eval(
                     monitor.into_inner(),
+                    None,
                     &world,
                     format!("playground::{name}"),
-                    value,
+                    value.into(),
                     encoding,
+                    request_id.as_deref(),
                 )
                 .await
             }
@@ -165,21 +351,48 @@ pub async fn evaluate(
     }
 }
 
+/// Compile a policy without keeping it, responding with the problems found.
+///
+/// The body is the source of the policy, in the `playground` package. An empty list of
+/// diagnostics means the policy is valid.
+#[post("/validate")]
+pub async fn validate(state: web::Data<PlaygroundState>, body: web::Bytes) -> HttpResponse {
+    match std::str::from_utf8(&body) {
+        Ok(policy) => HttpResponse::Ok().json(state.validate(policy).await),
+        Err(err) => HttpResponse::BadRequest().json(json!({ "error": err.to_string() })),
+    }
+}
+
 async fn run_eval(
     monitor: Arc<Mutex<Monitor>>,
+    cache: Option<&ResultCache>,
     world: &World,
     path: String,
-    value: Value,
+    value: RuntimeValue,
     encoding: OutputEncoding,
+    request_id: Option<&RequestId>,
 ) -> HttpResponse {
+    if let Some(result) = cache.and_then(|cache| cache.get(&path, &value)) {
+        return return_rationale(result, encoding);
+    }
+
     let context = EvalContext::new(
         seedwing_policy_engine::runtime::TraceConfig::Enabled(monitor.clone()),
         ConfigContext::default(),
         EvalOptions::new(),
     );
 
-    match world.evaluate(path, value, context).await {
-        Ok(result) => return_rationale(result, encoding),
+    match world.evaluate(path.as_str(), value, context).await {
+        Ok(result) => {
+            match request_id {
+                Some(id) => log::info!("{id} evaluated {path}: {}", result.severity()),
+                None => log::info!("evaluated {path}: {}", result.severity()),
+            }
+            if let Some(cache) = cache {
+                cache.insert(&path, &result);
+            }
+            return_rationale(result, encoding)
+        }
         Err(RuntimeError::NoSuchPattern(name)) => HttpResponse::BadRequest().json(json!({
             "reason": "NoSuchPattern",
             "name": name.as_type_str(),
@@ -191,28 +404,41 @@ async fn run_eval(
     }
 }
 
+/// The header carrying the overall severity of an evaluation, for clients only interested in the
+/// verdict.
+fn severity_header(result: &EvaluationResult) -> (&'static str, String) {
+    (SEVERITY_HEADER, result.severity().to_string())
+}
+
+/// Respond with the result of an evaluation.
+///
+/// Unless `no_error` was requested, failing the policy is responded to with
+/// `422 Unprocessable Entity`, other severities with `200 OK`.
 fn return_rationale(result: EvaluationResult, encoding: OutputEncoding) -> HttpResponse {
     match encoding {
         OutputEncoding::Opa => {
             let satisfied = result.severity() < Severity::Error;
-            HttpResponse::Ok().json(serde_json::json!({ "result": satisfied }))
+            HttpResponse::Ok()
+                .insert_header(severity_header(&result))
+                .json(serde_json::json!({ "result": satisfied }))
         }
         OutputEncoding::Seedwing {
             format,
             collapse,
+            output,
             select,
             no_error,
-        } => match format.format(&result, collapse, select) {
+        } => match format.format(&result, collapse, output, select) {
             Ok(rationale) => {
-                if no_error || result.severity() < Severity::Error {
+                let mut response = if no_error || result.severity() < Severity::Error {
                     HttpResponse::Ok()
-                        .content_type(format.content_type())
-                        .body(rationale)
                 } else {
                     HttpResponse::UnprocessableEntity()
-                        .content_type(format.content_type())
-                        .body(rationale)
-                }
+                };
+                response
+                    .insert_header(severity_header(&result))
+                    .content_type(format.content_type())
+                    .body(rationale)
             }
             Err(e) => HttpResponse::BadRequest().json(json!({ "error": e.to_string() })),
         },
@@ -234,3 +460,452 @@ pub async fn version() -> impl Responder {
     );
     HttpResponse::Ok().json(version)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+
+    async fn world(src: &str) -> World {
+        let src = Ephemeral::new("test", src);
+        let mut builder = Builder::new();
+        builder.build(src.iter()).unwrap();
+        builder.finish().await.unwrap()
+    }
+
+    #[actix_web::test]
+    async fn reject_oversized_input() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern any = anything").await))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .app_data(json_config(64))
+                .service(post_policy),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/policy/v1alpha1/test/any")
+            .insert_header((header::ACCEPT, "application/json"))
+            .set_json(json!({ "value": "small" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::post()
+            .uri("/policy/v1alpha1/test/any")
+            .insert_header((header::ACCEPT, "application/json"))
+            .set_json(json!({ "value": "x".repeat(1024) }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn gzip_encoded_input() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        fn gzip(data: &[u8]) -> Vec<u8> {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    world("pattern answer = { value: 42 }").await,
+                ))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .app_data(json_config(1024))
+                .service(post_policy),
+        )
+        .await;
+
+        let post = |body: Vec<u8>| {
+            test::TestRequest::post()
+                .uri("/policy/v1alpha1/test/answer")
+                .insert_header((header::ACCEPT, "application/json"))
+                .insert_header((header::CONTENT_TYPE, "application/json"))
+                .insert_header((header::CONTENT_ENCODING, "gzip"))
+                .set_payload(body)
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, post(gzip(br#"{"value": 42}"#))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(&app, post(gzip(br#"{"value": 41}"#))).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // small when compressed, but exceeding the limit once decompressed
+        let bomb = gzip(format!(r#"{{"value": "{}"}}"#, " ".repeat(64 * 1024)).as_bytes());
+        assert!(bomb.len() < 1024);
+        let resp = test::call_service(&app, post(bomb)).await;
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn catalog() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern any = anything").await))
+                .service(get_catalog),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/catalog").to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        let packages = resp["packages"].as_array().unwrap();
+        let list = packages.iter().find(|p| p["name"] == "list").unwrap();
+        let all = list["patterns"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["path"] == "list::all")
+            .unwrap();
+        assert_eq!(all["parameters"], json!(["pattern"]));
+        assert_eq!(all["function"], json!(true));
+    }
+
+    #[actix_web::test]
+    async fn patterns_glob() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern a\npattern b<x> = x").await))
+                .service(get_patterns),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/patterns?glob=test::*")
+            .to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp, json!(["test::a", "test::b"]));
+
+        let req = test::TestRequest::get()
+            .uri("/patterns?glob=nothing::**")
+            .to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp, json!([]));
+    }
+
+    #[actix_web::test]
+    async fn functions() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern a<x> = x").await))
+                .service(get_functions),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/functions").to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        let functions = resp.as_array().unwrap();
+        let function = |name: &str| functions.iter().find(|f| f["name"] == name);
+        assert_eq!(
+            function("base64::base64"),
+            Some(&json!({ "name": "base64::base64", "parameters": [] }))
+        );
+        assert_eq!(
+            function("list::all"),
+            Some(&json!({ "name": "list::all", "parameters": ["pattern"] }))
+        );
+        assert_eq!(function("test::a"), None);
+    }
+
+    #[actix_web::test]
+    async fn validate_policy() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PlaygroundState::new(Builder::new(), vec![])))
+                .service(validate),
+        )
+        .await;
+
+        let app = &app;
+        let post = move |policy: &'static str| {
+            let req = test::TestRequest::post()
+                .uri("/validate")
+                .insert_header((header::CONTENT_TYPE, "text/plain"))
+                .set_payload(policy)
+                .to_request();
+            test::call_and_read_body_json::<_, _, Value>(app, req)
+        };
+
+        let resp = post("pattern a = { name: string }").await;
+        assert_eq!(resp, json!([]));
+
+        let resp = post("pattern a = { name: b }").await;
+        assert_eq!(
+            resp,
+            json!([{
+                "source": "playground",
+                "span": [20, 21],
+                "line": 1,
+                "column": 21,
+                "message": "pattern not found: b",
+            }])
+        );
+
+        let resp = post("pattern a = {").await;
+        let diagnostics = resp.as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["source"], json!("playground"));
+        assert_eq!(diagnostics[0]["line"], json!(1));
+    }
+
+    #[actix_web::test]
+    async fn evaluate_bytes() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    world(
+                        r#"
+pattern png = base64::base64-encode(string::regexp<"^iVBORw0K">)
+pattern greeting = "hello"
+"#,
+                    )
+                    .await,
+                ))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .service(post_bytes),
+        )
+        .await;
+
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        let req = test::TestRequest::post()
+            .uri("/evaluate-bytes/test/png")
+            .insert_header((header::ACCEPT, "application/json"))
+            .insert_header((header::CONTENT_TYPE, "image/png"))
+            .set_payload(png)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::post()
+            .uri("/evaluate-bytes/test/png")
+            .insert_header((header::ACCEPT, "application/json"))
+            .set_payload(b"GIF89a".to_vec())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let req = test::TestRequest::post()
+            .uri("/evaluate-bytes/test/greeting")
+            .insert_header((header::ACCEPT, "application/json"))
+            .insert_header((header::CONTENT_TYPE, "text/plain; charset=utf-8"))
+            .set_payload("hello")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::post()
+            .uri("/evaluate-bytes/test/greeting")
+            .insert_header((header::ACCEPT, "application/json"))
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .set_payload(r#""hello""#)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn transformed_output() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern count = list::count").await))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .service(post_policy),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/policy/v1alpha1/test/count?format=json")
+            .set_json(json!([1, 2, 3]))
+            .to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+        assert!(resp.get("output").is_none());
+
+        let req = test::TestRequest::post()
+            .uri("/policy/v1alpha1/test/count?format=json&output=true")
+            .set_json(json!([1, 2, 3]))
+            .to_request();
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp["output"], json!(3));
+    }
+
+    #[actix_web::test]
+    async fn grouped_failures() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    world(
+                        r#"
+pattern names = {
+    first: name,
+    second: name,
+    third: name,
+}
+pattern name = string
+"#,
+                    )
+                    .await,
+                ))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .service(post_policy),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/policy/v1alpha1/test/names?format=grouped")
+            .set_json(json!({ "first": 1, "second": "bob", "third": true }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let resp: Value = test::read_body_json(resp).await;
+        assert_eq!(resp.as_array().unwrap().len(), 1);
+        assert_eq!(resp[0]["pattern"], json!("test::name"));
+        assert_eq!(resp[0]["count"], json!(2));
+        assert_eq!(resp[0]["examples"], json!([1, true]));
+    }
+
+    #[actix_web::test]
+    async fn severity_in_header() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    world(
+                        r#"
+pattern answer = 42
+pattern person = lang::deprecated-field<"name", "use 'full-name'">
+"#,
+                    )
+                    .await,
+                ))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .service(post_policy),
+        )
+        .await;
+
+        let app = &app;
+        let call = move |uri: &str, value: Value| {
+            let req = test::TestRequest::post()
+                .uri(uri)
+                .insert_header((header::ACCEPT, "application/json"))
+                .set_json(value)
+                .to_request();
+            test::call_service(app, req)
+        };
+        let severity = |resp: &actix_web::dev::ServiceResponse| {
+            resp.headers()
+                .get(SEVERITY_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string)
+        };
+
+        let resp = call("/policy/v1alpha1/test/answer", json!(42)).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(severity(&resp).as_deref(), Some("none"));
+
+        let resp = call("/policy/v1alpha1/test/person", json!({"name": "bob"})).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(severity(&resp).as_deref(), Some("warning"));
+
+        let resp = call("/policy/v1alpha1/test/answer", json!(41)).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(severity(&resp).as_deref(), Some("error"));
+
+        // the status can be decoupled from the severity
+        let resp = call("/policy/v1alpha1/test/answer?no_error=true", json!(41)).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(severity(&resp).as_deref(), Some("error"));
+
+        let resp = call("/policy/v1alpha1/test/answer?opa=true", json!(41)).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(severity(&resp).as_deref(), Some("error"));
+    }
+
+    #[actix_web::test]
+    async fn cached_result() {
+        let cache = web::Data::new(ResultCache::new(10, std::time::Duration::from_secs(60)));
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(world("pattern answer = 42").await))
+                .app_data(web::Data::new(Mutex::new(Monitor::new())))
+                .app_data(cache.clone())
+                .service(post_policy),
+        )
+        .await;
+
+        let post = |value: Value| {
+            test::TestRequest::post()
+                .uri("/policy/v1alpha1/test/answer")
+                .set_json(value)
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, post(json!(42))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(cache.hits(), 0);
+
+        let resp = test::call_service(&app, post(json!(42))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(cache.hits(), 1);
+
+        // a different input is evaluated again
+        let resp = test::call_service(&app, post(json!(41))).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[actix_web::test]
+    async fn attested_decision() {
+        use base64::Engine;
+        use sha2::{Digest, Sha256};
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    world("pattern person = { name: string }").await,
+                ))
+                .app_data(web::Data::new(Attestor::default()))
+                .service(post_attest),
+        )
+        .await;
+
+        let input = json!({ "name": "bob", "age": 42 });
+        let req = test::TestRequest::post()
+            .uri("/attest/v1alpha1/test/person")
+            .set_json(&input)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp: Value = test::read_body_json(resp).await;
+        assert_eq!(resp["decision"]["severity"], json!("none"));
+
+        let attestation = &resp["attestation"];
+        assert_eq!(attestation["payloadType"], "application/vnd.in-toto+json");
+        // no key configured
+        assert_eq!(attestation["signatures"], json!([]));
+
+        let payload = base64::engine::general_purpose::STANDARD
+            .decode(attestation["payload"].as_str().unwrap())
+            .unwrap();
+        let statement: Value = serde_json::from_slice(&payload).unwrap();
+
+        let expected = hex::encode(Sha256::digest(
+            RuntimeValue::from(input).as_canonical_json().as_bytes(),
+        ));
+        assert_eq!(statement["subject"][0]["digest"]["sha256"], json!(expected));
+        assert_eq!(statement["predicate"]["policy"], "test::person");
+        assert_eq!(statement["predicate"]["satisfied"], true);
+    }
+}
@@ -8,7 +8,6 @@ use okapi::openapi3::{
     Components, ExampleValue, Info, MediaType, OpenApi, Operation, PathItem, Ref, RefOr,
     RequestBody, Response, Responses, SchemaObject, Tag,
 };
-use okapi::schemars::schema::Metadata;
 use seedwing_policy_engine::runtime::{Example, World};
 use serde_json::json;
 use std::collections::BTreeSet;
@@ -83,17 +82,8 @@ pub async fn openapi(world: web::Data<World>) -> HttpResponse {
         let mut content = okapi::Map::new();
 
         let mut schema = pattern.as_json_schema(world.as_ref(), &vec![]);
-        // set some metadata
-        schema.metadata = Some(Box::new(Metadata {
-            id: None,
-            title: Some(name.as_type_str()),
-            description: None,
-            default: None,
-            deprecated: false,
-            read_only: false,
-            write_only: false,
-            examples: vec![],
-        }));
+        // set some metadata, keeping the description taken from the documentation
+        schema.metadata().title = Some(name.as_type_str());
 
         // insert to global schemas list
         schemas.insert(name.as_type_str(), schema.clone());
@@ -0,0 +1,166 @@
+//! Attesting policy decisions, using in-toto statements wrapped in DSSE envelopes.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use seedwing_policy_engine::dsse::{pae, Envelope, Signature};
+use seedwing_policy_engine::lang::Severity;
+use seedwing_policy_engine::runtime::EvaluationResult;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+pub const PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+pub const STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v0.1";
+pub const PREDICATE_TYPE: &str = "https://seedwing.io/attestation/decision/v1";
+
+#[derive(Debug, thiserror::Error)]
+pub enum AttestationError {
+    #[error("unable to read the key: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unable to decode the PEM encoded key: {0}")]
+    Pem(#[from] pem::PemError),
+    #[error("the key must be a PKCS#8 encoded Ed25519 private key")]
+    InvalidKey,
+}
+
+/// Signs the attestations with an Ed25519 key.
+pub struct Signer {
+    key: Ed25519KeyPair,
+    keyid: String,
+}
+
+impl Signer {
+    /// Create a signer from a PEM encoded PKCS#8 private key, as created by
+    /// `openssl genpkey -algorithm ed25519`.
+    pub fn from_pem(pem: &[u8]) -> Result<Self, AttestationError> {
+        let pem = pem::parse(pem)?;
+        let key = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&pem.contents)
+            .map_err(|_| AttestationError::InvalidKey)?;
+        // identify the key by the digest of its public key
+        let keyid = hex::encode(Sha256::digest(key.public_key().as_ref()));
+        Ok(Self { key, keyid })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, AttestationError> {
+        Self::from_pem(&std::fs::read(path)?)
+    }
+
+    fn sign(&self, message: &[u8]) -> Signature {
+        Signature {
+            keyid: Some(self.keyid.clone()),
+            sig: STANDARD.encode(self.key.sign(message)),
+        }
+    }
+}
+
+/// Creates attestations of evaluation results, signed if a key is configured.
+#[derive(Default)]
+pub struct Attestor {
+    signer: Option<Signer>,
+}
+
+impl Attestor {
+    pub fn new(signer: Option<Signer>) -> Self {
+        Self { signer }
+    }
+
+    /// The in-toto statement attesting the decision for the input of the result.
+    ///
+    /// The input is identified by the SHA-256 digest of its canonical JSON serialization.
+    pub fn statement(&self, policy: &str, result: &EvaluationResult) -> Value {
+        let digest = hex::encode(Sha256::digest(
+            result.input().as_canonical_json().as_bytes(),
+        ));
+        let (severity, reason) = result.outcome();
+
+        json!({
+            "_type": STATEMENT_TYPE,
+            "subject": [{
+                "name": "input",
+                "digest": { "sha256": digest },
+            }],
+            "predicateType": PREDICATE_TYPE,
+            "predicate": {
+                "policy": policy,
+                "satisfied": severity < Severity::Error,
+                "severity": severity,
+                "reason": reason,
+                "timestamp": Utc::now().to_rfc3339(),
+            },
+        })
+    }
+
+    /// Create the envelope of the statement, signing it if a key is configured.
+    pub fn attest(&self, policy: &str, result: &EvaluationResult) -> Envelope {
+        let payload = self.statement(policy, result).to_string();
+        let signatures = self
+            .signer
+            .iter()
+            .map(|signer| signer.sign(&pae(PAYLOAD_TYPE, payload.as_bytes())))
+            .collect();
+
+        Envelope {
+            payload_type: PAYLOAD_TYPE.into(),
+            payload: payload.into_bytes(),
+            signatures,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{UnparsedPublicKey, ED25519};
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+    use seedwing_policy_engine::runtime::EvalContext;
+
+    #[actix_web::test]
+    async fn signed_attestation() {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let pem = pem::encode(&pem::Pem {
+            tag: "PRIVATE KEY".into(),
+            contents: pkcs8.as_ref().to_vec(),
+        });
+        let signer = Signer::from_pem(pem.as_bytes()).unwrap();
+        let public_key = signer.key.public_key().as_ref().to_vec();
+        let attestor = Attestor::new(Some(signer));
+
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern answer = 42").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+        let result = world
+            .evaluate("test::answer", 42, EvalContext::default())
+            .await
+            .unwrap();
+
+        let envelope = attestor.attest("test::answer", &result);
+        assert_eq!(envelope.signatures.len(), 1);
+
+        let sig = STANDARD.decode(&envelope.signatures[0].sig).unwrap();
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(&envelope.pae(), &sig)
+            .unwrap();
+
+        let statement: Value = serde_json::from_slice(&envelope.payload).unwrap();
+        assert_eq!(statement["predicate"]["policy"], "test::answer");
+        assert_eq!(statement["predicate"]["satisfied"], true);
+    }
+
+    #[test]
+    fn invalid_key() {
+        let pem = pem::encode(&pem::Pem {
+            tag: "PRIVATE KEY".into(),
+            contents: vec![1, 2, 3],
+        });
+        assert!(matches!(
+            Signer::from_pem(pem.as_bytes()),
+            Err(AttestationError::InvalidKey)
+        ));
+    }
+}
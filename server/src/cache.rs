@@ -0,0 +1,198 @@
+//! Caching evaluation results, for re-checking the same input against the same pattern.
+
+use seedwing_policy_engine::runtime::EvaluationResult;
+use seedwing_policy_engine::value::RuntimeValue;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default time an entry stays in the cache.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    path: String,
+    input: u64,
+}
+
+struct Entry {
+    result: EvaluationResult,
+    inserted: Instant,
+    /// position in the recency order, higher being more recently used
+    used: u64,
+}
+
+/// The entries, along with their order of use for evicting the least recently used one.
+#[derive(Default)]
+struct Entries {
+    entries: HashMap<Key, Entry>,
+    order: BTreeMap<u64, Key>,
+    next: u64,
+}
+
+impl Entries {
+    fn insert(&mut self, key: Key, result: EvaluationResult) {
+        self.order.insert(self.next, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                result,
+                inserted: Instant::now(),
+                used: self.next,
+            },
+        );
+        self.next += 1;
+    }
+
+    fn touch(&mut self, key: &Key) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.used);
+            entry.used = self.next;
+            self.order.insert(self.next, key.clone());
+            self.next += 1;
+        }
+    }
+
+    fn remove(&mut self, key: &Key) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.used);
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, key)) = self.order.pop_first() {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// A bounded cache of evaluation results, keyed by pattern path and input hash.
+///
+/// When full, the least recently used entry is evicted.
+///
+/// A cache with a capacity of zero is disabled, never storing any results.
+///
+/// **NOTE:** A result served from the cache doesn't emit monitoring events, as the pattern isn't
+/// evaluated again.
+pub struct ResultCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(0, DEFAULT_CACHE_TTL)
+    }
+}
+
+impl ResultCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0 && !self.ttl.is_zero()
+    }
+
+    /// The number of requests served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn key(path: &str, input: &RuntimeValue) -> Key {
+        let mut hasher = DefaultHasher::new();
+        input.as_canonical_json().hash(&mut hasher);
+        Key {
+            path: path.to_string(),
+            input: hasher.finish(),
+        }
+    }
+
+    /// Look up a result which is still valid.
+    pub fn get(&self, path: &str, input: &RuntimeValue) -> Option<EvaluationResult> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let key = Self::key(path, input);
+        let mut entries = self.entries.lock().unwrap();
+
+        let result = match entries.entries.get(&key) {
+            Some(entry) if entry.inserted.elapsed() >= self.ttl => {
+                entries.remove(&key);
+                return None;
+            }
+            // guard against hash collisions
+            Some(entry) if entry.result.input().as_ref() == input => entry.result.clone(),
+            _ => return None,
+        };
+
+        entries.touch(&key);
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(result)
+    }
+
+    /// Store a result, evicting the least recently used entry when the cache is full.
+    pub fn insert(&self, path: &str, result: &EvaluationResult) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let key = Self::key(path, &result.input());
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.remove(&key);
+        if entries.entries.len() >= self.capacity {
+            entries.evict_least_recently_used();
+        }
+        entries.insert(key, result.clone());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+    use seedwing_policy_engine::runtime::EvalContext;
+
+    #[actix_web::test]
+    async fn evicts_least_recently_used() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern answer = 42").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let cache = ResultCache::new(2, DEFAULT_CACHE_TTL);
+        let mut results = Vec::new();
+        for input in [1, 2, 3] {
+            results.push(
+                world
+                    .evaluate("test::answer", input, EvalContext::default())
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        cache.insert("test::answer", &results[0]);
+        cache.insert("test::answer", &results[1]);
+        assert!(cache.get("test::answer", &results[0].input()).is_some());
+        cache.insert("test::answer", &results[2]);
+
+        assert!(cache.get("test::answer", &results[0].input()).is_some());
+        assert!(cache.get("test::answer", &results[1].input()).is_none());
+        assert!(cache.get("test::answer", &results[2].input()).is_some());
+        assert_eq!(cache.hits(), 3);
+    }
+}
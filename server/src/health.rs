@@ -0,0 +1,109 @@
+use actix_web::{get, web, HttpResponse};
+use serde_json::json;
+use std::sync::RwLock;
+
+/// Readiness of the server, reflecting the status of the world.
+#[derive(Debug)]
+pub struct Readiness(RwLock<Result<(), String>>);
+
+impl Readiness {
+    /// Create a new state, which is not ready until [`Self::ready`] gets called.
+    pub fn new() -> Self {
+        Self(RwLock::new(Err("world not built yet".into())))
+    }
+
+    /// Mark the world as built successfully.
+    pub fn ready(&self) {
+        *self.0.write().unwrap() = Ok(());
+    }
+
+    /// Mark the server as not ready, e.g. when shutting down.
+    pub fn not_ready<S: Into<String>>(&self, reason: S) {
+        *self.0.write().unwrap() = Err(reason.into());
+    }
+
+    fn status(&self) -> Result<(), String> {
+        self.0.read().unwrap().clone()
+    }
+}
+
+impl Default for Readiness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Liveness probe, reporting that the process is up.
+#[get("/healthz")]
+pub async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().json(json!({ "status": "ok" }))
+}
+
+/// Readiness probe, reporting if the world was built successfully and is currently valid.
+#[get("/readyz")]
+pub async fn readyz(readiness: web::Data<Readiness>) -> HttpResponse {
+    match readiness.status() {
+        Ok(()) => HttpResponse::Ok().json(json!({ "status": "ready" })),
+        Err(reason) => HttpResponse::ServiceUnavailable().json(json!({
+            "status": "not-ready",
+            "reason": reason,
+        })),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    #[actix_web::test]
+    async fn ready() {
+        let readiness = web::Data::new(Readiness::new());
+        readiness.ready();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(readiness.clone())
+                .service(healthz)
+                .service(readyz),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/healthz").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::get().uri("/readyz").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn not_ready() {
+        let readiness = web::Data::new(Readiness::new());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(readiness.clone())
+                .service(healthz)
+                .service(readyz),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/readyz").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        // shutting down makes the server not ready, while it stays alive draining requests
+        readiness.ready();
+        readiness.not_ready("shutting down");
+
+        let req = test::TestRequest::get().uri("/readyz").to_request();
+        let resp: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(resp["reason"], "shutting down");
+
+        let req = test::TestRequest::get().uri("/healthz").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
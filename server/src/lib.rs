@@ -1,19 +1,32 @@
 #![deny(warnings)]
 
 mod api;
+mod attestation;
+mod cache;
 mod cli;
+mod health;
+mod logging;
 mod metrics;
 mod playground;
+mod rate_limit;
+mod shutdown;
 mod stream;
 mod ui;
+mod workers;
 
 use actix_web::{web, App, HttpServer};
+use attestation::{Attestor, Signer};
+use cache::ResultCache;
+use futures_util::future::{select, Either};
 use playground::PlaygroundState;
+use rate_limit::RateLimit;
 use seedwing_policy_engine::data::DirectoryDataSource;
 use seedwing_policy_engine::runtime::ErrorPrinter;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process::exit;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
 use seedwing_policy_engine::lang::builder::Builder as PolicyBuilder;
@@ -25,12 +38,73 @@ use seedwing_policy_engine::runtime::statistics::monitor::Statistics;
 #[cfg(feature = "frontend")]
 use seedwing_policy_server_embedded_swaggerui::SwaggerOptions;
 
-pub async fn run(
-    policy_directories: Vec<PathBuf>,
-    data_directories: Vec<PathBuf>,
-    bind: String,
-    port: u16,
-) -> std::io::Result<()> {
+pub use api::DEFAULT_MAX_INPUT_SIZE;
+pub use cache::DEFAULT_CACHE_TTL;
+pub use logging::{configure_logger, LogFormat};
+pub use rate_limit::RateLimitConfig;
+pub use shutdown::DEFAULT_SHUTDOWN_TIMEOUT;
+pub use workers::worker_count;
+
+/// Configuration of the policy server.
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    /// Directories to load policies from.
+    pub policy_directories: Vec<PathBuf>,
+    /// Directories to load data from.
+    pub data_directories: Vec<PathBuf>,
+    /// Address to listen on.
+    pub bind: String,
+    /// Port to listen on.
+    pub port: u16,
+    /// Maximum size of a request body, in bytes.
+    pub max_input_size: usize,
+    /// Maximum number of cached evaluation results, disabled if zero.
+    pub cache_size: usize,
+    /// Time a cached evaluation result stays valid.
+    pub cache_ttl: Duration,
+    /// Time in-flight requests get to complete when shutting down.
+    pub shutdown_timeout: Duration,
+    /// Number of workers, defaults to the number of available CPUs.
+    pub workers: Option<NonZeroUsize>,
+    /// Rate of requests per client, unlimited if not set.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Private key signing attestations, which are unsigned if not set.
+    pub attestation_key: Option<PathBuf>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            policy_directories: Vec::new(),
+            data_directories: Vec::new(),
+            bind: "0.0.0.0".into(),
+            port: 8080,
+            max_input_size: DEFAULT_MAX_INPUT_SIZE,
+            cache_size: 0,
+            cache_ttl: DEFAULT_CACHE_TTL,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            workers: None,
+            rate_limit: None,
+            attestation_key: None,
+        }
+    }
+}
+
+pub async fn run(config: ServerConfig) -> std::io::Result<()> {
+    let ServerConfig {
+        policy_directories,
+        data_directories,
+        bind,
+        port,
+        max_input_size,
+        cache_size,
+        cache_ttl,
+        shutdown_timeout,
+        workers,
+        rate_limit,
+        attestation_key,
+    } = config;
+
     let mut errors = Vec::new();
 
     let mut builder = PolicyBuilder::new();
@@ -49,7 +123,10 @@ pub async fn run(
         }
     }
 
+    let builds = metrics::BuildMetrics::global();
+
     if !errors.is_empty() {
+        builds.record(&Err::<(), _>(&errors));
         ErrorPrinter::new(builder.source_cache()).display(&errors);
         exit(-1)
     }
@@ -60,6 +137,28 @@ pub async fn run(
     }
 
     let result = builder.finish().await;
+    builds.record(&result);
+
+    let readiness = web::Data::new(health::Readiness::new());
+
+    // attestations are only signed when a key is configured
+    let signer = match attestation_key {
+        Some(path) => match Signer::from_file(&path) {
+            Ok(signer) => Some(signer),
+            Err(err) => {
+                log::error!("Unable to load attestation key {}: {err}", path.display());
+                exit(-4);
+            }
+        },
+        None => None,
+    };
+    let attestor = web::Data::new(Attestor::new(signer));
+
+    // shared by all workers, disabled with a size of zero
+    let cache = web::Data::new(ResultCache::new(cache_size, cache_ttl));
+
+    // shared by all workers, disabled if not configured
+    let rate_limit = RateLimit::new(rate_limit);
 
     let monitor = Arc::new(Mutex::new(Monitor::new()));
 
@@ -69,31 +168,42 @@ pub async fn run(
 
     match result {
         Ok(world) => {
+            readiness.ready();
+
             // todo: wire the receiver to a statistics gatherer.
             let mut receiver = monitor.lock().await.subscribe("".into()).await;
 
             let gatherer = statistics.clone();
+            let (stop_gatherer, mut gatherer_stopped) = tokio::sync::oneshot::channel::<()>();
 
-            tokio::spawn(async move {
+            let gathering = tokio::spawn(async move {
                 loop {
-                    if let Some(result) = receiver.recv().await {
-                        if let MonitorEvent::Complete(event) = &result {
-                            if let Some(elapsed) = event.elapsed {
-                                if let Some(name) = result.ty().name() {
-                                    gatherer
-                                        .lock()
-                                        .await
-                                        .record(name, elapsed, &event.completion)
-                                        .await;
-                                }
+                    let stopped =
+                        match select(Box::pin(receiver.recv()), &mut gatherer_stopped).await {
+                            Either::Left((Some(event), _)) => {
+                                record(&gatherer, event).await;
+                                false
                             }
+                            Either::Left((None, _)) => break,
+                            Either::Right(_) => true,
+                        };
+
+                    if stopped {
+                        // flush the events which had been reported before shutting down
+                        while let Ok(event) = receiver.try_recv() {
+                            record(&gatherer, event).await;
                         }
+                        break;
                     }
                 }
             });
 
+            let recorded = statistics.clone();
+            let draining = readiness.clone();
             let server = HttpServer::new(move || {
                 let app = App::new()
+                    .wrap(rate_limit.clone())
+                    .wrap(logging::request_logger())
                     .app_data(web::Data::new(world.clone()))
                     // use "from" in case of an existing Arc
                     .app_data(web::Data::from(monitor.clone()))
@@ -102,15 +212,27 @@ pub async fn run(
                     .app_data(web::Data::new(PlaygroundState::new(
                         builder.clone(),
                         sources.clone(),
-                    )));
+                    )))
+                    // reject oversized inputs before they get deserialized
+                    .app_data(api::json_config(max_input_size))
+                    .app_data(api::payload_config(max_input_size))
+                    .app_data(cache.clone())
+                    .app_data(attestor.clone())
+                    .app_data(readiness.clone());
 
                 let app = app
                     .service(
                         web::scope("/api")
                             .service(api::openapi)
+                            .service(api::get_catalog)
+                            .service(api::get_patterns)
+                            .service(api::get_functions)
                             .service(api::get_policy)
                             .service(api::post_policy)
+                            .service(api::post_bytes)
+                            .service(api::post_attest)
                             .service(api::evaluate)
+                            .service(api::validate)
                             .service(api::statistics)
                             .service(api::version),
                     )
@@ -119,7 +241,9 @@ pub async fn run(
                             .service(stream::statistics_stream)
                             .service(stream::monitor_stream),
                     )
-                    .service(metrics::prometheus);
+                    .service(metrics::prometheus)
+                    .service(health::healthz)
+                    .service(health::readyz);
 
                 #[cfg(feature = "frontend")]
                 let app = {
@@ -145,9 +269,30 @@ pub async fn run(
                 app
             });
 
-            log::info!("starting up at http://{}:{}/", bind, port);
+            let workers = worker_count(workers);
+            log::info!("starting up at http://{bind}:{port}/ with {workers} workers");
+
+            let server = server
+                .workers(workers)
+                .disable_signals()
+                .shutdown_timeout(shutdown_timeout.as_secs())
+                .bind((bind, port))?
+                .run();
+            // stop reporting ready, so no new requests get routed to the server while it drains
+            let signal = async move {
+                shutdown::signal().await;
+                draining.not_ready("shutting down");
+            };
+            shutdown::serve_until(server, signal).await?;
 
-            server.bind((bind, port))?.run().await
+            let _ = stop_gatherer.send(());
+            let _ = gathering.await;
+            log::info!(
+                "recorded statistics for {} patterns",
+                recorded.lock().await.snapshot().len()
+            );
+
+            Ok(())
         }
         Err(errors) => {
             ErrorPrinter::new(builder.source_cache()).display(&errors);
@@ -155,3 +300,18 @@ pub async fn run(
         }
     }
 }
+
+/// Record a completed evaluation in the statistics.
+async fn record(statistics: &Mutex<Statistics<100>>, event: MonitorEvent) {
+    if let MonitorEvent::Complete(complete) = &event {
+        if let Some(elapsed) = complete.elapsed {
+            if let Some(name) = event.ty().name() {
+                statistics
+                    .lock()
+                    .await
+                    .record(name, elapsed, &complete.completion)
+                    .await;
+            }
+        }
+    }
+}
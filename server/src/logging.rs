@@ -0,0 +1,187 @@
+//! Configuring the format of the server logs.
+
+use actix_web::dev::ServiceRequest;
+use actix_web::middleware::Logger;
+use chrono::Utc;
+use env_logger::Builder;
+use log::Record;
+use serde_json::json;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Header carrying the id of a request, e.g. set by a proxy.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Maximum length of a request id taken from the [`REQUEST_ID_HEADER`].
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// The id of a request, available from the request extensions.
+///
+/// Log messages about a request start with it, as `request_id=<id> `, which the JSON format
+/// turns into a `request_id` field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "request_id={}", self.0)
+    }
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// A JSON object per line, for ingestion by log pipelines
+    Json,
+}
+
+/// Apply the log format to the builder of the logger.
+pub fn configure_logger(builder: &mut Builder, format: LogFormat) -> &mut Builder {
+    match format {
+        LogFormat::Text => builder,
+        LogFormat::Json => builder.format(write_json),
+    }
+}
+
+/// Write the record as a single line of JSON.
+pub fn write_json<W: Write>(out: &mut W, record: &Record) -> io::Result<()> {
+    let message = record.args().to_string();
+    let (request_id, message) = split_request_id(&message);
+
+    let mut line = json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "message": message,
+    });
+    if let Some(request_id) = request_id {
+        line["request_id"] = request_id.into();
+    }
+    writeln!(out, "{line}")
+}
+
+/// Split the request id off a message about a request.
+fn split_request_id(message: &str) -> (Option<&str>, &str) {
+    match message
+        .strip_prefix("request_id=")
+        .and_then(|message| message.split_once(' '))
+    {
+        Some((request_id, message)) => (Some(request_id), message),
+        None => (None, message),
+    }
+}
+
+/// Ids taken from the request must not break up the log line.
+fn valid_request_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Log each request, along with its id and the response status.
+///
+/// The id is taken from the [`REQUEST_ID_HEADER`] if present and valid, otherwise a sequential
+/// id gets assigned. It is stored as a [`RequestId`] in the request extensions, for handlers to
+/// include in their logs.
+pub fn request_logger() -> Logger {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+    Logger::new(r#"request_id=%{request_id}xi %a "%r" %s %b %T"#).custom_request_replace(
+        "request_id",
+        |req: &ServiceRequest| {
+            let id = match req
+                .headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|id| id.to_str().ok())
+            {
+                Some(id) if valid_request_id(id) => id.to_string(),
+                _ => NEXT_ID.fetch_add(1, Ordering::Relaxed).to_string(),
+            };
+            req.extensions_mut().insert(RequestId(id.clone()));
+            id
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use log::Level;
+
+    #[test]
+    fn json_lines() {
+        let mut out = Vec::new();
+        for message in ["first", "second \"quoted\"\nline"] {
+            write_json(
+                &mut out,
+                &Record::builder()
+                    .args(format_args!("{message}"))
+                    .level(Level::Info)
+                    .target("seedwing_policy_server")
+                    .build(),
+            )
+            .unwrap();
+        }
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "INFO");
+        assert_eq!(lines[0]["target"], "seedwing_policy_server");
+        assert_eq!(lines[0]["message"], "first");
+        assert_eq!(lines[1]["message"], "second \"quoted\"\nline");
+        assert!(lines[0].get("request_id").is_none());
+    }
+
+    #[test]
+    fn json_request_id() {
+        let id = RequestId("4711".into());
+        let mut out = Vec::new();
+        write_json(
+            &mut out,
+            &Record::builder()
+                .args(format_args!("{id} evaluated test::pattern: error"))
+                .level(Level::Info)
+                .target("seedwing_policy_server")
+                .build(),
+        )
+        .unwrap();
+
+        let line: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(line["request_id"], "4711");
+        assert_eq!(line["message"], "evaluated test::pattern: error");
+    }
+
+    #[actix_web::test]
+    async fn request_id_extension() {
+        use actix_web::{test, web, App, HttpResponse};
+
+        let app = test::init_service(App::new().wrap(request_logger()).route(
+            "/",
+            web::get().to(|id: web::ReqData<RequestId>| async move {
+                HttpResponse::Ok().body(id.0.clone())
+            }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "abc-123"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "abc-123");
+
+        // ids which would break up the log line are replaced
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "abc 123"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert!(body.iter().all(u8::is_ascii_digit), "{body:?}");
+    }
+}
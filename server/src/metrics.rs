@@ -1,5 +1,6 @@
 use actix_web::get;
 use actix_web::HttpResponse;
+use once_cell::sync::Lazy;
 
 #[get("/metrics")]
 pub async fn prometheus() -> HttpResponse {
@@ -20,3 +21,82 @@ pub async fn prometheus() -> HttpResponse {
     buffer.clear();
     HttpResponse::Ok().body(res)
 }
+
+/// Counters tracking how often the policies get built, and how often that failed.
+///
+/// The server only builds the policies when starting, and exits if that fails.
+#[derive(Clone)]
+pub struct BuildMetrics {
+    builds: ::prometheus::IntCounter,
+    failures: ::prometheus::IntCounter,
+}
+
+impl BuildMetrics {
+    /// The metrics registered with the default registry, which can only happen once per process.
+    pub fn global() -> &'static Self {
+        static GLOBAL: Lazy<BuildMetrics> = Lazy::new(|| {
+            BuildMetrics::new(::prometheus::default_registry())
+                .expect("build metrics are only registered once")
+        });
+        &GLOBAL
+    }
+
+    pub fn new(registry: &::prometheus::Registry) -> ::prometheus::Result<Self> {
+        Ok(Self {
+            builds: ::prometheus::register_int_counter_with_registry!(
+                "seedwing_policy_builds_total",
+                "Number of times the policies were built",
+                registry
+            )?,
+            failures: ::prometheus::register_int_counter_with_registry!(
+                "seedwing_policy_build_failures_total",
+                "Number of times building the policies failed",
+                registry
+            )?,
+        })
+    }
+
+    /// Record the outcome of building the world.
+    pub fn record<T, E>(&self, result: &Result<T, E>) {
+        self.builds.inc();
+        if result.is_err() {
+            self.failures.inc();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::prometheus::Registry;
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+
+    async fn build(metrics: &BuildMetrics, src: &str) {
+        let mut builder = Builder::new();
+        let result = match builder.build(Ephemeral::new("test", src).iter()) {
+            Ok(_) => builder.finish().await.map(|_| ()),
+            Err(errors) => Err(errors),
+        };
+        metrics.record(&result);
+    }
+
+    #[actix_web::test]
+    async fn count_failed_builds() {
+        let metrics = BuildMetrics::new(&Registry::new()).unwrap();
+
+        build(&metrics, "pattern valid = string").await;
+        assert_eq!(metrics.builds.get(), 1);
+        assert_eq!(metrics.failures.get(), 0);
+
+        build(&metrics, "pattern broken = unknown::pattern").await;
+        assert_eq!(metrics.builds.get(), 2);
+        assert_eq!(metrics.failures.get(), 1);
+    }
+
+    #[test]
+    fn global() {
+        // registering with the default registry twice would fail
+        assert!(std::ptr::eq(BuildMetrics::global(), BuildMetrics::global()));
+    }
+}
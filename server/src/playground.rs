@@ -1,4 +1,5 @@
 use seedwing_policy_engine::lang::builder::Builder as PolicyBuilder;
+use seedwing_policy_engine::runtime::diagnostic::Diagnostic;
 use seedwing_policy_engine::runtime::sources::{Directory, Ephemeral};
 
 #[derive(Clone)]
@@ -38,4 +39,31 @@ impl PlaygroundState {
         }
         Ok(builder)
     }
+
+    /// Compile a policy along with the server's policies, reporting the problems found.
+    ///
+    /// The compiled world is discarded, nothing is retained.
+    pub async fn validate(&self, policy: &str) -> Vec<Diagnostic> {
+        let mut builder = self.builder.clone();
+        let mut errors = Vec::new();
+        for source in self.sources.iter() {
+            if let Err(e) = builder.build(source.iter()) {
+                errors.extend(e);
+            }
+        }
+
+        if let Err(e) = builder.build(Ephemeral::new("playground", policy).iter()) {
+            errors.extend(e);
+        }
+        if errors.is_empty() {
+            if let Err(e) = builder.finish().await {
+                errors.extend(e);
+            }
+        }
+
+        errors
+            .iter()
+            .map(|error| Diagnostic::new(error, builder.source_cache()))
+            .collect()
+    }
 }
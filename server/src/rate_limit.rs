@@ -0,0 +1,317 @@
+//! Limiting the rate of requests per client IP address, using token buckets.
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::RETRY_AFTER;
+use actix_web::{Error, HttpResponse};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use serde_json::json;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv6Addr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Maximum number of tracked clients, further clients share a single bucket.
+const MAX_CLIENTS: usize = 10_000;
+
+/// Interval in which buckets which are full again get dropped.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Paths of probes and metrics, which are never limited.
+const EXEMPT_PATHS: &[&str] = &["/healthz", "/readyz", "/metrics"];
+
+/// The allowed rate of requests of a single client.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RateLimitConfig {
+    /// Requests per second, refilling the bucket.
+    pub requests_per_second: u32,
+    /// Requests which may be made at once, the size of the bucket.
+    pub burst: u32,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+struct Buckets {
+    clients: HashMap<IpAddr, Bucket>,
+    /// Shared by the clients which don't fit into `clients`.
+    overflow: Bucket,
+    swept: Instant,
+}
+
+/// Token buckets, one per client IP address.
+///
+/// IPv6 clients are tracked per /64 network, as a single host usually has a whole network to
+/// choose its addresses from.
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let burst = config.burst.max(1) as f64;
+        let now = Instant::now();
+        Self {
+            rate: config.requests_per_second.max(1) as f64,
+            burst,
+            buckets: Mutex::new(Buckets {
+                clients: HashMap::new(),
+                overflow: Bucket {
+                    tokens: burst,
+                    updated: now,
+                },
+                swept: now,
+            }),
+        }
+    }
+
+    /// Take a token from the bucket of a client, or return the time until one is available.
+    pub fn acquire(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let client = Self::key(client);
+        let mut buckets = self.buckets.lock().unwrap();
+        let Buckets {
+            clients,
+            overflow,
+            swept,
+        } = &mut *buckets;
+
+        if now.saturating_duration_since(*swept) >= SWEEP_INTERVAL {
+            // clients which are back to a full bucket are like new ones
+            clients.retain(|_, bucket| self.refill(bucket, now) < self.burst);
+            *swept = now;
+        }
+
+        let bucket = if clients.len() < MAX_CLIENTS || clients.contains_key(&client) {
+            clients.entry(client).or_insert(Bucket {
+                tokens: self.burst,
+                updated: now,
+            })
+        } else {
+            overflow
+        };
+        bucket.tokens = self.refill(bucket, now);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    /// The key of the bucket of a client.
+    fn key(client: IpAddr) -> IpAddr {
+        match client {
+            IpAddr::V4(_) => client,
+            IpAddr::V6(addr) => match addr.to_ipv4_mapped() {
+                Some(addr) => IpAddr::V4(addr),
+                None => IpAddr::V6(Ipv6Addr::from(u128::from(addr) & !(u128::MAX >> 64))),
+            },
+        }
+    }
+
+    fn refill(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        (bucket.tokens + elapsed * self.rate).min(self.burst)
+    }
+}
+
+/// Middleware rejecting requests exceeding the rate limit with `429 Too Many Requests`.
+///
+/// Requests are passed on unchecked if no limit is configured, the client address is unknown, or
+/// they are health probes or metrics scrapes.
+#[derive(Clone)]
+pub struct RateLimit(Option<Arc<RateLimiter>>);
+
+impl RateLimit {
+    pub fn new(config: Option<RateLimitConfig>) -> Self {
+        Self(config.map(|config| Arc::new(RateLimiter::new(config))))
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service,
+            limiter: self.0.clone(),
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: S,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let client = req.peer_addr().map(|addr| addr.ip());
+        let exempt = EXEMPT_PATHS.contains(&req.path());
+        if let (Some(limiter), Some(client), false) = (&self.limiter, client, exempt) {
+            if let Err(retry_after) = limiter.acquire(client, Instant::now()) {
+                log::debug!("rate limit exceeded by {client}");
+                // whole seconds, rounded up
+                let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                let response = HttpResponse::TooManyRequests()
+                    .insert_header((RETRY_AFTER, seconds.to_string()))
+                    .json(json!({ "error": "too many requests" }));
+                return Box::pin(ready(Ok(req.into_response(response).map_into_right_body())));
+            }
+        }
+
+        let response = self.service.call(req);
+        Box::pin(async move { response.await.map(ServiceResponse::map_into_left_body) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{api, health};
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App};
+    use std::net::SocketAddr;
+
+    const CONFIG: RateLimitConfig = RateLimitConfig {
+        requests_per_second: 1,
+        burst: 2,
+    };
+
+    #[test]
+    fn token_bucket() {
+        let limiter = RateLimiter::new(CONFIG);
+        let client = IpAddr::from([10, 0, 0, 1]);
+        let start = Instant::now();
+
+        assert!(limiter.acquire(client, start).is_ok());
+        assert!(limiter.acquire(client, start).is_ok());
+        assert_eq!(limiter.acquire(client, start), Err(Duration::from_secs(1)));
+
+        // refilled by one token after a second
+        let later = start + Duration::from_secs(1);
+        assert!(limiter.acquire(client, later).is_ok());
+        assert!(limiter.acquire(client, later).is_err());
+
+        // other clients have their own bucket
+        assert!(limiter.acquire(IpAddr::from([10, 0, 0, 2]), later).is_ok());
+    }
+
+    #[test]
+    fn ipv6_network() {
+        let limiter = RateLimiter::new(CONFIG);
+        let now = Instant::now();
+
+        let client = |addr: &str| addr.parse::<IpAddr>().unwrap();
+        assert!(limiter.acquire(client("2001:db8::1"), now).is_ok());
+        assert!(limiter.acquire(client("2001:db8::ffff:2"), now).is_ok());
+        // the same /64 network
+        assert!(limiter.acquire(client("2001:db8::3"), now).is_err());
+        assert!(limiter.acquire(client("2001:db8:0:1::1"), now).is_ok());
+
+        // IPv4 clients connecting through IPv6
+        assert!(limiter.acquire(client("::ffff:10.0.0.1"), now).is_ok());
+        assert!(limiter.acquire(client("::ffff:10.0.0.2"), now).is_ok());
+    }
+
+    #[test]
+    fn bounded_clients() {
+        let limiter = RateLimiter::new(CONFIG);
+        let now = Instant::now();
+
+        for n in 0..MAX_CLIENTS as u32 {
+            assert!(limiter.acquire(IpAddr::from(n.to_be_bytes()), now).is_ok());
+        }
+        assert_eq!(limiter.buckets.lock().unwrap().clients.len(), MAX_CLIENTS);
+
+        // further clients share a bucket
+        let other = |n: u32| IpAddr::from((u32::MAX - n).to_be_bytes());
+        assert!(limiter.acquire(other(0), now).is_ok());
+        assert!(limiter.acquire(other(1), now).is_ok());
+        assert!(limiter.acquire(other(2), now).is_err());
+        assert_eq!(limiter.buckets.lock().unwrap().clients.len(), MAX_CLIENTS);
+
+        // until the buckets which are full again get dropped
+        let later = now + SWEEP_INTERVAL;
+        assert!(limiter.acquire(other(2), later).is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().clients.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn too_many_requests() {
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(Some(CONFIG)))
+                .service(api::version)
+                .service(health::healthz),
+        )
+        .await;
+
+        let request = |addr: &str| {
+            test::TestRequest::get()
+                .uri("/version")
+                .peer_addr(addr.parse::<SocketAddr>().unwrap())
+                .to_request()
+        };
+
+        for _ in 0..2 {
+            let resp = test::call_service(&app, request("10.0.0.1:4711")).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+
+        let resp = test::call_service(&app, request("10.0.0.1:4712")).await;
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(resp.headers().get(RETRY_AFTER).unwrap(), "1");
+
+        let resp = test::call_service(&app, request("10.0.0.2:4711")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // probes are never limited
+        let req = test::TestRequest::get()
+            .uri("/healthz")
+            .peer_addr("10.0.0.1:4711".parse().unwrap())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn disabled() {
+        let app =
+            test::init_service(App::new().wrap(RateLimit::new(None)).service(api::version)).await;
+
+        // more requests than the burst of the limited configuration allows
+        for _ in 0..10 {
+            let req = test::TestRequest::get()
+                .uri("/version")
+                .peer_addr("10.0.0.1:4711".parse().unwrap())
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Graceful shutdown of the server.
+
+use actix_web::dev::Server;
+use std::future::Future;
+use std::time::Duration;
+
+/// Default time in-flight requests get to complete when shutting down.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Wait for a termination signal, `SIGTERM` or `Ctrl-C`.
+pub async fn signal() {
+    #[cfg(unix)]
+    {
+        use futures_util::future::{select, Either};
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                let ctrl_c = Box::pin(tokio::signal::ctrl_c());
+                let terminate = Box::pin(terminate.recv());
+                match select(ctrl_c, terminate).await {
+                    Either::Left(_) => log::info!("received Ctrl-C"),
+                    Either::Right(_) => log::info!("received SIGTERM"),
+                }
+            }
+            Err(err) => {
+                log::warn!("unable to listen for SIGTERM: {err}");
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        log::info!("received Ctrl-C");
+    }
+}
+
+/// Run the server until `shutdown` completes.
+///
+/// Once triggered, the server stops accepting new connections, and waits for in-flight requests to
+/// complete, up to the shutdown timeout the server was configured with.
+pub async fn serve_until<F>(server: Server, shutdown: F) -> std::io::Result<()>
+where
+    F: Future<Output = ()> + 'static,
+{
+    let handle = server.handle();
+    actix_rt::spawn(async move {
+        shutdown.await;
+        log::info!("shutting down, waiting for in-flight requests");
+        handle.stop(true).await;
+    });
+
+    server.await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use actix_web::{web, App, HttpServer};
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::sync::Arc;
+    use tokio::sync::{oneshot, Notify};
+
+    #[actix_web::test]
+    async fn complete_in_flight_requests() {
+        let started = Arc::new(Notify::new());
+
+        let notify = started.clone();
+        let server = HttpServer::new(move || {
+            let notify = notify.clone();
+            App::new().route(
+                "/slow",
+                web::get().to(move || {
+                    let notify = notify.clone();
+                    async move {
+                        notify.notify_one();
+                        actix_rt::time::sleep(Duration::from_millis(500)).await;
+                        "done"
+                    }
+                }),
+            )
+        })
+        .workers(1)
+        .disable_signals()
+        .shutdown_timeout(5)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        let server = server.run();
+
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream
+                .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let (trigger, triggered) = oneshot::channel::<()>();
+        actix_rt::spawn(async move {
+            // shut down while the request is being processed
+            started.notified().await;
+            let _ = trigger.send(());
+        });
+
+        serve_until(server, async move {
+            let _ = triggered.await;
+        })
+        .await
+        .unwrap();
+
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.ends_with("done"), "{response}");
+
+        // no longer accepting connections
+        assert!(TcpStream::connect(addr).is_err());
+    }
+}
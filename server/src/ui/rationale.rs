@@ -28,9 +28,7 @@ impl<'r> Rationalizer<'r> {
 
         let input = result.input();
         let input_json = input.as_json();
-        let input_json = serde_json::to_string_pretty(&input_json).unwrap();
-        let input_json = input_json.replace('<', "&lt;");
-        let input_json = input_json.replace('>', "&gt;");
+        let input_json = escape(&serde_json::to_string_pretty(&input_json).unwrap());
         html.push_str("<div class='input'>");
         html.push_str("<pre>");
         html.push_str(input_json.as_str());
@@ -38,6 +36,7 @@ impl<'r> Rationalizer<'r> {
         html.push_str("</div>");
 
         if let Some(name) = result.ty().name() {
+            let name = escape(&name.to_string());
             html.push_str("<div>");
             if result.severity() < Severity::Error {
                 html.push_str(
@@ -63,11 +62,17 @@ impl<'r> Rationalizer<'r> {
                 Rationale::Const(_) => {}
                 Rationale::Primordial(_) => {}
                 Rationale::Expression(_) => {}
+                Rationale::Labeled(_, _) => {}
+                Rationale::Reason(_, _) => {}
                 Rationale::Function {
                     severity: _,
-                    rationale: _,
+                    rationale,
                     supporting,
                 } => {
+                    if let Some(Rationale::Labeled(label, _)) = rationale.as_deref() {
+                        let label = escape(label);
+                        html.push_str(format!("<div class='label'>{label}</div>").as_str());
+                    }
                     for each in supporting.iter() {
                         Self::rationale_inner(html, &each);
                     }
@@ -119,12 +124,13 @@ impl<'r> Rationalizer<'r> {
                             html.push_str("<div class='field unsatisfied'>");
                         }
                         html.push_str("<div class='field-name'>field <code>");
-                        html.push_str(name.as_ref());
+                        html.push_str(&escape(name));
                         html.push_str("</code></div>");
                         Self::rationale_inner(html, result);
                         html.push_str("</div>");
                     } else {
                         html.push_str("<div class='field unsatisfied'>");
+                        let name = escape(name);
                         html.push_str(format!("field <code>{name}</code> is missing").as_str());
                         html.push_str("</div>");
                     }
@@ -176,14 +182,24 @@ impl<'r> Rationalizer<'r> {
                 html.push_str("<div>not a list</div>");
             }
             Rationale::MissingField(name) => {
+                let name = escape(name);
                 html.push_str(format!("<div>missing field: {name}</div>").as_str());
             }
             Rationale::InvalidArgument(name) => {
+                let name = escape(name);
                 html.push_str(format!("<div>invalid argument: {name}</div>").as_str());
             }
             Rationale::Const(_) => {}
             Rationale::Primordial(_) => {}
             Rationale::Expression(_) => {}
+            Rationale::Labeled(label, _) => {
+                let label = escape(label);
+                html.push_str(format!("<div class='label'>{label}</div>").as_str());
+            }
+            Rationale::Reason(reason, _) => {
+                let reason = escape(reason);
+                html.push_str(format!("<div>{reason}</div>").as_str());
+            }
             Rationale::Function {
                 severity: _,
                 rationale: _,
@@ -200,9 +216,8 @@ impl<'r> Rationalizer<'r> {
                         html.push_str("and produced a value");
 
                         let output_json = output.as_json();
-                        let output_json = serde_json::to_string_pretty(&output_json).unwrap();
-                        let output_json = output_json.replace('<', "&lt;");
-                        let output_json = output_json.replace('>', "&gt;");
+                        let output_json =
+                            escape(&serde_json::to_string_pretty(&output_json).unwrap());
                         html.push_str("<div class='output'>");
                         html.push_str("<pre>");
                         html.push_str(output_json.as_str());
@@ -223,3 +238,32 @@ impl<'r> Rationalizer<'r> {
         }
     }
 }
+
+/// Escape text for inclusion in HTML element content or attribute values.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escape_markup() {
+        assert_eq!(
+            escape(r#"<img src='x' onerror="alert(1)">&"#),
+            "&lt;img src=&#39;x&#39; onerror=&quot;alert(1)&quot;&gt;&amp;"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Sizing the pool of server workers.
+
+use std::num::NonZeroUsize;
+use std::thread::available_parallelism;
+
+/// The number of workers to start, defaulting to the available parallelism, as actix does.
+///
+/// Evaluating policies is mostly CPU-bound, so more workers than CPUs rarely pay off.
+pub fn worker_count(requested: Option<NonZeroUsize>) -> usize {
+    requested
+        .or_else(|| available_parallelism().ok())
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn requested() {
+        assert_eq!(worker_count(NonZeroUsize::new(3)), 3);
+    }
+
+    #[test]
+    fn default() {
+        let expected = available_parallelism().map(NonZeroUsize::get).unwrap_or(1);
+        assert_eq!(worker_count(None), expected);
+    }
+}
@@ -1,6 +1,10 @@
 use crate::command::bench::Bench;
 use crate::command::docs::Docs;
 use crate::command::eval::Eval;
+use crate::command::info::Info;
+use crate::command::publish::Publish;
+use crate::command::repl::Repl;
+use crate::command::report::Report;
 use crate::command::serve::Serve;
 use crate::command::test::Test;
 use crate::command::verify::Verify;
@@ -29,10 +33,14 @@ pub enum InputType {
 pub enum Command {
     Verify(Verify),
     Eval(Eval),
+    Publish(Publish),
+    Repl(Repl),
     Bench(Bench),
     Serve(Serve),
     Test(Test),
     Docs(Docs),
+    Info(Info),
+    Report(Report),
 }
 
 #[derive(clap::Parser, Debug)]
@@ -101,10 +109,14 @@ impl Cli {
                 ExitCode::SUCCESS
             }
             Command::Eval(eval) => eval.run(context).await?,
+            Command::Publish(publish) => publish.run(context).await?,
+            Command::Repl(repl) => repl.run(context).await?,
             Command::Bench(bench) => bench.run(context).await?,
             Command::Serve(serve) => serve.run(context).await?.report(),
             Command::Test(test) => test.run(context).await?,
             Command::Docs(docs) => docs.run(context).await?.report(),
+            Command::Info(info) => info.run(context).await?.report(),
+            Command::Report(report) => report.run(context).await?.report(),
         })
     }
 }
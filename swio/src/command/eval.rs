@@ -2,9 +2,27 @@ use crate::{
     cli::{Context, InputType},
     util::{self, load_values},
 };
-use seedwing_policy_engine::{lang::Severity, runtime::Response};
+use seedwing_policy_engine::{
+    lang::Severity,
+    runtime::{Response, World},
+    value::RuntimeValue,
+};
 use serde_view::View;
-use std::{path::PathBuf, process::ExitCode};
+use std::{
+    io::{stdout, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, clap::ValueEnum, Debug)]
+pub enum OutputFormat {
+    /// A pretty-printed JSON document per result
+    Pretty,
+    /// A single line of JSON per result (JSON Lines)
+    Jsonl,
+    /// A single line of text per result, summarizing it
+    Summary,
+}
 
 #[derive(clap::Args, Debug)]
 #[command(
@@ -16,6 +34,7 @@ pub struct Eval {
     typ: InputType,
     #[arg(short, long)]
     input: Option<PathBuf>,
+    /// Name of the pattern, may be a glob expression like `foo::*` or `foo::**`
     #[arg(short = 'n', long = "name")]
     name: Option<String>,
     #[arg(short = 'v', long = "verbose", default_value_t = false)]
@@ -27,6 +46,8 @@ pub struct Eval {
         default_value_t = String::from("name,severity,reason,rationale")
     )]
     select: String,
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = OutputFormat::Pretty)]
+    output: OutputFormat,
 }
 
 impl Eval {
@@ -49,12 +70,43 @@ impl Eval {
             context.required_policies.clone()
         };
 
+        // expand glob expressions, skipping patterns which need parameters
+        let names: Vec<String> = names
+            .into_iter()
+            .flat_map(|name| {
+                if name.contains('*') {
+                    world
+                        .patterns_matching(&name)
+                        .into_iter()
+                        .filter(|(_, pattern)| pattern.parameters().is_empty())
+                        .map(|(name, _)| name.as_type_str())
+                        .collect()
+                } else {
+                    vec![name]
+                }
+            })
+            .collect();
+        if names.is_empty() {
+            eprintln!("No policies matched the provided names");
+            return Ok(ExitCode::FAILURE);
+        }
+
         // Load from config
 
         let values = load_values(self.typ, inputs).await?;
+        self.evaluate(&world, &names, values, &mut stdout()).await
+    }
+
+    async fn evaluate<W: Write>(
+        &self,
+        world: &World,
+        names: &[String],
+        values: Vec<RuntimeValue>,
+        out: &mut W,
+    ) -> anyhow::Result<ExitCode> {
         for value in values {
             for name in names.iter() {
-                let eval = util::eval::Eval::new(&world, name, value.clone());
+                let eval = util::eval::Eval::new(world, name, value.clone());
 
                 let result = eval.run().await?;
                 let mut response = Response::new(&result);
@@ -62,9 +114,22 @@ impl Eval {
                     response = response.collapse(Severity::Error);
                 }
 
-                let response = response.as_view().with_fields(self.select.split(","));
-
-                println!("{}", serde_json::to_string_pretty(&response).unwrap());
+                match self.output {
+                    OutputFormat::Pretty => {
+                        let response = response.as_view().with_fields(self.select.split(","));
+                        writeln!(out, "{}", serde_json::to_string_pretty(&response)?)?
+                    }
+                    OutputFormat::Jsonl => {
+                        let response = response.as_view().with_fields(self.select.split(","));
+                        // flush every line, so that consumers can process results as they come in
+                        writeln!(out, "{}", serde_json::to_string(&response)?)?;
+                        out.flush()?;
+                    }
+                    OutputFormat::Summary => {
+                        writeln!(out, "{}", response.summary_line())?;
+                        out.flush()?;
+                    }
+                }
                 if result.severity() >= Severity::Error {
                     return Ok(ExitCode::from(2));
                 }
@@ -74,3 +139,82 @@ impl Eval {
         Ok(ExitCode::SUCCESS)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+
+    #[tokio::test]
+    async fn jsonl_output() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern name = { name: string }").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let eval = Eval {
+            typ: InputType::Json,
+            input: None,
+            name: None,
+            verbose: false,
+            select: "name,severity".into(),
+            output: OutputFormat::Jsonl,
+        };
+        let values = ["bob", "jim", "ulf"]
+            .into_iter()
+            .map(|name| serde_json::json!({ "name": name }).into())
+            .collect();
+
+        let mut out = Vec::new();
+        let code = eval
+            .evaluate(&world, &["test::name".into()], values, &mut out)
+            .await
+            .unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in lines {
+            let response: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(response["severity"], "none");
+        }
+    }
+
+    #[tokio::test]
+    async fn summary_output() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern name = { name: string }").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let eval = Eval {
+            typ: InputType::Json,
+            input: None,
+            name: None,
+            verbose: false,
+            select: "name,severity".into(),
+            output: OutputFormat::Summary,
+        };
+        let values = vec![
+            serde_json::json!({ "name": "bob" }).into(),
+            serde_json::json!({ "name": 42 }).into(),
+        ];
+
+        let mut out = Vec::new();
+        let code = eval
+            .evaluate(&world, &["test::name".into()], values, &mut out)
+            .await
+            .unwrap();
+        assert_eq!(code, ExitCode::from(2));
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out,
+            "PASS test::name\nFAIL test::name: field 'name' did not satisfy string\n"
+        );
+    }
+}
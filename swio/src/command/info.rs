@@ -0,0 +1,307 @@
+use crate::cli::Context;
+use anyhow::bail;
+use seedwing_policy_engine::lang::{Expr, SyntacticSugar, ValuePattern};
+use seedwing_policy_engine::runtime::metadata::{
+    InnerPatternMetadata, PatternMetadata, PatternOrReference, PatternRef, PrimordialPattern,
+};
+use seedwing_policy_engine::runtime::World;
+use std::collections::BTreeSet;
+use std::io::{self, stdout, Write};
+
+#[derive(clap::Args, Debug)]
+#[command(
+    about = "Explain the structure of a pattern",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Info {
+    /// Name of the pattern, like `list::all`
+    pattern: String,
+}
+
+impl Info {
+    pub async fn run(&self, context: Context) -> anyhow::Result<()> {
+        let world = context.world().await?.1;
+        self.write(&world, &mut stdout().lock())
+    }
+
+    fn write(&self, world: &World, out: &mut impl Write) -> anyhow::Result<()> {
+        let Some(pattern) = world.get_pattern_meta(self.pattern.as_str()) else {
+            bail!("Pattern not found: {}", self.pattern);
+        };
+
+        writeln!(out, "pattern: {}", self.pattern)?;
+        if let Some(docs) = &pattern.metadata.documentation.0 {
+            writeln!(out)?;
+            writeln!(out, "{}", docs.trim())?;
+            writeln!(out)?;
+        }
+
+        if !pattern.parameters.is_empty() {
+            let parameters: Vec<_> = pattern.parameters.iter().map(|p| p.as_ref()).collect();
+            writeln!(out, "parameters: {}", parameters.join(", "))?;
+        }
+
+        let references = references(&pattern);
+        if !references.is_empty() {
+            writeln!(out, "references:")?;
+            for reference in references {
+                writeln!(out, "  {reference}")?;
+            }
+        }
+
+        writeln!(out, "structure:")?;
+        render_inner(&pattern.inner, 1, out)?;
+
+        Ok(())
+    }
+}
+
+/// All patterns referenced by the pattern, sorted by their name.
+fn references(pattern: &PatternMetadata) -> BTreeSet<String> {
+    fn walk_type(ty: &PatternOrReference, refs: &mut BTreeSet<String>) {
+        match ty {
+            PatternOrReference::Pattern(inner) => walk_inner(inner, refs),
+            PatternOrReference::Ref(r) => {
+                refs.insert(path(r));
+            }
+        }
+    }
+
+    fn walk_inner(inner: &InnerPatternMetadata, refs: &mut BTreeSet<String>) {
+        match inner {
+            InnerPatternMetadata::Bound(ty, bindings) => {
+                walk_type(ty, refs);
+                for each in bindings.bindings.values() {
+                    walk_type(each, refs);
+                }
+            }
+            InnerPatternMetadata::Ref(_, ty, bindings) => {
+                walk_type(ty, refs);
+                for each in bindings {
+                    walk_type(each, refs);
+                }
+            }
+            InnerPatternMetadata::Deref(ty) => walk_type(ty, refs),
+            InnerPatternMetadata::Object(object) => {
+                for field in &object.fields {
+                    walk_type(&field.ty, refs);
+                }
+            }
+            InnerPatternMetadata::List(terms) => {
+                for each in terms {
+                    walk_type(each, refs);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut refs = BTreeSet::new();
+    walk_inner(&pattern.inner, &mut refs);
+    refs
+}
+
+fn path(r: &PatternRef) -> String {
+    r.package
+        .iter()
+        .map(|s| s.as_str())
+        .chain([r.name.as_str()])
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn node(depth: usize, label: &str, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{:indent$}{label}", "", indent = depth * 2)
+}
+
+fn render_type(ty: &PatternOrReference, depth: usize, out: &mut impl Write) -> io::Result<()> {
+    match ty {
+        PatternOrReference::Pattern(inner) => render_inner(inner, depth, out),
+        PatternOrReference::Ref(r) => node(depth, &path(r), out),
+    }
+}
+
+fn render_inner(
+    inner: &InnerPatternMetadata,
+    depth: usize,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match inner {
+        InnerPatternMetadata::Anything => node(depth, "anything", out),
+        InnerPatternMetadata::Nothing => node(depth, "nothing", out),
+        InnerPatternMetadata::Primordial(primordial) => {
+            let label = match primordial {
+                PrimordialPattern::Integer => "integer".into(),
+                PrimordialPattern::Decimal => "decimal".into(),
+                PrimordialPattern::Boolean => "boolean".into(),
+                PrimordialPattern::String => "string".into(),
+                PrimordialPattern::Function(_, r) => format!("built-in function {}", path(r)),
+            };
+            node(depth, &label, out)
+        }
+        InnerPatternMetadata::Argument(name) => node(depth, &format!("parameter {name}"), out),
+        InnerPatternMetadata::Const(val) => node(depth, &render_val(val), out),
+        InnerPatternMetadata::Expr(expr) => node(depth, &format!("$({})", render_expr(expr)), out),
+        InnerPatternMetadata::Deref(ty) => {
+            node(depth, "deref", out)?;
+            render_type(ty, depth + 1, out)
+        }
+        InnerPatternMetadata::Bound(ty, bindings) => {
+            render_type(ty, depth, out)?;
+            let mut bindings: Vec<_> = bindings.bindings.iter().collect();
+            bindings.sort_unstable_by_key(|(name, _)| name.as_str());
+            for (name, binding) in bindings {
+                node(depth + 1, &format!("{name} ="), out)?;
+                render_type(binding, depth + 2, out)?;
+            }
+            Ok(())
+        }
+        InnerPatternMetadata::Ref(SyntacticSugar::None, ty, bindings) => {
+            render_type(ty, depth, out)?;
+            for binding in bindings {
+                render_type(binding, depth + 1, out)?;
+            }
+            Ok(())
+        }
+        InnerPatternMetadata::Ref(sugar, _, bindings) => {
+            let label = match sugar {
+                SyntacticSugar::And => "and",
+                SyntacticSugar::Or => "or",
+                SyntacticSugar::Refine => "refine",
+                SyntacticSugar::Traverse => "traverse",
+                SyntacticSugar::Chain => "chain",
+                SyntacticSugar::Not => "not",
+                SyntacticSugar::None => unreachable!("handled above"),
+            };
+            node(depth, label, out)?;
+            // sugared terms are passed as a single list
+            match bindings.as_slice() {
+                [PatternOrReference::Pattern(inner)] => match &**inner {
+                    InnerPatternMetadata::List(terms) => {
+                        for term in terms {
+                            render_type(term, depth + 1, out)?;
+                        }
+                        Ok(())
+                    }
+                    inner => render_inner(inner, depth + 1, out),
+                },
+                bindings => {
+                    for binding in bindings {
+                        render_type(binding, depth + 1, out)?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+        InnerPatternMetadata::Object(object) => {
+            node(depth, "object", out)?;
+            for field in &object.fields {
+                let optional = if field.optional { "?" } else { "" };
+                node(depth + 1, &format!("{}{optional}:", field.name), out)?;
+                render_type(&field.ty, depth + 2, out)?;
+            }
+            Ok(())
+        }
+        InnerPatternMetadata::List(terms) => {
+            node(depth, "list", out)?;
+            for term in terms {
+                render_type(term, depth + 1, out)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn render_val(val: &ValuePattern) -> String {
+    match val {
+        ValuePattern::Null => "null".into(),
+        ValuePattern::String(val) => format!("{val:?}"),
+        ValuePattern::Integer(val) => val.to_string(),
+        ValuePattern::Decimal(val) => val.to_string(),
+        ValuePattern::Boolean(val) => val.to_string(),
+        ValuePattern::List(val) => {
+            let items: Vec<_> = val.iter().map(|v| render_val(v)).collect();
+            format!("[{}]", items.join(", "))
+        }
+        ValuePattern::Octets(val) => format!("{} octets", val.len()),
+    }
+}
+
+fn render_expr(expr: &Expr) -> String {
+    let binary = |lhs: &Expr, op: &str, rhs: &Expr| {
+        format!("{} {op} {}", render_expr(lhs), render_expr(rhs))
+    };
+
+    match expr {
+        Expr::SelfLiteral() => "self".into(),
+        Expr::Value(val) => render_val(val),
+        Expr::Function(name, expr) => format!("{name}({})", render_expr(expr)),
+        Expr::Add(lhs, rhs) => binary(lhs, "+", rhs),
+        Expr::Subtract(lhs, rhs) => binary(lhs, "-", rhs),
+        Expr::Multiply(lhs, rhs) => binary(lhs, "*", rhs),
+        Expr::Divide(lhs, rhs) => binary(lhs, "/", rhs),
+        Expr::LessThan(lhs, rhs) => binary(lhs, "<", rhs),
+        Expr::LessThanEqual(lhs, rhs) => binary(lhs, "<=", rhs),
+        Expr::GreaterThan(lhs, rhs) => binary(lhs, ">", rhs),
+        Expr::GreaterThanEqual(lhs, rhs) => binary(lhs, ">=", rhs),
+        Expr::Equal(lhs, rhs) => binary(lhs, "==", rhs),
+        Expr::NotEqual(lhs, rhs) => binary(lhs, "!=", rhs),
+        Expr::Not(expr) => format!("!{}", render_expr(expr)),
+        Expr::LogicalAnd(lhs, rhs) => binary(lhs, "&&", rhs),
+        Expr::LogicalOr(lhs, rhs) => binary(lhs, "||", rhs),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+
+    #[tokio::test]
+    async fn parameterized_pattern() {
+        let mut builder = Builder::new();
+        builder
+            .build(
+                Ephemeral::new(
+                    "test",
+                    r#"
+/// Components having a name, and a version.
+pattern component<version> = {
+  name: string,
+  version?: version,
+}
+
+pattern components<version> = list::all<component<version>>
+"#,
+                )
+                .iter(),
+            )
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let info = Info {
+            pattern: "test::components".into(),
+        };
+        let mut out = Vec::new();
+        info.write(&world, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("parameters: version\n"), "{out}");
+        assert!(
+            out.contains("references:\n  list::all\n  test::component\n"),
+            "{out}"
+        );
+        assert!(out.contains("structure:\n  list::all\n"), "{out}");
+    }
+
+    #[tokio::test]
+    async fn unknown_pattern() {
+        let world = Builder::new().finish().await.unwrap();
+
+        let info = Info {
+            pattern: "test::unknown".into(),
+        };
+        assert!(info.write(&world, &mut Vec::new()).is_err());
+    }
+}
@@ -1,6 +1,10 @@
 pub mod bench;
 pub mod docs;
 pub mod eval;
+pub mod info;
+pub mod publish;
+pub mod repl;
+pub mod report;
 pub mod serve;
 pub mod test;
 pub mod verify;
@@ -0,0 +1,251 @@
+use crate::{
+    cli::{Context, InputType},
+    util::{self, load_values},
+};
+use anyhow::bail;
+use hmac::{Hmac, Mac};
+use seedwing_policy_engine::{
+    lang::Severity,
+    runtime::{Response, World},
+    value::RuntimeValue,
+};
+use sha2::Sha256;
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+/// The header carrying the signature of the payload.
+const SIGNATURE_HEADER: &str = "X-Seedwing-Signature-256";
+
+/// The environment variable providing the secret, if it isn't provided on the command line.
+const SECRET_ENV: &str = "SEEDWING_WEBHOOK_SECRET";
+
+#[derive(clap::Args, Debug)]
+#[command(
+    about = "Evaluate a pattern against inputs and publish the results to a webhook",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Publish {
+    #[arg(short='t', value_name = "TYPE", value_enum, default_value_t=InputType::Json)]
+    typ: InputType,
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Name of the pattern
+    #[arg(short = 'n', long = "name")]
+    name: String,
+    /// URL of the webhook receiving the results
+    #[arg(short = 'u', long = "url", value_name = "URL")]
+    url: reqwest::Url,
+    /// Secret for signing the payload, falls back to the SEEDWING_WEBHOOK_SECRET environment variable
+    #[arg(long = "secret")]
+    secret: Option<String>,
+    /// Number of retries when delivering a result fails
+    #[arg(long = "retries", default_value_t = 3)]
+    retries: u32,
+}
+
+impl Publish {
+    pub async fn run(self, context: Context) -> anyhow::Result<ExitCode> {
+        let world = context.world().await?.1;
+
+        let inputs: Vec<PathBuf> = if let Some(input) = &self.input {
+            vec![input.clone()]
+        } else {
+            context.inputs.clone()
+        };
+        let values = load_values(self.typ, inputs).await?;
+
+        let webhook = Webhook {
+            client: reqwest::Client::new(),
+            url: self.url,
+            secret: self.secret.or_else(|| std::env::var(SECRET_ENV).ok()),
+            retries: self.retries,
+            backoff: Duration::from_millis(500),
+        };
+
+        publish(&world, &self.name, values, &webhook).await
+    }
+}
+
+/// Evaluate all values and deliver each response to the webhook.
+async fn publish(
+    world: &World,
+    name: &str,
+    values: Vec<RuntimeValue>,
+    webhook: &Webhook,
+) -> anyhow::Result<ExitCode> {
+    let mut code = ExitCode::SUCCESS;
+
+    for value in values {
+        let result = util::eval::Eval::new(world, name, value).run().await?;
+        let payload = serde_json::to_vec(&Response::new(&result))?;
+        webhook.deliver(&payload).await?;
+
+        if result.severity() >= Severity::Error {
+            code = ExitCode::from(2);
+        }
+    }
+
+    Ok(code)
+}
+
+/// A webhook, receiving JSON payloads.
+pub struct Webhook {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    secret: Option<String>,
+    retries: u32,
+    /// The delay before the first retry, doubling with each further attempt.
+    backoff: Duration,
+}
+
+impl Webhook {
+    /// Deliver a payload, retrying in case of a failure.
+    async fn deliver(&self, payload: &[u8]) -> anyhow::Result<()> {
+        let mut backoff = self.backoff;
+
+        for attempt in 0..=self.retries {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+
+            let mut request = self
+                .client
+                .post(self.url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(payload.to_vec());
+            if let Some(secret) = &self.secret {
+                request = request.header(SIGNATURE_HEADER, sign(secret, payload));
+            }
+
+            match request.send().await.and_then(|r| r.error_for_status()) {
+                Ok(_) => return Ok(()),
+                Err(err) => log::warn!(
+                    "Failed to deliver to {} (attempt {} of {}): {err}",
+                    self.url,
+                    attempt + 1,
+                    self.retries + 1
+                ),
+            }
+        }
+
+        bail!("Failed to deliver to {}", self.url)
+    }
+}
+
+/// Sign a payload using HMAC-SHA256, in the form of `sha256=<hex>`.
+fn sign(secret: &str, payload: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(payload);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use seedwing_policy_engine::lang::builder::Builder;
+    use seedwing_policy_engine::runtime::sources::Ephemeral;
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    type Requests = Arc<Mutex<Vec<(HashMap<String, String>, Vec<u8>)>>>;
+
+    /// Start a webhook, recording all requests and failing the first one.
+    fn mock_webhook() -> (reqwest::Url, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let requests = Requests::default();
+
+        let recorded = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut headers = HashMap::new();
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    match line.trim_end().split_once(": ") {
+                        Some((name, value)) => {
+                            headers.insert(name.to_lowercase(), value.to_string());
+                        }
+                        None => break,
+                    }
+                }
+                let len = headers["content-length"].parse().unwrap();
+                let mut body = vec![0; len];
+                reader.read_exact(&mut body).unwrap();
+
+                let mut recorded = recorded.lock().unwrap();
+                let status = if recorded.is_empty() {
+                    "500 Internal Server Error"
+                } else {
+                    "204 No Content"
+                };
+                recorded.push((headers, body));
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+            }
+        });
+
+        (url.parse().unwrap(), requests)
+    }
+
+    #[tokio::test]
+    async fn signed_payload() {
+        let mut builder = Builder::new();
+        builder
+            .build(Ephemeral::new("test", "pattern name = { name: string }").iter())
+            .unwrap();
+        let world = builder.finish().await.unwrap();
+
+        let (url, requests) = mock_webhook();
+        let webhook = Webhook {
+            client: reqwest::Client::new(),
+            url,
+            secret: Some("s3cr3t".into()),
+            retries: 3,
+            backoff: Duration::from_millis(10),
+        };
+
+        let values = vec![serde_json::json!({ "name": 42 }).into()];
+        let code = publish(&world, "test::name", values, &webhook)
+            .await
+            .unwrap();
+        assert_eq!(code, ExitCode::from(2));
+
+        // the first attempt failed, the retry got delivered
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].1, requests[1].1);
+
+        let (headers, body) = &requests[1];
+        assert_eq!(headers["content-type"], "application/json");
+        assert_eq!(
+            headers[&SIGNATURE_HEADER.to_lowercase()],
+            sign("s3cr3t", body)
+        );
+        assert!(headers[&SIGNATURE_HEADER.to_lowercase()].starts_with("sha256="));
+
+        let response: serde_json::Value = serde_json::from_slice(body).unwrap();
+        assert_eq!(response["name"]["pattern"], "test::name");
+        assert_eq!(response["severity"], "error");
+    }
+
+    #[test]
+    fn signature() {
+        // test case 2 of RFC 4231
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use crate::cli::Context;
+use crate::util;
+use is_terminal::IsTerminal;
+use seedwing_policy_engine::lang::Severity;
+use seedwing_policy_engine::runtime::{Response, World};
+use std::io::{stdin, stdout, BufRead, Write};
+use std::process::ExitCode;
+
+const HELP: &str = r#"Enter a pattern name, followed by a JSON input value. For example:
+
+  my::pattern {"name": "bob"}
+
+Commands:
+  :reload   rebuild the world from the policy and data directories
+  :explain  toggle showing the detailed evaluation result
+  :help     show this help
+  :quit     exit"#;
+
+#[derive(clap::Args, Debug)]
+#[command(
+    about = "Interactively evaluate patterns against inputs",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Repl {
+    /// Start with detailed evaluation results enabled
+    #[arg(short = 'e', long = "explain", default_value_t = false)]
+    explain: bool,
+}
+
+impl Repl {
+    pub async fn run(self, context: Context) -> anyhow::Result<ExitCode> {
+        let world = context.world().await?.1;
+
+        let prompt = stdin().is_terminal();
+        let mut session = Session {
+            context,
+            world,
+            explain: self.explain,
+        };
+        session.run(stdin().lock(), stdout().lock(), prompt).await?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+struct Session {
+    context: Context,
+    world: World,
+    explain: bool,
+}
+
+impl Session {
+    async fn run<R, W>(&mut self, input: R, mut output: W, prompt: bool) -> anyhow::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        if prompt {
+            writeln!(output, "Type :help for help, ^D to exit")?;
+        }
+
+        let mut lines = input.lines();
+        loop {
+            if prompt {
+                write!(output, "> ")?;
+                output.flush()?;
+            }
+
+            let Some(line) = lines.next() else {
+                break;
+            };
+            let line = line?;
+
+            match line.trim() {
+                "" => {}
+                ":quit" | ":q" => break,
+                ":help" => writeln!(output, "{HELP}")?,
+                ":explain" => {
+                    self.explain = !self.explain;
+                    let state = if self.explain { "on" } else { "off" };
+                    writeln!(output, "explain: {state}")?;
+                }
+                ":reload" => match self.context.world().await {
+                    Ok((_, world)) => {
+                        self.world = world;
+                        writeln!(output, "reloaded")?;
+                    }
+                    // the build errors have already been printed, keep the current world
+                    Err(err) => writeln!(output, "{err}")?,
+                },
+                line => self.eval(line, &mut output).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn eval<W: Write>(&self, line: &str, output: &mut W) -> anyhow::Result<()> {
+        let (name, input) = line
+            .split_once(char::is_whitespace)
+            .unwrap_or((line, "null"));
+
+        let value: serde_json::Value = match serde_json::from_str(input) {
+            Ok(value) => value,
+            Err(err) => {
+                writeln!(output, "invalid input: {err}")?;
+                return Ok(());
+            }
+        };
+
+        let result = match util::eval::Eval::new(&self.world, name, value).run().await {
+            Ok(result) => result,
+            Err(err) => {
+                writeln!(output, "{err}")?;
+                return Ok(());
+            }
+        };
+
+        let response = Response::new(&result);
+        if self.explain {
+            writeln!(output, "{}", serde_json::to_string_pretty(&response)?)?;
+        } else if response.severity >= Severity::Error && !response.reason.is_empty() {
+            writeln!(output, "{}: {}", response.severity, response.reason)?;
+        } else {
+            writeln!(output, "{}", response.severity)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn context(policies: PathBuf) -> Context {
+        Context {
+            config_file: None,
+            policy_directories: vec![policies],
+            inputs: Vec::new(),
+            data_directories: Vec::new(),
+            eval_config: None,
+            required_policies: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn scripted_session() {
+        let dir = std::env::temp_dir().join(format!("swio-repl-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("test.dog"), "pattern answer = 42").unwrap();
+
+        let context = context(dir.clone());
+        let world = context.world().await.unwrap().1;
+        let mut session = Session {
+            context,
+            world,
+            explain: false,
+        };
+
+        // change the policy, which only becomes visible after a reload
+        fs::write(dir.join("test.dog"), "pattern answer = 41").unwrap();
+
+        let script = [
+            "test::answer 42",
+            "test::answer {",
+            "test::unknown 42",
+            ":reload",
+            "test::answer 42",
+            "test::answer 41",
+            ":explain",
+            "test::answer 41",
+            ":quit",
+            "test::answer 41",
+        ]
+        .join("\n");
+
+        let mut output = Vec::new();
+        session
+            .run(script.as_bytes(), &mut output, false)
+            .await
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "none");
+        assert!(lines[1].starts_with("invalid input: "), "{}", lines[1]);
+        assert_eq!(lines[2], "no such pattern: test::unknown");
+        assert_eq!(lines[3], "reloaded");
+        assert!(lines[4].starts_with("error"), "{}", lines[4]);
+        assert_eq!(lines[5], "none");
+        assert_eq!(lines[6], "explain: on");
+
+        let explained: serde_json::Value = serde_json::from_str(&lines[7..].join("\n")).unwrap();
+        assert_eq!(explained["severity"], "none");
+        assert_eq!(explained["input"], 41);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::cli::Context;
+use std::io::{stdout, Write};
+
+#[derive(Copy, Clone, PartialEq, Eq, clap::ValueEnum, Debug)]
+pub enum ReportFormat {
+    /// A human readable summary
+    Text,
+    /// A pretty-printed JSON document
+    Json,
+}
+
+#[derive(clap::Args, Debug)]
+#[command(
+    about = "Summarize the compiled patterns",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Report {
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = ReportFormat::Text)]
+    output: ReportFormat,
+}
+
+impl Report {
+    pub async fn run(&self, context: Context) -> anyhow::Result<()> {
+        let report = context.world().await?.1.build_report();
+
+        let mut out = stdout().lock();
+        match self.output {
+            ReportFormat::Text => write!(out, "{report}")?,
+            ReportFormat::Json => {
+                serde_json::to_writer_pretty(&mut out, &report)?;
+                writeln!(out)?;
+            }
+        }
+
+        Ok(())
+    }
+}
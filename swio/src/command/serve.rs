@@ -1,6 +1,10 @@
 use crate::cli::Context;
 use env_logger::Builder;
 use log::LevelFilter;
+use seedwing_policy_server::{configure_logger, LogFormat, RateLimitConfig, ServerConfig};
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(clap::Args, Debug)]
 #[command(
@@ -13,22 +17,119 @@ pub struct Serve {
 
     #[arg(short = 'P', long = "port", default_value_t = 8080)]
     pub(crate) port: u16,
+
+    /// Maximum size of a request body, in bytes
+    #[arg(long, value_name = "BYTES", default_value_t = seedwing_policy_server::DEFAULT_MAX_INPUT_SIZE)]
+    pub(crate) max_input_size: usize,
+
+    /// Maximum number of evaluation results to cache, disabled if zero
+    #[arg(long, value_name = "ENTRIES", default_value_t = 0)]
+    pub(crate) cache_size: usize,
+
+    /// Time a cached evaluation result stays valid, in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = seedwing_policy_server::DEFAULT_CACHE_TTL.as_secs())]
+    pub(crate) cache_ttl: u64,
+
+    /// Time in-flight requests get to complete when shutting down, in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = seedwing_policy_server::DEFAULT_SHUTDOWN_TIMEOUT.as_secs())]
+    pub(crate) shutdown_timeout: u64,
+
+    /// Number of workers handling requests, defaults to the number of available CPUs
+    #[arg(long, value_name = "N")]
+    pub(crate) workers: Option<NonZeroUsize>,
+
+    /// Requests per second a single client IP address may make, unlimited if not set
+    #[arg(long, value_name = "REQUESTS")]
+    pub(crate) rate_limit: Option<NonZeroU32>,
+
+    /// Requests a single client may make at once, defaults to the rate limit
+    #[arg(long, value_name = "REQUESTS", requires = "rate_limit")]
+    pub(crate) rate_limit_burst: Option<NonZeroU32>,
+
+    /// PEM encoded Ed25519 private key, used for signing attestations
+    #[arg(long, value_name = "FILE")]
+    pub(crate) attestation_key: Option<PathBuf>,
+
+    /// Format of the log output
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = LogFormat::Text)]
+    pub(crate) log_format: LogFormat,
 }
 
 impl Serve {
     pub async fn run(&self, context: Context) -> anyhow::Result<()> {
-        Builder::new()
+        configure_logger(&mut Builder::new(), self.log_format)
             .filter_level(LevelFilter::Warn)
             .filter_module("seedwing_policy_server", LevelFilter::Info)
             .filter_module("seedwing_policy_engine", LevelFilter::Info)
+            .filter_module("actix_web::middleware::logger", LevelFilter::Info)
             .init();
-        seedwing_policy_server::run(
-            context.policy_directories.clone(),
-            context.data_directories.clone(),
-            self.bind.clone(),
-            self.port,
-        )
+        seedwing_policy_server::run(ServerConfig {
+            policy_directories: context.policy_directories.clone(),
+            data_directories: context.data_directories.clone(),
+            bind: self.bind.clone(),
+            port: self.port,
+            max_input_size: self.max_input_size,
+            cache_size: self.cache_size,
+            cache_ttl: Duration::from_secs(self.cache_ttl),
+            shutdown_timeout: Duration::from_secs(self.shutdown_timeout),
+            workers: self.workers,
+            rate_limit: self.rate_limit_config(),
+            attestation_key: self.attestation_key.clone(),
+        })
         .await?;
         Ok(())
     }
+
+    fn rate_limit_config(&self) -> Option<RateLimitConfig> {
+        self.rate_limit.map(|rate| RateLimitConfig {
+            requests_per_second: rate.get(),
+            burst: self.rate_limit_burst.unwrap_or(rate).get(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(clap::Parser, Debug)]
+    struct Cli {
+        #[command(flatten)]
+        serve: Serve,
+    }
+
+    #[test]
+    fn workers() {
+        let cli = Cli::try_parse_from(["serve", "--workers", "4"]).unwrap();
+        assert_eq!(cli.serve.workers, NonZeroUsize::new(4));
+        assert_eq!(seedwing_policy_server::worker_count(cli.serve.workers), 4);
+
+        let cli = Cli::try_parse_from(["serve"]).unwrap();
+        assert_eq!(cli.serve.workers, None);
+
+        assert!(Cli::try_parse_from(["serve", "--workers", "0"]).is_err());
+    }
+
+    #[test]
+    fn rate_limit() {
+        let cli = Cli::try_parse_from(["serve"]).unwrap();
+        assert_eq!(cli.serve.rate_limit_config(), None);
+
+        let cli = Cli::try_parse_from(["serve", "--rate-limit", "10"]).unwrap();
+        assert_eq!(
+            cli.serve.rate_limit_config(),
+            Some(RateLimitConfig {
+                requests_per_second: 10,
+                burst: 10,
+            })
+        );
+
+        let cli = Cli::try_parse_from(["serve", "--rate-limit", "10", "--rate-limit-burst", "50"])
+            .unwrap();
+        assert_eq!(cli.serve.rate_limit_config().unwrap().burst, 50);
+
+        // a burst is meaningless without a rate
+        assert!(Cli::try_parse_from(["serve", "--rate-limit-burst", "50"]).is_err());
+    }
 }
@@ -2,8 +2,10 @@ use crate::cli::Context;
 use seedwing_policy_engine::{
     lang::builder::Builder,
     runtime::{
-        config::ConfigContext, sources::Ephemeral, BuildError, EvalContext, EvalOptions, Output,
-        PatternName, RuntimeError, World,
+        config::ConfigContext,
+        junit::{self, Outcome, Report},
+        sources::Ephemeral,
+        BuildError, EvalContext, EvalOptions, Output, PatternName, RuntimeError, World,
     },
     value::RuntimeValue,
 };
@@ -24,6 +26,10 @@ pub struct Test {
 
     #[arg(short = 'm', long = "match", value_name = "MATCH")]
     pub(crate) r#match: Option<String>,
+
+    /// Write a JUnit XML report of the results to a file
+    #[arg(long = "junit", value_name = "FILE")]
+    pub(crate) junit: Option<PathBuf>,
 }
 
 impl Test {
@@ -35,6 +41,9 @@ impl Test {
         println!();
         plan.run(&builder, &world).await;
         self.display_results(&plan);
+        if let Some(path) = &self.junit {
+            tokio::fs::write(path, plan.to_junit().to_xml()).await?;
+        }
         println!();
         let result = if plan.had_failures() { "failed" } else { "ok" };
 
@@ -198,6 +207,26 @@ impl TestPlan {
         }
     }
 
+    /// The results as JUnit report, with pending and ignored tests being skipped.
+    pub fn to_junit(&self) -> Report {
+        let mut report = Report::new("swio test");
+        for test in &self.tests {
+            let outcome = match &test.result {
+                Some(TestResult::Passed) => Outcome::Passed,
+                Some(TestResult::Failed) => Outcome::Failure("unexpected output".into()),
+                Some(TestResult::Ignored) => Outcome::Skipped(Some("ignored".into())),
+                Some(TestResult::Pending) | None => Outcome::Skipped(Some("pending".into())),
+                Some(TestResult::Error(err)) => Outcome::Error(format!("{err:?}")),
+            };
+            report.add(junit::TestCase::new(
+                test.pattern.as_type_str(),
+                test.name.clone(),
+                outcome,
+            ));
+        }
+        report
+    }
+
     fn had_failures(&self) -> bool {
         self.tests
             .iter()